@@ -170,6 +170,12 @@ enum Commands {
         #[arg(short = 'o', long = "output", required = true)]
         output: PathBuf,
 
+        /// Write a lockfile of pinned digests (package@version -> sha256-<base64>)
+        /// for every HTTP(S) import, for reproducible, tamper-evident rebuilds;
+        /// git and on-chain imports have no digest and are not listed
+        #[arg(long = "freeze", value_name = "FILE")]
+        freeze: Option<PathBuf>,
+
         /// Verbose output
         #[arg(short = 'v', long = "verbose")]
         verbose: bool,
@@ -281,9 +287,10 @@ fn main() -> anyhow::Result<()> {
             file,
             include_dirs,
             output,
+            freeze,
             verbose,
         } => {
-            run_bundle(file, include_dirs, output, verbose)?;
+            run_bundle(file, include_dirs, output, freeze, verbose)?;
         }
     }
 
@@ -467,7 +474,7 @@ fn run_prep_for_publish(
             ImportSource::Git { url, .. } => {
                 anyhow::bail!("Git imports not allowed for publishing: {}", url);
             }
-            ImportSource::Http { url } => {
+            ImportSource::Http { url, .. } => {
                 anyhow::bail!("HTTP imports not allowed for publishing: {}", url);
             }
             _ => {}
@@ -540,6 +547,7 @@ fn run_bundle(
     file: PathBuf,
     include_dirs: Vec<PathBuf>,
     output: PathBuf,
+    freeze: Option<PathBuf>,
     verbose: bool,
 ) -> anyhow::Result<()> {
     use abi_loader::{EnhancedImportResolver, FetcherConfig};
@@ -572,5 +580,20 @@ fn run_bundle(
         println!("  Written to: {}", output.display());
     }
 
+    /* Optionally pin every remote import's observed digest into a lockfile */
+    if let Some(freeze_path) = freeze {
+        let lockfile = resolution.to_lockfile();
+        let lockfile_json = serde_json::to_string_pretty(&lockfile)?;
+        std::fs::write(&freeze_path, &lockfile_json)?;
+
+        if verbose {
+            println!(
+                "  Wrote lockfile with {} pinned digest(s) to: {}",
+                lockfile.len(),
+                freeze_path.display()
+            );
+        }
+    }
+
     Ok(())
 }
@@ -224,6 +224,8 @@ mod tests {
                     tag_ref: ExprKind::FieldRef(crate::abi::expr::FieldRefExpr {
                         path: vec!["tag".to_string()],
                     }),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![crate::abi::types::EnumVariant {
                         name: "leaf_variant".to_string(),
                         tag_value: 0,
@@ -1,5 +1,5 @@
 use crate::abi::expr::{ConstantExpression, ExprKind};
-use crate::abi::types::{FloatingPointType, IntegralType, PrimitiveType, TypeDef, TypeKind};
+use crate::abi::types::{FloatingPointType, IntegralType, PrimitiveType, TagWidth, TypeDef, TypeKind};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -27,7 +27,7 @@ pub struct ResolvedType {
 pub enum ResolvedTypeKind {
   Primitive { prim_type: PrimitiveType },
   Struct { fields: Vec<ResolvedField>, packed: bool, custom_alignment: Option<u64> },
-  Union { variants: Vec<ResolvedField> },
+  Union { variants: Vec<ResolvedField>, tagged: Option<TagWidth> },
   Enum { tag_expression: ExprKind, tag_constant_status: ConstantStatus, variants: Vec<ResolvedEnumVariant> },
   Array { element_type: Box<ResolvedType>, size_expression: ExprKind, size_constant_status: ConstantStatus },
   SizeDiscriminatedUnion { variants: Vec<ResolvedSizeDiscriminatedVariant> },
@@ -407,14 +407,28 @@ impl TypeResolver {
           });
         }
 
-        let final_size = if all_sizes_known { Size::Const(max_size) } else { Size::Variable(field_references) };
+        let tagged = union_type.container_attributes.tagged;
+
+        if tagged.is_some() && !all_sizes_known {
+          return Err(ResolutionError::InvalidTypeDefinition(format!(
+            "Union '{}' is tagged but has a variable-sized variant; tagged unions must have a constant size so the leading discriminant has a fixed offset",
+            type_name
+          )));
+        }
+
+        let final_size = if all_sizes_known {
+          let tag_bytes = tagged.map(|w| w.bytes()).unwrap_or(0);
+          Size::Const(max_size + tag_bytes)
+        } else {
+          Size::Variable(field_references)
+        };
 
         Ok(ResolvedType {
           name: type_name,
           size: final_size,
           alignment: max_alignment,
           comment: union_type.container_attributes.comment.clone(),
-          kind: ResolvedTypeKind::Union { variants },
+          kind: ResolvedTypeKind::Union { variants, tagged },
         })
       }
 
@@ -1192,4 +1206,56 @@ mod tests {
     // With parent context, it should validate the field exists and is primitive
     // This would require a more complex test setup with actual resolved types
   }
+
+  #[test]
+  fn test_tagged_union_resolution() {
+    let mut resolver = TypeResolver::new();
+
+    let typedef = TypeDef {
+      name: "TaggedUnion".to_string(),
+      kind: TypeKind::Union(UnionType {
+        container_attributes: ContainerAttributes { tagged: Some(TagWidth::One), ..Default::default() },
+        variants: vec![
+          UnionVariant { name: "variant1".to_string(), variant_type: TypeKind::Primitive(PrimitiveType::Integral(IntegralType::U32)) },
+          UnionVariant { name: "variant2".to_string(), variant_type: TypeKind::Primitive(PrimitiveType::Integral(IntegralType::U64)) },
+        ],
+      }),
+    };
+
+    resolver.add_typedef(typedef);
+    resolver.resolve_all().unwrap();
+
+    let resolved = resolver.types.get("TaggedUnion").unwrap();
+    // Largest variant (u64, 8 bytes) plus a 1-byte tag
+    assert_eq!(resolved.size, Size::Const(9));
+    match &resolved.kind {
+      ResolvedTypeKind::Union { tagged, .. } => assert_eq!(*tagged, Some(TagWidth::One)),
+      _ => panic!("expected a resolved union"),
+    }
+  }
+
+  #[test]
+  fn test_tagged_union_rejects_variable_sized_variant() {
+    let mut resolver = TypeResolver::new();
+
+    let typedef = TypeDef {
+      name: "BadTaggedUnion".to_string(),
+      kind: TypeKind::Union(UnionType {
+        container_attributes: ContainerAttributes { tagged: Some(TagWidth::One), ..Default::default() },
+        variants: vec![UnionVariant {
+          name: "variant1".to_string(),
+          variant_type: TypeKind::Array(ArrayType {
+            container_attributes: Default::default(),
+            size: ExprKind::FieldRef(FieldRefExpr { path: vec!["len".to_string()] }),
+            element_type: Box::new(TypeKind::Primitive(PrimitiveType::Integral(IntegralType::U8))),
+            jagged: false,
+          }),
+        }],
+      }),
+    };
+
+    resolver.add_typedef(typedef);
+    let err = resolver.resolve_all().unwrap_err();
+    assert!(matches!(err, ResolutionError::InvalidTypeDefinition(_)));
+  }
 }
@@ -182,7 +182,7 @@ fn print_variable_references(
                     }
                 }
             }
-            ResolvedTypeKind::Union { variants } => {
+            ResolvedTypeKind::Union { variants, .. } => {
                 /* Find the variant with matching name */
                 for variant in variants {
                     if variant.name == *field_or_variant {
@@ -390,7 +390,7 @@ fn print_detailed_type_analysis(typedefs: &[TypeDef], resolver: &TypeResolver) {
                         );
                     }
                 }
-                ResolvedTypeKind::Union { variants } => {
+                ResolvedTypeKind::Union { variants, .. } => {
                     println!("   Kind: Union");
                     println!("   Variants:");
                     for variant in variants {
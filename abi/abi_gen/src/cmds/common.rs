@@ -28,24 +28,25 @@ pub fn analyze_and_resolve_types(
     let mut analyzer = DependencyAnalyzer::new();
     let analysis = analyzer.analyze_multiple_typedefs(typedefs);
 
-    /* Check for errors */
-    let has_errors = !analysis.cycles.is_empty()
-        || !analysis.layout_violations.is_empty()
-        || !analysis.validation_errors.is_empty();
+    /* Check for errors. Cycles are no longer fatal on their own: a cycle that
+    forms a legitimate recursive group (see `analysis.recursive_groups`) can
+    still be emitted, via forward declarations, so only layout violations and
+    structural validation errors abort analysis. */
+    let has_errors = !analysis.layout_violations.is_empty() || !analysis.validation_errors.is_empty();
 
-    if verbose || has_errors {
+    if verbose || has_errors || !analysis.cycles.is_empty() {
         println!("\n[~] Dependency Analysis Results:");
         println!("==============================");
 
-        if analysis.cycles.is_empty() {
-            println!("[✓] No circular dependencies detected");
+        if analysis.recursive_groups.is_empty() {
+            println!("[✓] No recursive type groups");
         } else {
             println!(
-                "[✗] {} circular dependency cycle(s) detected:",
-                analysis.cycles.len()
+                "[~] {} recursive type group(s) detected (emitted together via forward declarations):",
+                analysis.recursive_groups.len()
             );
-            for cycle in &analysis.cycles {
-                println!("  [~] Cycle: {}", cycle.cycle.join(" -> "));
+            for group in &analysis.recursive_groups {
+                println!("  [~] Group: {}", group.members.join(", "));
             }
         }
 
@@ -250,7 +250,7 @@ impl<'a> CCodeGenerator<'a> {
                     );
                 }
             }
-            ResolvedTypeKind::Union { variants } => {
+            ResolvedTypeKind::Union { variants, .. } => {
                 for variant in variants {
                     self.collect_from_resolved_type(
                         &variant.field_type,
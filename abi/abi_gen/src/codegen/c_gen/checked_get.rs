@@ -0,0 +1,555 @@
+use super::helpers::{
+    escape_c_keyword, format_expr_to_c, format_type_to_c, get_c_accessor_type,
+    is_nested_complex_type, sanitize_type_name,
+};
+use crate::abi::resolved::{ResolvedField, ResolvedType, ResolvedTypeKind, Size};
+use crate::abi::types::PrimitiveType;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+/* ERROR CODES
+      1 = Buffer too small
+      2 = Size overflow
+*/
+
+#[derive(Clone)]
+enum CheckedFieldKind {
+    Primitive { c_type: String },
+    Array { elem_c_type: String, len_fn: String },
+    Opaque { c_type: String },
+}
+
+struct CheckedFieldInfo {
+    raw_name: String,
+    escaped_name: String,
+    kind: CheckedFieldKind,
+}
+
+/// Bounds-checked counterpart to the plain getters `emit_accessor_fn_struct` emits for
+/// fields that live after a struct's trailing variable-size (FAM) data: those take only
+/// `self` and trust the caller already knows `buffer` is long enough, same as `{type}_init`
+/// trusted its caller-supplied lengths before this module existed. These instead take
+/// `buf_sz`, replay the same `safe_add_u64`/`safe_mul_u64` offset walk `{type}_init` used
+/// when writing the buffer, and return an error instead of reading past the end.
+///
+/// Trailing enum-tagged payloads are read back through their own tag/variant accessors and
+/// are skipped here, same as `{type}_init` initializes them separately. When the FAM itself is
+/// such an enum, any named fields declared after it still need an `offset` that accounts for
+/// the enum body's own runtime length, so that's seeded at `sizeof({type}_t)` and walked
+/// forward by its footprint, mirroring `get.rs`'s `fam_offset_code` enum case.
+pub fn emit_checked_accessor_fn(resolved_type: &ResolvedType) -> String {
+    let mut output = String::new();
+    let type_name = sanitize_type_name(&resolved_type.name);
+
+    let fields = if let ResolvedTypeKind::Struct { fields, .. } = &resolved_type.kind {
+        fields
+    } else {
+        return output;
+    };
+
+    if !fields
+        .iter()
+        .any(|f| matches!(&f.field_type.size, Size::Variable(_)))
+    {
+        return output;
+    }
+
+    let mut post_fields: Vec<CheckedFieldInfo> = Vec::new();
+    let mut trailing_enum_field: Option<&ResolvedField> = None;
+    let mut after_variable_size_data = false;
+    for field in fields.iter() {
+        let is_fam = matches!(&field.field_type.size, Size::Variable(_));
+        if is_fam {
+            after_variable_size_data = true;
+        }
+        if !after_variable_size_data {
+            continue;
+        }
+        if matches!(&field.field_type.kind, ResolvedTypeKind::Enum { .. }) {
+            if is_fam {
+                trailing_enum_field = Some(field);
+            }
+            continue;
+        }
+
+        let escaped_name = escape_c_keyword(&field.name);
+        let kind = match &field.field_type.kind {
+            ResolvedTypeKind::Primitive { .. } => CheckedFieldKind::Primitive {
+                c_type: get_c_accessor_type(&field.field_type),
+            },
+            ResolvedTypeKind::Array { element_type, .. } => {
+                let mut elem_c_type = format_type_to_c(element_type);
+                if is_nested_complex_type(element_type) {
+                    elem_c_type = format!("{}_{}_inner_t", type_name, field.name);
+                }
+                CheckedFieldKind::Array {
+                    elem_c_type,
+                    len_fn: format!("{}_get_{}_size", type_name, escaped_name),
+                }
+            }
+            _ => {
+                let mut c_type = format_type_to_c(&field.field_type);
+                if is_nested_complex_type(&field.field_type) {
+                    c_type = format!("{}_{}_inner_t", type_name, field.name);
+                }
+                CheckedFieldKind::Opaque { c_type }
+            }
+        };
+
+        post_fields.push(CheckedFieldInfo {
+            raw_name: field.name.clone(),
+            escaped_name,
+            kind,
+        });
+    }
+
+    if post_fields.is_empty() {
+        return output;
+    }
+
+    /* Per-field code that extends a running `offset` by that field's byte length, checked
+     * against overflow and (by the caller, afterwards) against `buf_sz`. Built up once so
+     * each field's function can replay the prefix it depends on. */
+    let mut offset_steps: Vec<String> = Vec::new();
+
+    if let Some(enum_field) = trailing_enum_field {
+        offset_steps.push(enum_fam_offset_step(&type_name, enum_field, resolved_type));
+    }
+
+    for info in post_fields.iter() {
+        let field_bytes_expr = match &info.kind {
+            CheckedFieldKind::Primitive { c_type } => format!("(uint64_t)sizeof( {} )", c_type),
+            CheckedFieldKind::Opaque { c_type } => format!("(uint64_t)sizeof( {} )", c_type),
+            CheckedFieldKind::Array { .. } => String::new(), // computed inline below (needs a safe_mul_u64)
+        };
+
+        let mut step = String::new();
+        write!(step, "  {{  /* field: {} */\n", info.raw_name).unwrap();
+        match &info.kind {
+            CheckedFieldKind::Array { elem_c_type, len_fn } => {
+                write!(
+                    step,
+                    "    uint64_t elem_count = {}( ({}_t const *)buffer );\n",
+                    len_fn, type_name
+                )
+                .unwrap();
+                write!(
+                    step,
+                    "    uint64_t field_bytes = 0;\n    if( safe_mul_u64( (uint64_t)sizeof( {} ), elem_count, &field_bytes ) ) return 2;\n",
+                    elem_c_type
+                )
+                .unwrap();
+            }
+            _ => {
+                write!(step, "    uint64_t field_bytes = {};\n", field_bytes_expr).unwrap();
+            }
+        }
+        write!(
+            step,
+            "    if( safe_add_u64( offset, field_bytes, &offset ) ) return 2;\n"
+        )
+        .unwrap();
+        write!(step, "    if( offset > buf_sz ) return 1;\n").unwrap();
+        write!(step, "  }}\n").unwrap();
+
+        offset_steps.push(step);
+    }
+
+    for (idx, info) in post_fields.iter().enumerate() {
+        let first_field_name = &post_fields[0].raw_name;
+
+        match &info.kind {
+            CheckedFieldKind::Primitive { c_type } => {
+                write!(
+                    output,
+                    "int {}_get_{}_checked( void const * buffer, uint64_t buf_sz, {} const ** out ) {{\n",
+                    type_name, info.escaped_name, c_type
+                )
+                .unwrap();
+            }
+            CheckedFieldKind::Opaque { c_type } => {
+                write!(
+                    output,
+                    "int {}_get_{}_checked( void const * buffer, uint64_t buf_sz, {} const ** out ) {{\n",
+                    type_name, info.escaped_name, c_type
+                )
+                .unwrap();
+            }
+            CheckedFieldKind::Array { elem_c_type, .. } => {
+                write!(
+                    output,
+                    "int {}_get_{}_checked( void const * buffer, uint64_t buf_sz, {} const ** out, uint64_t * len_out ) {{\n",
+                    type_name, info.escaped_name, elem_c_type
+                )
+                .unwrap();
+            }
+        }
+
+        write!(
+            output,
+            "  if( sizeof( {}_t ) > buf_sz ) return 1;\n",
+            type_name
+        )
+        .unwrap();
+        if trailing_enum_field.is_some() {
+            /* The FAM itself is an enum, which never gets a real struct member (see
+             * `types.rs`'s `format_struct_field`), so there's no later field name to anchor
+             * on; start from the struct's fixed-size prefix instead and walk the enum body's
+             * own runtime length via `offset_steps` below. */
+            write!(
+                output,
+                "  uint64_t offset = sizeof( {}_t );\n",
+                type_name
+            )
+            .unwrap();
+        } else {
+            write!(
+                output,
+                "  uint64_t offset = offsetof( {}_t, {} );\n",
+                type_name, first_field_name
+            )
+            .unwrap();
+        }
+        write!(output, "  if( offset > buf_sz ) return 1;\n").unwrap();
+
+        let prefix_steps = if trailing_enum_field.is_some() { idx + 1 } else { idx };
+        for step in offset_steps.iter().take(prefix_steps) {
+            output.push_str(step);
+        }
+
+        /* This field's own bytes: compute length, bounds-check, then hand back the pointer. */
+        match &info.kind {
+            CheckedFieldKind::Array { elem_c_type, len_fn } => {
+                write!(
+                    output,
+                    "  uint64_t elem_count = {}( ({}_t const *)buffer );\n",
+                    len_fn, type_name
+                )
+                .unwrap();
+                write!(
+                    output,
+                    "  uint64_t field_bytes = 0;\n  if( safe_mul_u64( (uint64_t)sizeof( {} ), elem_count, &field_bytes ) ) return 2;\n",
+                    elem_c_type
+                )
+                .unwrap();
+                write!(
+                    output,
+                    "  uint64_t end_offset = 0;\n  if( safe_add_u64( offset, field_bytes, &end_offset ) ) return 2;\n"
+                )
+                .unwrap();
+                write!(output, "  if( end_offset > buf_sz ) return 1;\n").unwrap();
+                write!(
+                    output,
+                    "  *out = ({} const *)((unsigned char const *)buffer + offset);\n",
+                    elem_c_type
+                )
+                .unwrap();
+                write!(output, "  *len_out = elem_count;\n").unwrap();
+            }
+            CheckedFieldKind::Primitive { c_type } | CheckedFieldKind::Opaque { c_type } => {
+                write!(
+                    output,
+                    "  uint64_t field_bytes = (uint64_t)sizeof( {} );\n",
+                    c_type
+                )
+                .unwrap();
+                write!(
+                    output,
+                    "  uint64_t end_offset = 0;\n  if( safe_add_u64( offset, field_bytes, &end_offset ) ) return 2;\n"
+                )
+                .unwrap();
+                write!(output, "  if( end_offset > buf_sz ) return 1;\n").unwrap();
+                write!(
+                    output,
+                    "  *out = ({} const *)((unsigned char const *)buffer + offset);\n",
+                    c_type
+                )
+                .unwrap();
+            }
+        }
+
+        write!(output, "  return 0;\n").unwrap();
+        write!(output, "}}\n\n").unwrap();
+    }
+
+    output
+}
+
+/* Like `helpers::generate_nested_field_access`, but evaluated against `buffer` (cast back to
+ * `{type}_t const *`) instead of `self` -- every function this module emits takes `buffer`, not
+ * `self`, so it has no `self` in scope to call the plain getters with. */
+fn nested_field_access_from_buffer(field_ref: &str, type_name: &str, indent: &str) -> String {
+    let var_name = field_ref.replace('.', "_");
+    format!(
+        "{}int64_t {} = (int64_t)({}_get_{}( ({}_t const *)buffer ));\n",
+        indent, var_name, type_name, var_name, type_name
+    )
+}
+
+/// Offset-walk step for a trailing FAM field that's an `Enum`: its body is never a real struct
+/// member (`types.rs`'s `format_struct_field` emits it as an inline comment), so its runtime
+/// length has to come from its own footprint rather than `sizeof()` on a C type. Mirrors the
+/// variant-switch `get.rs`'s `fam_offset_code` builds for this same case, just computing
+/// `field_bytes` up front so it can go through the usual `safe_add_u64`/`buf_sz` check.
+fn enum_fam_offset_step(
+    type_name: &str,
+    field: &ResolvedField,
+    resolved_type: &ResolvedType,
+) -> String {
+    let mut step = String::new();
+    write!(step, "  {{  /* field: {} (enum body) */\n", field.name).unwrap();
+
+    let (tag_expression, variants) = match &field.field_type.kind {
+        ResolvedTypeKind::Enum { tag_expression, variants, .. } => (tag_expression, variants),
+        _ => unreachable!("enum_fam_offset_step called on a non-enum field"),
+    };
+
+    // `field` is only ever the trailing FAM, so its size (and, transitively, the struct's own)
+    // is always `Size::Variable` -- callers never reach here with a constant-size enum.
+    {
+        let variable_refs = match &resolved_type.size {
+            Size::Variable(variable_refs) => variable_refs,
+            Size::Const(_) => {
+                write!(step, "    uint64_t field_bytes = 0;\n").unwrap();
+                write!(step, "  }}\n").unwrap();
+                return step;
+            }
+        };
+        let field_map = match variable_refs.get(&field.name) {
+            Some(field_map) => field_map,
+            None => {
+                write!(step, "    uint64_t field_bytes = 0;\n").unwrap();
+                write!(step, "  }}\n").unwrap();
+                return step;
+            }
+        };
+
+        let mut all_field_refs: BTreeMap<String, PrimitiveType> = BTreeMap::new();
+        for refs in variable_refs.values() {
+            for (ref_path, prim_type) in refs {
+                all_field_refs
+                    .entry(ref_path.clone())
+                    .or_insert_with(|| prim_type.clone());
+            }
+        }
+        let non_constant_refs: Vec<String> = all_field_refs.keys().cloned().collect();
+
+        let field_prefix = format!("{}.", field.name);
+        let mut variant_ref_map: std::collections::HashMap<String, Vec<String>> =
+            std::collections::HashMap::new();
+        let mut variant_ref_order: Vec<String> = Vec::new();
+        let mut declared_refs: HashSet<String> = HashSet::new();
+
+        for (field_ref, _prim_type) in field_map {
+            let field_ref_str = field_ref.as_str();
+            if field_ref_str.starts_with(&field_prefix) {
+                let remainder = &field_ref_str[field_prefix.len()..];
+                let variant_name = remainder.split('.').next().unwrap_or_default();
+                if !variant_name.is_empty() {
+                    variant_ref_map
+                        .entry(variant_name.to_string())
+                        .or_insert_with(Vec::new)
+                        .push(field_ref.clone());
+                    if !variant_ref_order.contains(field_ref) {
+                        variant_ref_order.push(field_ref.clone());
+                    }
+                    continue;
+                }
+            }
+            if declared_refs.insert(field_ref.clone()) {
+                step.push_str(&nested_field_access_from_buffer(field_ref_str, type_name, "    "));
+            }
+        }
+
+        if !variant_ref_map.is_empty() {
+            for field_ref in &variant_ref_order {
+                if declared_refs.insert(field_ref.clone()) {
+                    let var_name = field_ref.replace('.', "_");
+                    write!(step, "    int64_t {} = 0;\n", var_name).unwrap();
+                }
+            }
+
+            let tag_expr_str = format_expr_to_c(tag_expression, &non_constant_refs);
+            write!(step, "    switch ( {} ) {{\n", tag_expr_str).unwrap();
+
+            for variant in variants {
+                if let Size::Variable(_) = variant.variant_type.size {
+                    if let Some(refs) = variant_ref_map.get(&variant.name) {
+                        write!(step, "      case {}:\n", variant.tag_value).unwrap();
+                        write!(step, "      {{\n").unwrap();
+                        for field_ref in refs.iter() {
+                            let var_name = field_ref.replace('.', "_");
+                            write!(
+                                step,
+                                "        {} = (int64_t)({}_get_{}( ({}_t const *)buffer ));\n",
+                                var_name, type_name, var_name, type_name
+                            )
+                            .unwrap();
+                        }
+                        write!(step, "        break;\n").unwrap();
+                        write!(step, "      }}\n").unwrap();
+                    }
+                }
+            }
+
+            write!(step, "      default:\n").unwrap();
+            write!(step, "      {{\n").unwrap();
+            for field_ref in &variant_ref_order {
+                let var_name = field_ref.replace('.', "_");
+                write!(step, "        {} = 0;\n", var_name).unwrap();
+            }
+            write!(step, "        break;\n").unwrap();
+            write!(step, "      }}\n").unwrap();
+            write!(step, "    }}\n").unwrap();
+        }
+
+        let mut params: Vec<String> =
+            field_map.keys().map(|field_ref| field_ref.replace('.', "_")).collect();
+        params.sort();
+        write!(
+            step,
+            "    uint64_t field_bytes = {}_{}_inner_footprint( {} );\n",
+            type_name,
+            field.name,
+            params.join(", ")
+        )
+        .unwrap();
+    }
+
+    write!(
+        step,
+        "    if( safe_add_u64( offset, field_bytes, &offset ) ) return 2;\n"
+    )
+    .unwrap();
+    write!(step, "    if( offset > buf_sz ) return 1;\n").unwrap();
+    write!(step, "  }}\n").unwrap();
+
+    step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::expr::{ExprKind, FieldRefExpr};
+    use crate::abi::resolved::{ConstantStatus, ResolvedEnumVariant};
+    use crate::abi::types::IntegralType;
+    use std::collections::HashMap;
+
+    fn u32_primitive() -> ResolvedType {
+        ResolvedType {
+            name: "u32".into(),
+            size: Size::Const(4),
+            alignment: 4,
+            comment: None,
+            kind: ResolvedTypeKind::Primitive {
+                prim_type: PrimitiveType::Integral(IntegralType::U32),
+            },
+        }
+    }
+
+    /// `Msg { tag: u32, payload: enum(tag) { small: [u8; tag] }, checksum: u32 }` - a struct
+    /// whose trailing FAM is an enum, followed by a further named field. Exercises the bug the
+    /// old code couldn't handle: `checksum` has no `offsetof()` to anchor on, since the enum
+    /// body it follows was never emitted as a real struct member.
+    fn enum_fam_then_field_struct() -> ResolvedType {
+        let tag_field = ResolvedField {
+            name: "tag".into(),
+            field_type: u32_primitive(),
+            offset: Some(0),
+        };
+
+        let small_variant_refs: HashMap<String, PrimitiveType> =
+            [("tag".to_string(), PrimitiveType::Integral(IntegralType::U32))]
+                .into_iter()
+                .collect();
+        let small_variant = ResolvedEnumVariant {
+            name: "small".into(),
+            tag_value: 0,
+            variant_type: ResolvedType {
+                name: "Msg_payload_small_inner".into(),
+                size: Size::Variable(
+                    [("small".to_string(), small_variant_refs)].into_iter().collect(),
+                ),
+                alignment: 1,
+                comment: None,
+                kind: ResolvedTypeKind::Array {
+                    element_type: Box::new(ResolvedType {
+                        name: "u8".into(),
+                        size: Size::Const(1),
+                        alignment: 1,
+                        comment: None,
+                        kind: ResolvedTypeKind::Primitive {
+                            prim_type: PrimitiveType::Integral(IntegralType::U8),
+                        },
+                    }),
+                    size_expression: ExprKind::FieldRef(FieldRefExpr {
+                        path: vec!["tag".into()],
+                    }),
+                    size_constant_status: ConstantStatus::NonConstant(HashMap::new()),
+                },
+            },
+        };
+
+        let payload_refs: HashMap<String, PrimitiveType> =
+            [("payload.small.tag".to_string(), PrimitiveType::Integral(IntegralType::U32))]
+                .into_iter()
+                .collect();
+        let payload_field = ResolvedField {
+            name: "payload".into(),
+            field_type: ResolvedType {
+                name: "Msg_payload".into(),
+                size: Size::Variable([("payload".to_string(), payload_refs.clone())].into_iter().collect()),
+                alignment: 1,
+                comment: None,
+                kind: ResolvedTypeKind::Enum {
+                    tag_expression: ExprKind::FieldRef(FieldRefExpr {
+                        path: vec!["tag".into()],
+                    }),
+                    tag_constant_status: ConstantStatus::NonConstant(HashMap::new()),
+                    variants: vec![small_variant],
+                },
+            },
+            offset: None,
+        };
+
+        let checksum_field = ResolvedField {
+            name: "checksum".into(),
+            field_type: u32_primitive(),
+            offset: None,
+        };
+
+        ResolvedType {
+            name: "Msg".into(),
+            size: Size::Variable([("payload".to_string(), payload_refs)].into_iter().collect()),
+            alignment: 4,
+            comment: None,
+            kind: ResolvedTypeKind::Struct {
+                fields: vec![tag_field, payload_field, checksum_field],
+                packed: false,
+                custom_alignment: None,
+            },
+        }
+    }
+
+    #[test]
+    fn checked_getter_after_enum_fam_anchors_on_struct_size_not_offsetof() {
+        let output = emit_checked_accessor_fn(&enum_fam_then_field_struct());
+
+        assert!(
+            output.contains("uint64_t offset = sizeof( Msg_t );"),
+            "expected the offset walk to seed from sizeof(Msg_t), got:\n{}",
+            output
+        );
+        assert!(
+            !output.contains("offsetof( Msg_t, checksum )"),
+            "checksum is never a real struct member when preceded by an enum FAM, so \
+             offsetof() on it can't compile:\n{}",
+            output
+        );
+        assert!(
+            output.contains("Msg_payload_inner_footprint("),
+            "the enum body's own runtime length must be walked before checksum's offset:\n{}",
+            output
+        );
+    }
+}
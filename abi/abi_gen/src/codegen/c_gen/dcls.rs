@@ -1,6 +1,6 @@
 use super::helpers::{
-    escape_c_keyword, format_type_to_c, get_c_accessor_type, is_nested_complex_type,
-    primitive_to_c_type, sanitize_type_name,
+    escape_c_keyword, format_type_to_c, get_c_accessor_type, init_err_const, init_err_type_name,
+    is_nested_complex_type, primitive_to_c_type, sanitize_type_name,
 };
 use super::ir_footprint::{format_ir_parameter_list, sanitize_symbol};
 use crate::abi::resolved::{ResolvedType, ResolvedTypeKind, Size};
@@ -560,6 +560,34 @@ pub fn emit_forward_declarations(resolved_type: &ResolvedType, type_ir: Option<&
             }
 
             /* Init function declarations */
+            if matches!(
+                &resolved_type.kind,
+                ResolvedTypeKind::Struct { .. } | ResolvedTypeKind::Union { .. }
+            ) {
+                let err_type = init_err_type_name(&type_name);
+                output.push_str("typedef enum {\n");
+                output.push_str(&format!(
+                    "  {} = 0,\n",
+                    init_err_const(&type_name, "NONE")
+                ));
+                output.push_str(&format!(
+                    "  {},\n",
+                    init_err_const(&type_name, "BUFFER_TOO_SMALL")
+                ));
+                output.push_str(&format!(
+                    "  {},\n",
+                    init_err_const(&type_name, "SIZE_OVERFLOW")
+                ));
+                output.push_str(&format!(
+                    "  {},\n",
+                    init_err_const(&type_name, "FIELD_TOO_LARGE")
+                ));
+                output.push_str(&format!(
+                    "  {},\n",
+                    init_err_const(&type_name, "VALIDATE_FAILED")
+                ));
+                output.push_str(&format!("}} {};\n", err_type));
+            }
             match &resolved_type.kind {
                 ResolvedTypeKind::Struct { fields, .. } => {
                     let mut field_param_lines: Vec<String> = Vec::new();
@@ -622,26 +650,26 @@ pub fn emit_forward_declarations(resolved_type: &ResolvedType, type_ir: Option<&
                         }
                     }
 
+                    let err_type = init_err_type_name(&type_name);
                     if field_param_lines.is_empty() {
                         output.push_str(&format!(
-                            "int {}_init( void * buffer, uint64_t buf_sz );\n",
-                            type_name
+                            "int {}_init( void * buffer, uint64_t buf_sz, {} * err_out, int64_t * err_field_index_out );\n",
+                            type_name, err_type
                         ));
                     } else {
                         output.push_str(&format!(
                             "int {}_init( void * buffer, uint64_t buf_sz,\n",
                             type_name
                         ));
-                        for (idx, line) in field_param_lines.iter().enumerate() {
-                            let suffix = if idx + 1 == field_param_lines.len() {
-                                "\n"
-                            } else {
-                                ",\n"
-                            };
+                        for line in field_param_lines.iter() {
                             output.push_str("  ");
                             output.push_str(line);
-                            output.push_str(suffix);
+                            output.push_str(",\n");
                         }
+                        output.push_str(&format!(
+                            "  {} * err_out, int64_t * err_field_index_out\n",
+                            err_type
+                        ));
                         output.push_str(");\n");
                     }
                     output.push_str(&format!(
@@ -654,7 +682,8 @@ pub fn emit_forward_declarations(resolved_type: &ResolvedType, type_ir: Option<&
                         type_name, type_name
                     ));
                 }
-                ResolvedTypeKind::Union { variants } => {
+                ResolvedTypeKind::Union { variants, .. } => {
+                    let err_type = init_err_type_name(&type_name);
                     for variant in variants {
                         let escaped_variant = escape_c_keyword(&variant.name);
                         let param_decl = match &variant.field_type.kind {
@@ -684,8 +713,8 @@ pub fn emit_forward_declarations(resolved_type: &ResolvedType, type_ir: Option<&
                         };
 
                         output.push_str(&format!(
-                            "int {}_init_{}( void * buffer, uint64_t buf_sz, {} );\n",
-                            type_name, escaped_variant, param_decl
+                            "int {}_init_{}( void * buffer, uint64_t buf_sz, {}, {} * err_out, int64_t * err_field_index_out );\n",
+                            type_name, escaped_variant, param_decl, err_type
                         ));
                     }
                     output.push_str(&format!(
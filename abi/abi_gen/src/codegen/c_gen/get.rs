@@ -894,9 +894,15 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
     let mut output = String::new();
     let type_name = sanitize_type_name(&resolved_type.name);
 
-    if let ResolvedTypeKind::Union { variants } = &resolved_type.kind {
+    if let ResolvedTypeKind::Union { variants, tagged } = &resolved_type.kind {
         for variant in variants {
             let escaped_variant_name = escape_c_keyword(&variant.name);
+            /* See emit_init_fn_union: tagged payloads live under self->value. */
+            let field_path = if tagged.is_some() {
+                format!("value.{}", escaped_variant_name)
+            } else {
+                escaped_variant_name.clone()
+            };
 
             match &variant.field_type.kind {
                 ResolvedTypeKind::Primitive { .. } => {
@@ -911,7 +917,7 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
                     writeln!(
                         output,
                         "  memcpy( &value, &self->{}, sizeof( value ) );",
-                        escaped_variant_name
+                        field_path
                     )
                     .unwrap();
                     writeln!(output, "  return value;").unwrap();
@@ -929,7 +935,7 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
                         return_type, type_name, escaped_variant_name, type_name
                     )
                     .unwrap();
-                    writeln!(output, "  return self->{};", escaped_variant_name).unwrap();
+                    writeln!(output, "  return self->{};", field_path).unwrap();
                     writeln!(output, "}}\n").unwrap();
                 }
                 ResolvedTypeKind::TypeRef { target_name, .. } => {
@@ -941,7 +947,7 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
                         const_return_type, type_name, escaped_variant_name, type_name
                     )
                     .unwrap();
-                    writeln!(output, "  return &self->{};", escaped_variant_name).unwrap();
+                    writeln!(output, "  return &self->{};", field_path).unwrap();
                     writeln!(output, "}}\n").unwrap();
 
                     /* Mutable getter */
@@ -952,7 +958,7 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
                         mut_return_type, type_name, escaped_variant_name, type_name
                     )
                     .unwrap();
-                    writeln!(output, "  return &self->{};", escaped_variant_name).unwrap();
+                    writeln!(output, "  return &self->{};", field_path).unwrap();
                     writeln!(output, "}}\n").unwrap();
                 }
                 _ => {
@@ -965,7 +971,7 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
                         const_return_type, type_name, escaped_variant_name, type_name
                     )
                     .unwrap();
-                    writeln!(output, "  return &self->{};", escaped_variant_name).unwrap();
+                    writeln!(output, "  return &self->{};", field_path).unwrap();
                     writeln!(output, "}}\n").unwrap();
 
                     /* Mutable getter */
@@ -977,25 +983,51 @@ pub fn emit_accessor_fn_union(resolved_type: &ResolvedType) -> String {
                         mut_return_type, type_name, escaped_variant_name, type_name
                     )
                     .unwrap();
-                    writeln!(output, "  return &self->{};", escaped_variant_name).unwrap();
+                    writeln!(output, "  return &self->{};", field_path).unwrap();
                     writeln!(output, "}}\n").unwrap();
                 }
             }
         }
 
-        writeln!(
-            output,
-            "void const * {}_get_variant( {}_t const * self ) {{",
-            type_name, type_name
-        )
-        .unwrap();
-        writeln!(
-            output,
-            "  /* WARNING: unchecked accessor; caller must know which variant is active */"
-        )
-        .unwrap();
-        writeln!(output, "  return (void const *)self;").unwrap();
-        writeln!(output, "}}\n").unwrap();
+        if let Some(_width) = tagged {
+            writeln!(
+                output,
+                "uint32_t {}_get_tag( {}_t const * self ) {{",
+                type_name, type_name
+            )
+            .unwrap();
+            writeln!(output, "  return (uint32_t)self->tag;").unwrap();
+            writeln!(output, "}}\n").unwrap();
+
+            writeln!(
+                output,
+                "void const * {}_get_variant( {}_t const * self ) {{",
+                type_name, type_name
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "  /* WARNING: unchecked accessor; caller must know which variant is active. Use {}_get_tag to read the discriminant. */",
+                type_name
+            )
+            .unwrap();
+            writeln!(output, "  return (void const *)&self->value;").unwrap();
+            writeln!(output, "}}\n").unwrap();
+        } else {
+            writeln!(
+                output,
+                "void const * {}_get_variant( {}_t const * self ) {{",
+                type_name, type_name
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "  /* WARNING: unchecked accessor; caller must know which variant is active */"
+            )
+            .unwrap();
+            writeln!(output, "  return (void const *)self;").unwrap();
+            writeln!(output, "}}\n").unwrap();
+        }
     }
 
     output
@@ -1,6 +1,6 @@
 use crate::abi::expr::{ExprKind, LiteralExpr};
 use crate::abi::resolved::{ResolvedType, ResolvedTypeKind, Size};
-use crate::abi::types::{FloatingPointType, IntegralType, PrimitiveType};
+use crate::abi::types::{FloatingPointType, IntegralType, PrimitiveType, TagWidth};
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 
@@ -304,6 +304,17 @@ pub fn sanitize_type_name(name: &str) -> String {
     escape_c_keyword(&name.replace("::", "_"))
 }
 
+/// Name of the structured error enum emitted alongside a type's `_init` functions.
+pub fn init_err_type_name(type_name: &str) -> String {
+    format!("{}_init_err_t", type_name)
+}
+
+/// Name of one of that enum's constants, e.g. `init_err_const("foo", "BUFFER_TOO_SMALL")`
+/// yields `"FOO_INIT_ERR_BUFFER_TOO_SMALL"`.
+pub fn init_err_const(type_name: &str, suffix: &str) -> String {
+    format!("{}_INIT_ERR_{}", type_name.to_uppercase(), suffix)
+}
+
 pub fn primitive_to_c_type(prim_type: &PrimitiveType) -> &'static str {
     match prim_type {
         PrimitiveType::Integral(int_type) => match int_type {
@@ -325,6 +336,15 @@ pub fn primitive_to_c_type(prim_type: &PrimitiveType) -> &'static str {
     }
 }
 
+/* C integer type used to store a tagged union's leading discriminant. */
+pub fn tag_width_c_type(width: &TagWidth) -> &'static str {
+    match width {
+        TagWidth::One => "uint8_t",
+        TagWidth::Two => "uint16_t",
+        TagWidth::Four => "uint32_t",
+    }
+}
+
 /* Generate code to access a nested field reference, eg "box.first" */
 pub fn generate_nested_field_access(
     field_ref: &str,
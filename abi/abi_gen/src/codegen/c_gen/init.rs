@@ -1,11 +1,41 @@
 use super::helpers::{
-    escape_c_keyword, format_expr_to_c, format_type_to_c, is_nested_complex_type,
-    primitive_to_c_type, sanitize_type_name,
+    escape_c_keyword, format_expr_to_c, format_type_to_c, init_err_const, init_err_type_name,
+    is_nested_complex_type, primitive_to_c_type, sanitize_type_name,
 };
 use crate::abi::resolved::{ResolvedType, ResolvedTypeKind, Size};
 use std::fmt::Write;
 
-fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
+/// Static helper shared by every `_init` entry point emitted for this type: it
+/// records a structured error code -- and, where known, the offending field
+/// index -- through the caller's optional out-params (mirroring the
+/// `NULL`-capable third argument `{type}_validate` already takes) before
+/// returning the legacy `-1` status.
+fn emit_init_fail_helper(type_name: &str) -> String {
+    let err_type = init_err_type_name(type_name);
+    let mut output = String::new();
+    write!(
+        output,
+        "static int {}_init_fail( {} * err_out, int64_t * err_field_index_out, {} code, int64_t field_index ) {{\n",
+        type_name, err_type, err_type
+    )
+    .unwrap();
+    write!(output, "  if( err_out != NULL ) *err_out = code;\n").unwrap();
+    write!(
+        output,
+        "  if( err_field_index_out != NULL ) *err_field_index_out = field_index;\n"
+    )
+    .unwrap();
+    write!(output, "  return -1;\n").unwrap();
+    write!(output, "}}\n\n").unwrap();
+    output
+}
+
+/// Emits the `{type}_init` constructor for a struct type. When `strided` is set, array
+/// fields additionally accept a `uint64_t {param}_stride` byte stride and are copied
+/// element-by-element, so callers can initialize an array field directly from a
+/// non-contiguous view (e.g. a matrix column) instead of compacting into a temp buffer
+/// first; the contiguous case (`stride == elem_size`) still takes the plain `memcpy` path.
+fn emit_init_fn_struct(resolved_type: &ResolvedType, strided: bool) -> String {
     let mut output = String::new();
     let type_name = sanitize_type_name(&resolved_type.name);
 
@@ -23,6 +53,7 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
         Array {
             len_name: String,
             elem_size_expr: String,
+            stride_param_name: Option<String>,
         },
         ConstPointer {
             size_expr: String,
@@ -37,12 +68,13 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
         param_name: String,
         init_kind: FieldInitKind,
         is_fam: bool,
+        field_index: usize,
     }
 
     let mut field_param_lines: Vec<String> = Vec::new();
     let mut field_infos: Vec<FieldInitInfo> = Vec::new();
 
-    for (_idx, field) in fields.iter().enumerate() {
+    for (field_index, field) in fields.iter().enumerate() {
         let param_name = escape_c_keyword(&field.name);
         let is_fam = matches!(&field.field_type.size, Size::Variable(_));
 
@@ -62,6 +94,7 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                         size_expr: format!("sizeof( {} )", type_str),
                     },
                     is_fam,
+                    field_index,
                 });
             }
             ResolvedTypeKind::Array { element_type, .. } => {
@@ -71,12 +104,28 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                     element_param_type = format!("{}_{}_inner_t", type_name, field.name);
                 }
 
-                field_param_lines.push(format!(
-                    "{} const * {}, uint64_t {}",
-                    element_param_type,
-                    param_name.clone(),
-                    len_name.clone()
-                ));
+                let stride_param_name = if strided {
+                    Some(format!("{}_stride", param_name))
+                } else {
+                    None
+                };
+
+                if let Some(stride_param) = &stride_param_name {
+                    field_param_lines.push(format!(
+                        "{} const * {}, uint64_t {}, uint64_t {}",
+                        element_param_type,
+                        param_name.clone(),
+                        len_name.clone(),
+                        stride_param
+                    ));
+                } else {
+                    field_param_lines.push(format!(
+                        "{} const * {}, uint64_t {}",
+                        element_param_type,
+                        param_name.clone(),
+                        len_name.clone()
+                    ));
+                }
 
                 field_infos.push(FieldInitInfo {
                     raw_name: field.name.clone(),
@@ -84,8 +133,10 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                     init_kind: FieldInitKind::Array {
                         len_name,
                         elem_size_expr: format!("sizeof( {} )", element_param_type),
+                        stride_param_name,
                     },
                     is_fam,
+                    field_index,
                 });
             }
             _ => {
@@ -105,6 +156,7 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                         param_name: param_name.clone(),
                         init_kind: FieldInitKind::VarPointer { size_param_name },
                         is_fam: true, /* Enums are treated like FAMs */
+                        field_index,
                     });
                 } else {
                     /* Regular complex types (TypeRef, Union, etc.) */
@@ -128,6 +180,7 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                             param_name: param_name.clone(),
                             init_kind: FieldInitKind::VarPointer { size_param_name },
                             is_fam,
+                            field_index,
                         });
                     } else {
                         /* Constant-sized: use sizeof */
@@ -143,6 +196,7 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                                 size_expr: format!("sizeof( {} )", pointer_type),
                             },
                             is_fam,
+                            field_index,
                         });
                     }
                 }
@@ -150,33 +204,41 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
         }
     }
 
+    let fn_name = if strided {
+        format!("{}_init_strided", type_name)
+    } else {
+        format!("{}_init", type_name)
+    };
+
+    let err_type = init_err_type_name(&type_name);
     if field_param_lines.is_empty() {
         write!(
             output,
-            "int {}_init( void * buffer, uint64_t buf_sz ) {{\n",
-            type_name
+            "int {}( void * buffer, uint64_t buf_sz, {} * err_out, int64_t * err_field_index_out ) {{\n",
+            fn_name, err_type
         )
         .unwrap();
     } else {
+        write!(output, "int {}( void * buffer, uint64_t buf_sz,\n", fn_name).unwrap();
+        for line in field_param_lines.iter() {
+            write!(output, "  {},\n", line).unwrap();
+        }
         write!(
             output,
-            "int {}_init( void * buffer, uint64_t buf_sz,\n",
-            type_name
+            "  {} * err_out, int64_t * err_field_index_out ) {{\n",
+            err_type
         )
         .unwrap();
-        for (idx, line) in field_param_lines.iter().enumerate() {
-            let suffix = if idx + 1 == field_param_lines.len() {
-                "\n"
-            } else {
-                ",\n"
-            };
-            write!(output, "  {}{}", line, suffix).unwrap();
-        }
-        write!(output, ") {{\n").unwrap();
     }
 
     write!(output, "  if( sizeof( {}_t ) > buf_sz ) {{\n", type_name).unwrap();
-    write!(output, "    return -1; /* Buffer too small */\n").unwrap();
+    write!(
+        output,
+        "    return {}_init_fail( err_out, err_field_index_out, {}, -1 );\n",
+        type_name,
+        init_err_const(&type_name, "BUFFER_TOO_SMALL")
+    )
+    .unwrap();
     write!(output, "  }}\n").unwrap();
 
     let mut after_variable_size_data = false;
@@ -194,6 +256,12 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
         if !after_variable_size_data {
             continue;
         }
+        let size_overflow_return = format!(
+            "return {}_init_fail( err_out, err_field_index_out, {}, {} );",
+            type_name,
+            init_err_const(&type_name, "SIZE_OVERFLOW"),
+            info.field_index
+        );
         match &info.init_kind {
             FieldInitKind::Primitive { size_expr } => {
                 let field_size = format!("(uint64_t)({})", size_expr);
@@ -201,7 +269,8 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                 write!(output, "    uint64_t field_bytes = {};\n", field_size).unwrap();
                 write!(
                     output,
-                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) return -1;\n"
+                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) {}\n",
+                    size_overflow_return
                 )
                 .unwrap();
                 write!(output, "  }}\n").unwrap();
@@ -209,6 +278,7 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
             FieldInitKind::Array {
                 len_name,
                 elem_size_expr,
+                ..
             } => {
                 let elem_size = format!("(uint64_t)({})", elem_size_expr);
                 write!(output, "  {{  /* field: {} */\n", info.raw_name).unwrap();
@@ -216,13 +286,14 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                 write!(output, "    uint64_t field_bytes = 0;\n").unwrap();
                 write!(
                     output,
-                    "    if( safe_mul_u64( elem_size, {}, &field_bytes ) ) return -1;\n",
-                    len_name
+                    "    if( safe_mul_u64( elem_size, {}, &field_bytes ) ) {}\n",
+                    len_name, size_overflow_return
                 )
                 .unwrap();
                 write!(
                     output,
-                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) return -1;\n"
+                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) {}\n",
+                    size_overflow_return
                 )
                 .unwrap();
                 write!(output, "  }}\n").unwrap();
@@ -233,7 +304,8 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                 write!(output, "    uint64_t field_bytes = {};\n", field_size).unwrap();
                 write!(
                     output,
-                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) return -1;\n"
+                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) {}\n",
+                    size_overflow_return
                 )
                 .unwrap();
                 write!(output, "  }}\n").unwrap();
@@ -248,13 +320,21 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
                 write!(output, "    uint64_t field_bytes = {};\n", size_param_name).unwrap();
                 write!(
                     output,
-                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) return -1;\n"
+                    "    if( safe_add_u64( offset, field_bytes, &offset ) ) {}\n",
+                    size_overflow_return
                 )
                 .unwrap();
                 write!(output, "  }}\n").unwrap();
             }
         }
-        write!(output, "  if( offset > buf_sz ) return -1;\n").unwrap();
+        write!(
+            output,
+            "  if( offset > buf_sz ) return {}_init_fail( err_out, err_field_index_out, {}, {} );\n",
+            type_name,
+            init_err_const(&type_name, "FIELD_TOO_LARGE"),
+            info.field_index
+        )
+        .unwrap();
     }
 
     /* Pre-compute which fields come after variable-size data by scanning original fields list */
@@ -328,44 +408,77 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
             FieldInitKind::Array {
                 len_name,
                 elem_size_expr,
+                stride_param_name,
             } => {
                 let elem_size = format!("(uint64_t)({})", elem_size_expr);
-                if after_variable_size_data {
-                    write!(output, "  {{  /* field: {} */\n", info.raw_name).unwrap();
-                    write!(output, "    uint64_t elem_size = {};\n", elem_size).unwrap();
-                    write!(output, "    uint64_t field_bytes = 0;\n").unwrap();
+                let dest_expr = if after_variable_size_data {
+                    "(unsigned char *)self + offset".to_string()
+                } else {
+                    format!("(unsigned char *)self->{}", info.raw_name)
+                };
+
+                write!(output, "  {{  /* field: {} */\n", info.raw_name).unwrap();
+                write!(output, "    uint64_t elem_size = {};\n", elem_size).unwrap();
+                write!(output, "    uint64_t field_bytes = 0;\n").unwrap();
+                write!(
+                    output,
+                    "    if( safe_mul_u64( elem_size, {}, &field_bytes ) ) return -1;\n",
+                    len_name
+                )
+                .unwrap();
+
+                if let Some(stride_param) = stride_param_name {
+                    write!(output, "    if( {} == elem_size ) {{\n", stride_param).unwrap();
                     write!(
                         output,
-                        "    if( safe_mul_u64( elem_size, {}, &field_bytes ) ) return -1;\n",
-                        len_name
+                        "      memcpy( {}, {}, field_bytes );\n",
+                        dest_expr, info.param_name
                     )
                     .unwrap();
+                    write!(output, "    }} else {{\n").unwrap();
+                    write!(output, "      uint64_t span = 0;\n").unwrap();
+                    write!(output, "      if( {} > 0 ) {{\n", len_name).unwrap();
+                    write!(output, "        uint64_t max_offset = 0;\n").unwrap();
                     write!(
                         output,
-                        "    memcpy( (unsigned char *)self + offset, {}, field_bytes );\n",
-                        info.param_name
+                        "        if( safe_mul_u64( {} - 1, {}, &max_offset ) ) return -1;\n",
+                        len_name, stride_param
                     )
                     .unwrap();
-                    write!(output, "    offset += field_bytes;\n").unwrap();
-                    write!(output, "  }}\n").unwrap();
-                } else {
-                    write!(output, "  {{  /* field: {} */\n", info.raw_name).unwrap();
-                    write!(output, "    uint64_t elem_size = {};\n", elem_size).unwrap();
-                    write!(output, "    uint64_t field_bytes = 0;\n").unwrap();
                     write!(
                         output,
-                        "    if( safe_mul_u64( elem_size, {}, &field_bytes ) ) return -1;\n",
+                        "        if( safe_add_u64( max_offset, elem_size, &span ) ) return -1;\n"
+                    )
+                    .unwrap();
+                    write!(output, "      }}\n").unwrap();
+                    write!(output, "      (void)span;\n").unwrap();
+                    write!(
+                        output,
+                        "      for( uint64_t i = 0; i < {}; i++ ) {{\n",
                         len_name
                     )
                     .unwrap();
                     write!(
                         output,
-                        "    memcpy( self->{}, {}, field_bytes );\n",
-                        info.raw_name, info.param_name
+                        "        memcpy( {} + i * elem_size, (unsigned char const *){} + i * {}, elem_size );\n",
+                        dest_expr, info.param_name, stride_param
+                    )
+                    .unwrap();
+                    write!(output, "      }}\n").unwrap();
+                    write!(output, "    }}\n").unwrap();
+                } else {
+                    write!(
+                        output,
+                        "    memcpy( {}, {}, field_bytes );\n",
+                        dest_expr, info.param_name
                     )
                     .unwrap();
-                    write!(output, "  }}\n").unwrap();
                 }
+
+                if after_variable_size_data {
+                    write!(output, "    offset += field_bytes;\n").unwrap();
+                }
+                write!(output, "  }}\n").unwrap();
             }
             FieldInitKind::ConstPointer { size_expr } => {
                 let field_size = format!("(uint64_t)({})", size_expr);
@@ -424,7 +537,20 @@ fn emit_init_fn_struct(resolved_type: &ResolvedType) -> String {
         type_name
     )
     .unwrap();
-    write!(output, "  if( err ) return err;\n").unwrap();
+    write!(output, "  if( err ) {{\n").unwrap();
+    write!(
+        output,
+        "    if( err_out != NULL ) *err_out = {};\n",
+        init_err_const(&type_name, "VALIDATE_FAILED")
+    )
+    .unwrap();
+    write!(
+        output,
+        "    if( err_field_index_out != NULL ) *err_field_index_out = -1;\n"
+    )
+    .unwrap();
+    write!(output, "    return err;\n").unwrap();
+    write!(output, "  }}\n").unwrap();
     write!(output, "  return 0;\n").unwrap();
     write!(output, "}}\n\n").unwrap();
 
@@ -435,13 +561,22 @@ fn emit_init_fn_union(resolved_type: &ResolvedType) -> String {
     let mut output = String::new();
     let type_name = sanitize_type_name(&resolved_type.name);
 
-    let variants = match &resolved_type.kind {
-        ResolvedTypeKind::Union { variants } => variants,
+    let (variants, tagged) = match &resolved_type.kind {
+        ResolvedTypeKind::Union { variants, tagged } => (variants, tagged),
         _ => return output,
     };
 
-    for variant in variants {
+    let err_type = init_err_type_name(&type_name);
+    for (variant_index, variant) in variants.iter().enumerate() {
         let escaped_variant = escape_c_keyword(&variant.name);
+        /* When the union is tagged, the payload lives under `self->value`
+         * rather than directly on `self`, to make room for the leading
+         * discriminant field. */
+        let field_path = if tagged.is_some() {
+            format!("value.{}", escaped_variant)
+        } else {
+            escaped_variant.clone()
+        };
 
         let mut array_size_expr: Option<String> = None;
         let param_decl = match &variant.field_type.kind {
@@ -476,12 +611,18 @@ fn emit_init_fn_union(resolved_type: &ResolvedType) -> String {
 
         write!(
             output,
-            "int {}_init_{}( void * buffer, uint64_t buf_sz, {} ) {{\n",
-            type_name, escaped_variant, param_decl
+            "int {}_init_{}( void * buffer, uint64_t buf_sz, {}, {} * err_out, int64_t * err_field_index_out ) {{\n",
+            type_name, escaped_variant, param_decl, err_type
         )
         .unwrap();
         write!(output, "  if( sizeof( {}_t ) > buf_sz ) {{\n", type_name).unwrap();
-        write!(output, "    return -1; /* Buffer too small */\n").unwrap();
+        write!(
+            output,
+            "    return {}_init_fail( err_out, err_field_index_out, {}, -1 );\n",
+            type_name,
+            init_err_const(&type_name, "BUFFER_TOO_SMALL")
+        )
+        .unwrap();
         write!(output, "  }}\n").unwrap();
         write!(
             output,
@@ -489,12 +630,15 @@ fn emit_init_fn_union(resolved_type: &ResolvedType) -> String {
             type_name, type_name
         )
         .unwrap();
+        if tagged.is_some() {
+            write!(output, "  self->tag = {};\n", variant_index).unwrap();
+        }
         match &variant.field_type.kind {
             ResolvedTypeKind::Primitive { .. } => {
                 write!(
                     output,
-                    "  memcpy( &self->{}, &value, sizeof( self->{} ) );\n",
-                    escaped_variant, escaped_variant
+                    "  memcpy( &self->{0}, &value, sizeof( self->{0} ) );\n",
+                    field_path
                 )
                 .unwrap();
             }
@@ -504,16 +648,16 @@ fn emit_init_fn_union(resolved_type: &ResolvedType) -> String {
                 }
                 write!(
                     output,
-                    "  memcpy( self->{}, value, len * sizeof self->{}[0] );\n",
-                    escaped_variant, escaped_variant
+                    "  memcpy( self->{0}, value, len * sizeof self->{0}[0] );\n",
+                    field_path
                 )
                 .unwrap();
             }
             _ => {
                 write!(
                     output,
-                    "  memcpy( &self->{}, value, sizeof( self->{} ) );\n",
-                    escaped_variant, escaped_variant
+                    "  memcpy( &self->{0}, value, sizeof( self->{0} ) );\n",
+                    field_path
                 )
                 .unwrap();
             }
@@ -524,7 +668,212 @@ fn emit_init_fn_union(resolved_type: &ResolvedType) -> String {
             type_name
         )
         .unwrap();
-        write!(output, "  if( err ) return err;\n").unwrap();
+        write!(output, "  if( err ) {{\n").unwrap();
+        write!(
+            output,
+            "    if( err_out != NULL ) *err_out = {};\n",
+            init_err_const(&type_name, "VALIDATE_FAILED")
+        )
+        .unwrap();
+        write!(
+            output,
+            "    if( err_field_index_out != NULL ) *err_field_index_out = -1;\n"
+        )
+        .unwrap();
+        write!(output, "    return err;\n").unwrap();
+        write!(output, "  }}\n").unwrap();
+        write!(output, "  return 0;\n").unwrap();
+        write!(output, "}}\n\n").unwrap();
+    }
+
+    output
+}
+
+fn emit_init_fn_size_discriminated_union(resolved_type: &ResolvedType) -> String {
+    let mut output = String::new();
+    let type_name = sanitize_type_name(&resolved_type.name);
+
+    let variants = match &resolved_type.kind {
+        ResolvedTypeKind::SizeDiscriminatedUnion { variants } => variants,
+        _ => return output,
+    };
+
+    /* Codegen-time ambiguity check: the active variant is recovered purely from buf_sz,
+     * so no two variants may claim the same size, and a variable-sized (FAM) variant's
+     * open-ended range starting at expected_size (no upper bound) must not collide with
+     * any other variant's size/range, or `{type}_validate` would have no way to pick one. */
+    let mut fixed_sizes: Vec<(&str, u64)> = Vec::new();
+    let mut variable_mins: Vec<(&str, u64)> = Vec::new();
+    for variant in variants {
+        match &variant.variant_type.size {
+            Size::Const(_) => fixed_sizes.push((variant.name.as_str(), variant.expected_size)),
+            Size::Variable(_) => variable_mins.push((variant.name.as_str(), variant.expected_size)),
+        }
+    }
+    for i in 0..fixed_sizes.len() {
+        for j in (i + 1)..fixed_sizes.len() {
+            if fixed_sizes[i].1 == fixed_sizes[j].1 {
+                panic!(
+                    "SizeDiscriminatedUnion `{}`: variants `{}` and `{}` both have size {} bytes; the active variant cannot be recovered from size alone",
+                    resolved_type.name, fixed_sizes[i].0, fixed_sizes[j].0, fixed_sizes[i].1
+                );
+            }
+        }
+    }
+    if variable_mins.len() > 1 {
+        panic!(
+            "SizeDiscriminatedUnion `{}`: variants `{}` and `{}` are both variable-sized; their open-ended size ranges always overlap, so the active variant cannot be recovered from size alone",
+            resolved_type.name, variable_mins[0].0, variable_mins[1].0
+        );
+    }
+    for (fixed_name, fixed_size) in &fixed_sizes {
+        for (var_name, var_min) in &variable_mins {
+            if fixed_size >= var_min {
+                panic!(
+                    "SizeDiscriminatedUnion `{}`: fixed-size variant `{}` ({} bytes) overlaps the range of variable-sized variant `{}` (>= {} bytes); the active variant cannot be recovered from size alone",
+                    resolved_type.name, fixed_name, fixed_size, var_name, var_min
+                );
+            }
+        }
+    }
+
+    let err_type = init_err_type_name(&type_name);
+    for variant in variants {
+        let escaped_variant = escape_c_keyword(&variant.name);
+        let is_variable = matches!(&variant.variant_type.size, Size::Variable(_));
+
+        let mut array_size_expr: Option<String> = None;
+        let param_decl = if is_variable {
+            "void const * value, uint64_t trailing_len".to_string()
+        } else {
+            match &variant.variant_type.kind {
+                ResolvedTypeKind::Primitive { .. } => {
+                    let type_str = format_type_to_c(&variant.variant_type);
+                    format!("{} value", type_str)
+                }
+                ResolvedTypeKind::Array {
+                    element_type,
+                    size_expression,
+                    ..
+                } => {
+                    let mut element_c_type = format_type_to_c(element_type);
+                    if is_nested_complex_type(element_type) {
+                        element_c_type = format!("{}_{}_inner_t", type_name, escaped_variant);
+                    }
+                    array_size_expr = Some(format_expr_to_c(&size_expression, &[]));
+                    format!("{} const * value, uint64_t len", element_c_type)
+                }
+                ResolvedTypeKind::TypeRef { target_name, .. } => {
+                    format!("{}_t const * value", target_name)
+                }
+                _ => {
+                    let target_name = if is_nested_complex_type(&variant.variant_type) {
+                        format!("{}_{}_inner_t", type_name, escaped_variant)
+                    } else {
+                        format_type_to_c(&variant.variant_type)
+                    };
+                    format!("{} const * value", target_name)
+                }
+            }
+        };
+
+        write!(
+            output,
+            "int {}_init_{}( void * buffer, uint64_t buf_sz, {}, {} * err_out, int64_t * err_field_index_out ) {{\n",
+            type_name, escaped_variant, param_decl, err_type
+        )
+        .unwrap();
+
+        if is_variable {
+            write!(output, "  uint64_t field_bytes = 0;\n").unwrap();
+            write!(
+                output,
+                "  if( safe_add_u64( {}ULL, trailing_len, &field_bytes ) ) return {}_init_fail( err_out, err_field_index_out, {}, -1 );\n",
+                variant.expected_size, type_name, init_err_const(&type_name, "SIZE_OVERFLOW")
+            )
+            .unwrap();
+            write!(output, "  if( field_bytes > buf_sz ) {{\n").unwrap();
+            write!(
+                output,
+                "    return {}_init_fail( err_out, err_field_index_out, {}, -1 );\n",
+                type_name,
+                init_err_const(&type_name, "BUFFER_TOO_SMALL")
+            )
+            .unwrap();
+            write!(output, "  }}\n").unwrap();
+            write!(output, "  memcpy( buffer, value, field_bytes );\n").unwrap();
+        } else {
+            write!(
+                output,
+                "  if( buf_sz != {}ULL ) {{\n",
+                variant.expected_size
+            )
+            .unwrap();
+            write!(
+                output,
+                "    return {}_init_fail( err_out, err_field_index_out, {}, -1 ); /* Size does not match this variant */\n",
+                type_name,
+                init_err_const(&type_name, "BUFFER_TOO_SMALL")
+            )
+            .unwrap();
+            write!(output, "  }}\n").unwrap();
+            write!(
+                output,
+                "  {}_t * self = ({}_t *)buffer;\n",
+                type_name, type_name
+            )
+            .unwrap();
+            match &variant.variant_type.kind {
+                ResolvedTypeKind::Primitive { .. } => {
+                    write!(
+                        output,
+                        "  memcpy( &self->{}, &value, sizeof( self->{} ) );\n",
+                        escaped_variant, escaped_variant
+                    )
+                    .unwrap();
+                }
+                ResolvedTypeKind::Array { .. } => {
+                    if let Some(size_expr_str) = array_size_expr {
+                        write!(output, "  assert( len == ({}) );\n", size_expr_str).unwrap();
+                    }
+                    write!(
+                        output,
+                        "  memcpy( self->{}, value, len * sizeof self->{}[0] );\n",
+                        escaped_variant, escaped_variant
+                    )
+                    .unwrap();
+                }
+                _ => {
+                    write!(
+                        output,
+                        "  memcpy( &self->{}, value, sizeof( self->{} ) );\n",
+                        escaped_variant, escaped_variant
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        write!(
+            output,
+            "  int err = {}_validate( buffer, buf_sz, NULL );\n",
+            type_name
+        )
+        .unwrap();
+        write!(output, "  if( err ) {{\n").unwrap();
+        write!(
+            output,
+            "    if( err_out != NULL ) *err_out = {};\n",
+            init_err_const(&type_name, "VALIDATE_FAILED")
+        )
+        .unwrap();
+        write!(
+            output,
+            "    if( err_field_index_out != NULL ) *err_field_index_out = -1;\n"
+        )
+        .unwrap();
+        write!(output, "    return err;\n").unwrap();
+        write!(output, "  }}\n").unwrap();
         write!(output, "  return 0;\n").unwrap();
         write!(output, "}}\n\n").unwrap();
     }
@@ -534,10 +883,26 @@ fn emit_init_fn_union(resolved_type: &ResolvedType) -> String {
 
 pub fn emit_init_fn(resolved_type: &ResolvedType) -> String {
     match &resolved_type.kind {
-        ResolvedTypeKind::Struct { .. } => emit_init_fn_struct(&resolved_type),
-        ResolvedTypeKind::Union { .. } => emit_init_fn_union(&resolved_type),
+        ResolvedTypeKind::Struct { fields, .. } => {
+            let mut output = emit_init_fail_helper(&sanitize_type_name(&resolved_type.name));
+            output.push_str(&emit_init_fn_struct(&resolved_type, false));
+            let has_array_field = fields
+                .iter()
+                .any(|f| matches!(&f.field_type.kind, ResolvedTypeKind::Array { .. }));
+            if has_array_field {
+                output.push_str(&emit_init_fn_struct(&resolved_type, true));
+            }
+            output
+        }
+        ResolvedTypeKind::Union { .. } => {
+            let mut output = emit_init_fail_helper(&sanitize_type_name(&resolved_type.name));
+            output.push_str(&emit_init_fn_union(&resolved_type));
+            output
+        }
         ResolvedTypeKind::SizeDiscriminatedUnion { .. } => {
-            format!("/* TODO: EMIT SIZE FN FOR SizeDiscriminatedUnion */\n\n")
+            let mut output = emit_init_fail_helper(&sanitize_type_name(&resolved_type.name));
+            output.push_str(&emit_init_fn_size_discriminated_union(&resolved_type));
+            output
         }
         _ => {
             /* Unsupported type*/
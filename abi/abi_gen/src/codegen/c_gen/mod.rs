@@ -1,3 +1,4 @@
+pub mod checked_get;
 pub mod dcls;
 pub mod footprint;
 pub mod functions_opaque;
@@ -12,6 +13,7 @@ pub mod types;
 pub mod validate;
 
 // Re-export main public functions
+pub use checked_get::emit_checked_accessor_fn;
 pub use dcls::emit_forward_declarations;
 pub use footprint::{collect_and_emit_nested_footprints, emit_footprint_fn};
 pub use functions_opaque::emit_opaque_functions;
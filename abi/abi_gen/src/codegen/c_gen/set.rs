@@ -10,12 +10,18 @@ pub fn emit_set_fn_union(resolved_type: &ResolvedType) -> String {
     let mut output = String::new();
     let type_name = sanitize_type_name(&resolved_type.name);
 
-    if let ResolvedTypeKind::Union { variants } = &resolved_type.kind {
+    if let ResolvedTypeKind::Union { variants, tagged } = &resolved_type.kind {
         /* Emit a setter function for each variant in the union */
-        for variant in variants {
+        for (variant_index, variant) in variants.iter().enumerate() {
             let variant_name = &variant.name;
             let variant_type = &variant.field_type;
             let escaped_variant_name = escape_c_keyword(variant_name);
+            /* See emit_init_fn_union: tagged payloads live under self->value. */
+            let field_path = if tagged.is_some() {
+                format!("value.{}", escaped_variant_name)
+            } else {
+                escaped_variant_name.clone()
+            };
 
             match &variant_type.kind {
                 ResolvedTypeKind::Primitive { .. } => {
@@ -27,10 +33,13 @@ pub fn emit_set_fn_union(resolved_type: &ResolvedType) -> String {
                         type_name, escaped_variant_name, type_name, c_type
                     )
                     .unwrap();
+                    if tagged.is_some() {
+                        writeln!(output, "  self->tag = {};", variant_index).unwrap();
+                    }
                     writeln!(
                         output,
                         "  memcpy( &self->{}, value, sizeof( {} ) );",
-                        escaped_variant_name, c_type
+                        field_path, c_type
                     )
                     .unwrap();
                 }
@@ -52,10 +61,13 @@ pub fn emit_set_fn_union(resolved_type: &ResolvedType) -> String {
                     .unwrap();
                     let expected_len_expr = format_expr_to_c(&size_expression, &[]);
                     writeln!(output, "  assert( len == ({}) );", expected_len_expr).unwrap();
+                    if tagged.is_some() {
+                        writeln!(output, "  self->tag = {};", variant_index).unwrap();
+                    }
                     writeln!(
                         output,
                         "  memcpy( self->{0}, value, len * sizeof self->{0}[0] );",
-                        escaped_variant_name
+                        field_path
                     )
                     .unwrap();
                 }
@@ -68,10 +80,13 @@ pub fn emit_set_fn_union(resolved_type: &ResolvedType) -> String {
                         type_name, escaped_variant_name, type_name, c_type
                     )
                     .unwrap();
+                    if tagged.is_some() {
+                        writeln!(output, "  self->tag = {};", variant_index).unwrap();
+                    }
                     writeln!(
                         output,
                         "  memcpy( &self->{}, value, sizeof( {} ) );",
-                        escaped_variant_name, c_type
+                        field_path, c_type
                     )
                     .unwrap();
                 }
@@ -83,10 +98,13 @@ pub fn emit_set_fn_union(resolved_type: &ResolvedType) -> String {
                         type_name, escaped_variant_name, type_name, target_name
                     )
                     .unwrap();
+                    if tagged.is_some() {
+                        writeln!(output, "  self->tag = {};", variant_index).unwrap();
+                    }
                     writeln!(
                         output,
                         "  memcpy( &self->{}, value, sizeof( {} ) );",
-                        escaped_variant_name, target_name
+                        field_path, target_name
                     )
                     .unwrap();
                 }
@@ -2,7 +2,7 @@ use crate::abi::expr::ConstantExpression;
 use crate::abi::resolved::{ResolvedType, ResolvedTypeKind, Size};
 use crate::abi::types::{FloatingPointType, IntegralType, PrimitiveType};
 use std::fmt::Write;
-use super::helpers::{escape_c_keyword, is_nested_complex_type};
+use super::helpers::{escape_c_keyword, is_nested_complex_type, tag_width_c_type};
 
 const INDENT_FIELD: usize = 4;
 
@@ -154,7 +154,7 @@ fn emit_recursive_types(type_def: &ResolvedType, type_path: Option<&str>, output
         }
       }
     }
-    ResolvedTypeKind::Union { variants } => {
+    ResolvedTypeKind::Union { variants, .. } => {
       let current_path = type_path.unwrap_or(&type_def.name);
       for variant in variants {
         if is_nested_complex_type(&variant.field_type) {
@@ -203,7 +203,7 @@ fn emit_recursive_types(type_def: &ResolvedType, type_path: Option<&str>, output
 
       emit_c_type_definition("struct", &type_name, &struct_content, *packed, *custom_alignment, output);
     }
-    ResolvedTypeKind::Union { variants } => {
+    ResolvedTypeKind::Union { variants, tagged } => {
       let type_name = match type_path {
         None => escape_c_keyword(&type_def.name),
         Some(path) => format!("{}_inner", path),
@@ -217,14 +217,27 @@ fn emit_recursive_types(type_def: &ResolvedType, type_path: Option<&str>, output
         write!(union_content, "    {};\n", variant_decl).unwrap();
       }
 
-      emit_c_type_definition(
-        "union",
-        &type_name,
-        &union_content,
-        false, // Unions don't have packed from resolved type
-        None,  // Unions don't have custom alignment from resolved type
-        output,
-      );
+      match tagged {
+        None => {
+          emit_c_type_definition(
+            "union",
+            &type_name,
+            &union_content,
+            false, // Unions don't have packed from resolved type
+            None,  // Unions don't have custom alignment from resolved type
+            output,
+          );
+        }
+        Some(width) => {
+          /* Tagged layout: wrap the untagged union in a struct carrying a
+             leading discriminant, so the on-wire buffer is self-describing. */
+          let mut struct_content = String::new();
+          write!(struct_content, "    {} tag;\n", tag_width_c_type(width)).unwrap();
+          write!(struct_content, "    union {{\n{}    }} value;\n", union_content).unwrap();
+
+          emit_c_type_definition("struct", &type_name, &struct_content, false, None, output);
+        }
+      }
     }
     ResolvedTypeKind::Enum { .. } => {
       /* For enums, we don't generate a union wrapper.
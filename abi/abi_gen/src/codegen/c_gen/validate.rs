@@ -612,6 +612,22 @@ pub fn emit_validate_fn_union(resolved_type: &ResolvedType, type_ir: Option<&Typ
         write!(output, "    return 1; /* Buffer too small */\n").unwrap();
         write!(output, "  }}\n").unwrap();
 
+        if let ResolvedTypeKind::Union {
+            variants,
+            tagged: Some(_width),
+        } = &resolved_type.kind
+        {
+            write!(
+                output,
+                "  {}_t const * self = ({}_t const *)buffer;\n",
+                type_name, type_name
+            )
+            .unwrap();
+            write!(output, "  if( self->tag >= {} ) {{\n", variants.len()).unwrap();
+            write!(output, "    return 2; /* Invalid tag value */\n").unwrap();
+            write!(output, "  }}\n").unwrap();
+        }
+
         /* Set bytes consumed */
         write!(output, "  if( out_bytes_consumed != NULL ) {{\n").unwrap();
         write!(
@@ -1,5 +1,7 @@
 use crate::abi::resolved::{ResolvedType, ResolvedTypeKind, TypeResolver};
-use crate::codegen::rust_gen::{emit_ir_footprint_fn, emit_ir_validate_fn, emit_opaque_functions};
+use crate::codegen::rust_gen::{
+    emit_ir_footprint_fn, emit_ir_validate_data_fn, emit_ir_validate_fn, emit_opaque_functions,
+};
 use crate::codegen::shared::builder::IrBuilder;
 use crate::codegen::shared::ir::TypeIr;
 use std::fs;
@@ -110,6 +112,13 @@ impl<'a> RustCodeGenerator<'a> {
                             resolved_type.name, err
                         )),
                     }
+                    match emit_ir_validate_data_fn(ir) {
+                        Ok(ir_fn) => functions_output.push_str(&ir_fn),
+                        Err(err) => functions_output.push_str(&format!(
+                            "/* Failed to emit data-aware IR validator for {}: {} */\n",
+                            resolved_type.name, err
+                        )),
+                    }
                 } else if let Some(msg) = ir_error.as_ref() {
                     functions_output.push_str(&format!(
                         "/* IR helpers unavailable for {}: {} */\n",
@@ -166,7 +175,7 @@ impl<'a> RustCodeGenerator<'a> {
                     );
                 }
             }
-            ResolvedTypeKind::Union { variants } => {
+            ResolvedTypeKind::Union { variants, .. } => {
                 for variant in variants {
                     self.collect_from_resolved_type(
                         &variant.field_type,
@@ -270,34 +279,65 @@ impl<'a> RustCodeGenerator<'a> {
 
 const IR_VALIDATE_RUNTIME_HELPERS: &str = r#"
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum AbiIrValidateError {
+pub enum AbiIrValidateErrorKind {
     BufferTooSmall,
     InvalidVariant,
     ArithmeticOverflow,
+    TrailingBytes,
 }
 
-impl AbiIrValidateError {
+impl AbiIrValidateErrorKind {
     pub const fn as_str(self) -> &'static str {
         match self {
-            AbiIrValidateError::BufferTooSmall => "buffer too small",
-            AbiIrValidateError::InvalidVariant => "invalid variant tag",
-            AbiIrValidateError::ArithmeticOverflow => "size arithmetic overflow",
+            AbiIrValidateErrorKind::BufferTooSmall => "buffer too small",
+            AbiIrValidateErrorKind::InvalidVariant => "invalid variant tag",
+            AbiIrValidateErrorKind::ArithmeticOverflow => "size arithmetic overflow",
+            AbiIrValidateErrorKind::TrailingBytes => "trailing bytes after value",
         }
     }
 }
 
+/// Structured validation failure: which field, at what byte offset, and how
+/// much space was expected vs. actually available.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiIrValidateError {
+    pub kind: AbiIrValidateErrorKind,
+    pub path: String,
+    pub offset: u64,
+    pub needed: u64,
+    pub available: u64,
+}
+
+impl std::fmt::Display for AbiIrValidateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at `{}` (offset {}, needed {}, available {})",
+            self.kind.as_str(),
+            self.path,
+            self.offset,
+            self.needed,
+            self.available
+        )
+    }
+}
+
 fn abi_ir_error_str(err: AbiIrValidateError) -> &'static str {
-    err.as_str()
+    err.kind.as_str()
 }
 
-fn tn_checked_add_u64(a: u64, b: u64) -> Result<u64, AbiIrValidateError> {
+fn tn_checked_add_u64(a: u64, b: u64) -> Option<u64> {
     a.checked_add(b)
-        .ok_or(AbiIrValidateError::ArithmeticOverflow)
 }
 
-fn tn_checked_mul_u64(a: u64, b: u64) -> Result<u64, AbiIrValidateError> {
+fn tn_checked_mul_u64(a: u64, b: u64) -> Option<u64> {
     a.checked_mul(b)
-        .ok_or(AbiIrValidateError::ArithmeticOverflow)
+}
+
+fn tn_read_u32_le(buf: &[u8], offset: u64) -> Option<u64> {
+    let start = offset as usize;
+    let bytes = buf.get(start..start + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64)
 }
 "#;
 
@@ -310,7 +350,7 @@ fn emit_recursive_types(resolved_type: &ResolvedType, output: &mut String) {
                 emit_recursive_types(&field.field_type, output);
             }
         }
-        ResolvedTypeKind::Union { variants } => {
+        ResolvedTypeKind::Union { variants, .. } => {
             for variant in variants {
                 emit_recursive_types(&variant.field_type, output);
             }
@@ -394,7 +434,7 @@ fn emit_single_type(resolved_type: &ResolvedType) -> String {
             }
             output.push_str("}\n");
         }
-        ResolvedTypeKind::Union { variants } => {
+        ResolvedTypeKind::Union { variants, .. } => {
             emit_union_type(
                 &mut output,
                 &type_name,
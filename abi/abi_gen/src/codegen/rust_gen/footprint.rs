@@ -23,7 +23,7 @@ pub fn collect_and_emit_nested_footprints(
         }
       }
     }
-    ResolvedTypeKind::Union { variants } => {
+    ResolvedTypeKind::Union { variants, .. } => {
       let current_path = type_path.unwrap_or(&type_def.name);
       for variant in variants {
         if is_nested_complex_type(&variant.field_type) {
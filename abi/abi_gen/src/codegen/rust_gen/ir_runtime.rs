@@ -3,7 +3,7 @@
    Intended to mirror the TypeScript runtime semantics (BigInt/checked math, missing switch detection). */
 
 use crate::codegen::shared::ir::{
-    AlignNode, BinaryOpNode, CallNestedNode, Endianness, IrNode, NodeMetadata, SwitchNode, TypeIr,
+    AlignNode, BinaryOpNode, CallNestedNode, Endianness, IrNode, SumOverArrayNode, SwitchNode, TypeIr,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -67,50 +67,84 @@ impl IrError {
 }
 
 pub type ParamLookup<'a> = &'a dyn Fn(&str) -> Option<u64>;
-pub type NestedCaller<'a> = &'a dyn Fn(&str, &[u64]) -> Result<u64, IrError>;
+/* Nested-type footprint lookup. The `Endianness` argument is the calling
+node's endianness context, propagated down so a nested type that doesn't
+declare its own override can inherit the caller's -- footprint size is
+endianness-independent, but the context still needs to flow for any
+endianness-sensitive behavior a nested caller may implement. */
+pub type NestedCaller<'a> = &'a dyn Fn(&str, &[u64], Endianness) -> Result<u64, IrError>;
+/* Per-element footprint lookup for a jagged array: given the array's field
+path and an element index, returns that element's size. Required because a
+jagged array's elements aren't uniformly sized, so there's no static
+expression for them -- the caller has to answer by inspecting the actual
+instance. */
+pub type ArrayIterator<'a> = &'a dyn Fn(&str, u64) -> Option<u64>;
+
+/* How arithmetic overflow is handled while evaluating a footprint */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalMode {
+    /* Abort with `ArithmeticOverflow` the moment any step overflows --
+    correct for generating code that must not silently wrap */
+    Checked,
+    /* Clamp every overflowing step to `u64::MAX` and keep going, recording
+    that saturation occurred -- useful for analysis tooling that wants an
+    approximate size for a pathological type instead of aborting */
+    Saturating,
+}
+
+impl Default for EvalMode {
+    fn default() -> Self {
+        EvalMode::Checked
+    }
+}
+
+/* Result of evaluating a footprint: the computed size, and whether any step
+saturated rather than being computed exactly. `saturated` is always `false`
+under `EvalMode::Checked`, since that mode errors out instead of clamping. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FootprintOutcome {
+    pub value: u64,
+    pub saturated: bool,
+}
 
 pub fn eval_footprint(
     ir: &TypeIr,
     params: ParamLookup<'_>,
     nested: NestedCaller<'_>,
-) -> Result<u64, IrError> {
-    eval_node(&ir.root, params, nested)
+    array: ArrayIterator<'_>,
+    mode: EvalMode,
+) -> Result<FootprintOutcome, IrError> {
+    let mut saturated = false;
+    let value = eval_node(&ir.root, params, nested, array, mode, &mut saturated)?;
+    Ok(FootprintOutcome { value, saturated })
 }
 
 fn eval_node(
     node: &IrNode,
     params: ParamLookup<'_>,
     nested: NestedCaller<'_>,
+    array: ArrayIterator<'_>,
+    mode: EvalMode,
+    saturated: &mut bool,
 ) -> Result<u64, IrError> {
     match node {
-        IrNode::Const(c) => {
-            ensure_little(&c.meta)?;
-            Ok(c.value)
-        }
-        IrNode::ZeroSize { meta } => {
-            ensure_little(meta)?;
-            Ok(0)
-        }
+        /* Footprint is a byte count, not an interpretation of the bytes, so
+        it's identical regardless of the node's declared endianness */
+        IrNode::Const(c) => Ok(c.value),
+        IrNode::ZeroSize { .. } => Ok(0),
         IrNode::FieldRef(field) => {
-            ensure_little(&field.meta)?;
             let name = field
                 .parameter
                 .as_deref()
                 .unwrap_or_else(|| field.path.as_str());
             params(name).ok_or_else(|| IrError::missing_param(name))
         }
-        IrNode::AddChecked(node) => combine_binary(node, params, nested, checked_add),
-        IrNode::MulChecked(node) => combine_binary(node, params, nested, checked_mul),
-        IrNode::AlignUp(node) => align_expr(node, params, nested),
-        IrNode::CallNested(node) => call_nested(node, params, nested),
-        IrNode::Switch(node) => switch_expr(node, params, nested),
-        IrNode::SumOverArray(_node) => {
-            /* Jagged arrays are not supported in runtime IR evaluation.
-               Size calculation requires iteration over actual data. */
-            Err(IrError::unsupported_operation(
-                "SumOverArray requires iteration over actual data",
-            ))
-        }
+        IrNode::AddChecked(node) => combine_binary(node, params, nested, array, mode, saturated, add),
+        IrNode::MulChecked(node) => combine_binary(node, params, nested, array, mode, saturated, mul),
+        IrNode::AlignUp(node) => align_expr(node, params, nested, array, mode, saturated),
+        IrNode::CallNested(node) => call_nested(node, params, nested, array, mode, saturated),
+        IrNode::Switch(node) => switch_expr(node, params, nested, array, mode, saturated),
+        IrNode::SumOverArray(node) => sum_over_array(node, params, nested, array, mode, saturated),
     }
 }
 
@@ -118,42 +152,67 @@ fn combine_binary(
     node: &BinaryOpNode,
     params: ParamLookup<'_>,
     nested: NestedCaller<'_>,
-    op: fn(u64, u64) -> Result<u64, IrError>,
+    array: ArrayIterator<'_>,
+    mode: EvalMode,
+    saturated: &mut bool,
+    op: fn(u64, u64, EvalMode, &mut bool) -> Result<u64, IrError>,
 ) -> Result<u64, IrError> {
-    let left = eval_node(&node.left, params, nested)?;
-    let right = eval_node(&node.right, params, nested)?;
-    op(left, right)
+    let left = eval_node(&node.left, params, nested, array, mode, saturated)?;
+    let right = eval_node(&node.right, params, nested, array, mode, saturated)?;
+    op(left, right, mode, saturated)
 }
 
-fn checked_add(a: u64, b: u64) -> Result<u64, IrError> {
-    a.checked_add(b).ok_or_else(IrError::overflow)
+fn add(a: u64, b: u64, mode: EvalMode, saturated: &mut bool) -> Result<u64, IrError> {
+    match mode {
+        EvalMode::Checked => a.checked_add(b).ok_or_else(IrError::overflow),
+        EvalMode::Saturating => {
+            let (sum, overflowed) = a.overflowing_add(b);
+            *saturated |= overflowed;
+            Ok(if overflowed { u64::MAX } else { sum })
+        }
+    }
 }
 
-fn checked_mul(a: u64, b: u64) -> Result<u64, IrError> {
-    a.checked_mul(b).ok_or_else(IrError::overflow)
+fn mul(a: u64, b: u64, mode: EvalMode, saturated: &mut bool) -> Result<u64, IrError> {
+    match mode {
+        EvalMode::Checked => a.checked_mul(b).ok_or_else(IrError::overflow),
+        EvalMode::Saturating => {
+            let (product, overflowed) = a.overflowing_mul(b);
+            *saturated |= overflowed;
+            Ok(if overflowed { u64::MAX } else { product })
+        }
+    }
 }
 
 fn align_expr(
     node: &AlignNode,
     params: ParamLookup<'_>,
     nested: NestedCaller<'_>,
+    array: ArrayIterator<'_>,
+    mode: EvalMode,
+    saturated: &mut bool,
 ) -> Result<u64, IrError> {
-    ensure_little(&node.meta)?;
-    let inner = eval_node(&node.node, params, nested)?;
+    let inner = eval_node(&node.node, params, nested, array, mode, saturated)?;
     let alignment = node.alignment.max(1);
     if alignment <= 1 {
         return Ok(inner);
     }
-    let add = checked_add(inner, alignment - 1)?;
-    Ok(add & !(alignment - 1))
+    let padded = add(inner, alignment - 1, mode, saturated)?;
+    Ok(padded & !(alignment - 1))
 }
 
 fn call_nested(
     node: &CallNestedNode,
     params: ParamLookup<'_>,
     nested: NestedCaller<'_>,
+    _array: ArrayIterator<'_>,
+    _mode: EvalMode,
+    _saturated: &mut bool,
 ) -> Result<u64, IrError> {
-    ensure_little(&node.meta)?;
+    /* `array`/`mode`/`saturated` are threaded through for signature
+    uniformity across every evaluator function, but a nested type resolves
+    its own arrays and overflow handling in its own frame (via its own
+    `NestedCaller` invocation), not this caller's */
     let mut args = Vec::with_capacity(node.arguments.len());
     for arg in &node.arguments {
         let value_name = arg.value.as_str();
@@ -162,41 +221,53 @@ fn call_nested(
         };
         args.push(val);
     }
-    nested(&node.type_name, &args)
+    nested(&node.type_name, &args, node.meta.endianness)
 }
 
 fn switch_expr(
     node: &SwitchNode,
     params: ParamLookup<'_>,
     nested: NestedCaller<'_>,
+    array: ArrayIterator<'_>,
+    mode: EvalMode,
+    saturated: &mut bool,
 ) -> Result<u64, IrError> {
-    ensure_little(&node.meta)?;
     let tag = node.tag.as_str();
     let tag_val = params(tag).ok_or_else(|| IrError::missing_param(tag))?;
     for case in &node.cases {
         if case.tag_value == tag_val {
-            return eval_node(&case.node, params, nested);
+            return eval_node(&case.node, params, nested, array, mode, saturated);
         }
     }
     if let Some(default) = &node.default {
-        return eval_node(default, params, nested);
+        return eval_node(default, params, nested, array, mode, saturated);
     }
     Err(IrError::missing_switch_case(tag_val))
 }
 
-fn ensure_little(meta: &NodeMetadata) -> Result<(), IrError> {
-    match meta.endianness {
-        Endianness::Little => Ok(()),
-        _ => Err(IrError::unsupported_endianness()),
+fn sum_over_array(
+    node: &SumOverArrayNode,
+    params: ParamLookup<'_>,
+    nested: NestedCaller<'_>,
+    array: ArrayIterator<'_>,
+    mode: EvalMode,
+    saturated: &mut bool,
+) -> Result<u64, IrError> {
+    let len = eval_node(&node.count, params, nested, array, mode, saturated)?;
+    let mut total = 0u64;
+    for i in 0..len {
+        let element_size = array(&node.field_name, i).ok_or_else(|| IrError::missing_param(&node.field_name))?;
+        total = add(total, element_size, mode, saturated)?;
     }
+    Ok(total)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::codegen::shared::ir::{
-        AlignNode, BinaryOpNode, ConstNode, Endianness, IrNode, NodeMetadata, SwitchCase,
-        SwitchNode, TypeIr,
+        AlignNode, BinaryOpNode, ConstNode, Endianness, FieldRefNode, IrNode, NodeMetadata,
+        SwitchCase, SwitchNode, TypeIr,
     };
 
     fn metadata() -> NodeMetadata {
@@ -207,10 +278,22 @@ mod tests {
         }
     }
 
-    fn noop_nested(_: &str, _: &[u64]) -> Result<u64, IrError> {
+    fn metadata_with(endianness: Endianness) -> NodeMetadata {
+        NodeMetadata {
+            size_expr: None,
+            alignment: 1,
+            endianness,
+        }
+    }
+
+    fn noop_nested(_: &str, _: &[u64], _: Endianness) -> Result<u64, IrError> {
         Err(IrError::unknown_nested("noop"))
     }
 
+    fn noop_array(_: &str, _: u64) -> Option<u64> {
+        None
+    }
+
     #[test]
     fn eval_const() {
         let ir = TypeIr {
@@ -223,7 +306,9 @@ mod tests {
             parameters: Vec::new(),
         };
         let params = |_name: &str| None;
-        assert_eq!(eval_footprint(&ir, &params, &noop_nested).unwrap(), 16);
+        let got = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Checked).unwrap();
+        assert_eq!(got.value, 16);
+        assert!(!got.saturated);
     }
 
     #[test]
@@ -245,10 +330,58 @@ mod tests {
             parameters: Vec::new(),
         };
         let params = |_name: &str| None;
-        let err = eval_footprint(&ir, &params, &noop_nested).unwrap_err();
+        let err = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Checked).unwrap_err();
         assert_eq!(err.code, IrErrorCode::ArithmeticOverflow);
     }
 
+    #[test]
+    fn add_overflow_saturates_instead_of_erroring_in_saturating_mode() {
+        let ir = TypeIr {
+            type_name: "Overflow".into(),
+            alignment: 1,
+            root: IrNode::AddChecked(BinaryOpNode {
+                left: Box::new(IrNode::Const(ConstNode {
+                    value: u64::MAX,
+                    meta: metadata(),
+                })),
+                right: Box::new(IrNode::Const(ConstNode {
+                    value: 1,
+                    meta: metadata(),
+                })),
+                meta: metadata(),
+            }),
+            parameters: Vec::new(),
+        };
+        let params = |_name: &str| None;
+        let got = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Saturating).unwrap();
+        assert_eq!(got.value, u64::MAX);
+        assert!(got.saturated);
+    }
+
+    #[test]
+    fn non_overflowing_saturating_mode_reports_not_saturated() {
+        let ir = TypeIr {
+            type_name: "NoOverflow".into(),
+            alignment: 1,
+            root: IrNode::AddChecked(BinaryOpNode {
+                left: Box::new(IrNode::Const(ConstNode {
+                    value: 3,
+                    meta: metadata(),
+                })),
+                right: Box::new(IrNode::Const(ConstNode {
+                    value: 4,
+                    meta: metadata(),
+                })),
+                meta: metadata(),
+            }),
+            parameters: Vec::new(),
+        };
+        let params = |_name: &str| None;
+        let got = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Saturating).unwrap();
+        assert_eq!(got.value, 7);
+        assert!(!got.saturated);
+    }
+
     #[test]
     fn switch_missing_case() {
         let ir = TypeIr {
@@ -270,7 +403,7 @@ mod tests {
             parameters: Vec::new(),
         };
         let params = |name: &str| if name == "tag" { Some(2) } else { None };
-        let err = eval_footprint(&ir, &params, &noop_nested).unwrap_err();
+        let err = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Checked).unwrap_err();
         assert_eq!(err.code, IrErrorCode::MissingSwitchCase);
     }
 
@@ -290,8 +423,8 @@ mod tests {
             parameters: Vec::new(),
         };
         let params = |_name: &str| None;
-        let got = eval_footprint(&ir, &params, &noop_nested).unwrap();
-        assert_eq!(got, 8);
+        let got = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Checked).unwrap();
+        assert_eq!(got.value, 8);
     }
 
     #[test]
@@ -320,7 +453,7 @@ mod tests {
             "tag" => Some(7),
             _ => None,
         };
-        let nested = |name: &str, args: &[u64]| -> Result<u64, IrError> {
+        let nested = |name: &str, args: &[u64], _endianness: Endianness| -> Result<u64, IrError> {
             if name == "Other" {
                 assert_eq!(args, &[3, 7]);
                 Ok(10)
@@ -328,26 +461,128 @@ mod tests {
                 Err(IrError::unknown_nested(name))
             }
         };
-        assert_eq!(eval_footprint(&ir, &params, &nested).unwrap(), 10);
+        let got = eval_footprint(&ir, &params, &nested, &noop_array, EvalMode::Checked).unwrap();
+        assert_eq!(got.value, 10);
     }
 
     #[test]
-    fn rejects_non_little_endian() {
+    fn call_nested_propagates_caller_endianness() {
         let ir = TypeIr {
-            type_name: "BigEndian".into(),
+            type_name: "Call".into(),
             alignment: 1,
-            root: IrNode::Const(ConstNode {
-                value: 1,
-                meta: NodeMetadata {
-                    size_expr: None,
-                    alignment: 1,
-                    endianness: Endianness::Big,
-                },
+            root: IrNode::CallNested(CallNestedNode {
+                type_name: "Other".into(),
+                arguments: Vec::new(),
+                meta: metadata_with(Endianness::Big),
+            }),
+            parameters: Vec::new(),
+        };
+        let params = |_name: &str| None;
+        let nested = |_name: &str, _args: &[u64], endianness: Endianness| -> Result<u64, IrError> {
+            assert_eq!(endianness, Endianness::Big);
+            Ok(4)
+        };
+        let got = eval_footprint(&ir, &params, &nested, &noop_array, EvalMode::Checked).unwrap();
+        assert_eq!(got.value, 4);
+    }
+
+    /* Build the same shape of IR tree with every node tagged with `endianness`,
+    for comparing a big-endian tree's footprint against its little-endian twin */
+    fn mixed_tree(endianness: Endianness) -> TypeIr {
+        TypeIr {
+            type_name: "Mixed".into(),
+            alignment: 4,
+            root: IrNode::AlignUp(AlignNode {
+                alignment: 8,
+                node: Box::new(IrNode::AddChecked(BinaryOpNode {
+                    left: Box::new(IrNode::Const(ConstNode {
+                        value: 3,
+                        meta: metadata_with(endianness),
+                    })),
+                    right: Box::new(IrNode::Switch(SwitchNode {
+                        tag: "tag".into(),
+                        cases: vec![SwitchCase {
+                            tag_value: 1,
+                            node: Box::new(IrNode::Const(ConstNode {
+                                value: 4,
+                                meta: metadata_with(endianness),
+                            })),
+                            parameters: Vec::new(),
+                        }],
+                        default: None,
+                        meta: metadata_with(endianness),
+                    })),
+                    meta: metadata_with(endianness),
+                })),
+                meta: metadata_with(endianness),
+            }),
+            parameters: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn big_endian_tree_matches_little_endian_footprint() {
+        let params = |name: &str| if name == "tag" { Some(1) } else { None };
+
+        let little =
+            eval_footprint(&mixed_tree(Endianness::Little), &params, &noop_nested, &noop_array, EvalMode::Checked)
+                .unwrap();
+        let big = eval_footprint(&mixed_tree(Endianness::Big), &params, &noop_nested, &noop_array, EvalMode::Checked)
+            .unwrap();
+
+        assert_eq!(little.value, big.value);
+        assert_eq!(little.value, 8); /* 3 + 4 = 7, aligned up to 8 */
+    }
+
+    #[test]
+    fn sum_over_array_accumulates_element_sizes() {
+        let ir = TypeIr {
+            type_name: "Jagged".into(),
+            alignment: 1,
+            root: IrNode::SumOverArray(SumOverArrayNode {
+                count: Box::new(IrNode::FieldRef(FieldRefNode {
+                    path: "items.len".into(),
+                    parameter: None,
+                    meta: metadata(),
+                })),
+                element_type_name: "Item".into(),
+                field_name: "items".into(),
+                meta: metadata(),
+            }),
+            parameters: Vec::new(),
+        };
+        let sizes = [2u64, 4, 6];
+        let params = |name: &str| if name == "items.len" { Some(sizes.len() as u64) } else { None };
+        let array = |field_name: &str, index: u64| {
+            if field_name == "items" {
+                sizes.get(index as usize).copied()
+            } else {
+                None
+            }
+        };
+        let got = eval_footprint(&ir, &params, &noop_nested, &array, EvalMode::Checked).unwrap();
+        assert_eq!(got.value, 12);
+    }
+
+    #[test]
+    fn sum_over_array_missing_length_is_missing_param() {
+        let ir = TypeIr {
+            type_name: "Jagged".into(),
+            alignment: 1,
+            root: IrNode::SumOverArray(SumOverArrayNode {
+                count: Box::new(IrNode::FieldRef(FieldRefNode {
+                    path: "items.len".into(),
+                    parameter: None,
+                    meta: metadata(),
+                })),
+                element_type_name: "Item".into(),
+                field_name: "items".into(),
+                meta: metadata(),
             }),
             parameters: Vec::new(),
         };
         let params = |_name: &str| None;
-        let err = eval_footprint(&ir, &params, &noop_nested).unwrap_err();
-        assert_eq!(err.code, IrErrorCode::UnsupportedEndianness);
+        let err = eval_footprint(&ir, &params, &noop_nested, &noop_array, EvalMode::Checked).unwrap_err();
+        assert_eq!(err.code, IrErrorCode::MissingParam);
     }
 }
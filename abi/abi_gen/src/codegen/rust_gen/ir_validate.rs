@@ -11,8 +11,42 @@ pub enum IrValidateError {
     UnsupportedNode,
 }
 
+/// Builds the literal Rust expression that constructs an `AbiIrValidateError`
+/// with the given kind and path, to be embedded directly in emitted code.
+fn error_literal(kind: &str, path: &str, offset: &str, needed: &str, available: &str) -> String {
+    format!(
+        "AbiIrValidateError {{ kind: AbiIrValidateErrorKind::{}, path: \"{}\".to_string(), offset: {}, needed: {}, available: {} }}",
+        kind, path, offset, needed, available
+    )
+}
+
+/// Controls how the emitted `*_validate_ir` function treats a buffer whose
+/// length exceeds the type's footprint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidateMode {
+    /// `buf_sz` may be larger than the footprint; only undersized buffers
+    /// are rejected. This is the historical behavior.
+    AtLeast,
+    /// `buf_sz` must equal the footprint exactly; trailing bytes are an
+    /// error. Use for wire formats where smuggling extra bytes past a
+    /// validator must not be possible.
+    Exact,
+    /// No final comparison against `buf_sz` at all, so neither an oversized
+    /// nor an undersized buffer is rejected at that point; the footprint is
+    /// simply returned so the caller can treat it as the consumed prefix
+    /// length and continue parsing whatever follows (chained/framed parsing).
+    Prefix,
+}
+
+impl Default for ValidateMode {
+    fn default() -> Self {
+        ValidateMode::AtLeast
+    }
+}
+
 pub struct IrValidateEmitter<'a> {
     type_ir: &'a TypeIr,
+    mode: ValidateMode,
     output: String,
     temp_idx: usize,
 }
@@ -21,11 +55,19 @@ impl<'a> IrValidateEmitter<'a> {
     pub fn new(type_ir: &'a TypeIr) -> Self {
         Self {
             type_ir,
+            mode: ValidateMode::default(),
             output: String::new(),
             temp_idx: 0,
         }
     }
 
+    /// Selects the over-size handling mode for the emitted function. See
+    /// [`ValidateMode`].
+    pub fn with_mode(mut self, mode: ValidateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     pub fn emit(mut self) -> Result<String, IrValidateError> {
         let fn_name = format!(
             "{}_validate_ir",
@@ -40,20 +82,72 @@ impl<'a> IrValidateEmitter<'a> {
         signature.push_str(") -> Result<u64, AbiIrValidateError>");
 
         writeln!(&mut self.output, "{} {{", signature).unwrap();
-        let result_var = self.emit_node(&self.type_ir.root, 1)?;
-        writeln!(&mut self.output, "    if {} > buf_sz {{", result_var).unwrap();
-        writeln!(
-            &mut self.output,
-            "        return Err(AbiIrValidateError::BufferTooSmall);"
-        )
-        .unwrap();
-        writeln!(&mut self.output, "    }}").unwrap();
+        let root_path = self.type_ir.type_name.clone();
+        let result_var = self.emit_node(&self.type_ir.root, 1, &root_path, "0")?;
+        match self.mode {
+            ValidateMode::AtLeast => {
+                writeln!(&mut self.output, "    if {} > buf_sz {{", result_var).unwrap();
+                writeln!(
+                    &mut self.output,
+                    "        return Err({});",
+                    error_literal(
+                        "BufferTooSmall",
+                        &root_path,
+                        "buf_sz",
+                        &result_var,
+                        "buf_sz"
+                    )
+                )
+                .unwrap();
+                writeln!(&mut self.output, "    }}").unwrap();
+            }
+            ValidateMode::Exact => {
+                writeln!(&mut self.output, "    if {} > buf_sz {{", result_var).unwrap();
+                writeln!(
+                    &mut self.output,
+                    "        return Err({});",
+                    error_literal(
+                        "BufferTooSmall",
+                        &root_path,
+                        "buf_sz",
+                        &result_var,
+                        "buf_sz"
+                    )
+                )
+                .unwrap();
+                writeln!(&mut self.output, "    }}").unwrap();
+                writeln!(&mut self.output, "    if {} != buf_sz {{", result_var).unwrap();
+                writeln!(
+                    &mut self.output,
+                    "        return Err({});",
+                    error_literal(
+                        "TrailingBytes",
+                        &root_path,
+                        "buf_sz",
+                        &result_var,
+                        "buf_sz"
+                    )
+                )
+                .unwrap();
+                writeln!(&mut self.output, "    }}").unwrap();
+            }
+            ValidateMode::Prefix => {}
+        }
         writeln!(&mut self.output, "    Ok({})", result_var).unwrap();
         writeln!(&mut self.output, "}}\n").unwrap();
         Ok(self.output)
     }
 
-    fn emit_node(&mut self, node: &IrNode, indent: usize) -> Result<String, IrValidateError> {
+    /// Emits code computing the size contributed by `node`. `path` is the
+    /// dotted field path leading to this node (for error reporting) and
+    /// `offset` is a Rust expression for the cursor position reached so far.
+    fn emit_node(
+        &mut self,
+        node: &IrNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
         match node {
             IrNode::Const(c) => {
                 let var = self.new_var();
@@ -83,12 +177,12 @@ impl<'a> IrValidateEmitter<'a> {
             } else {
                 sanitize_param_name(&field.path)
             }),
-            IrNode::AddChecked(node) => self.emit_binary(node, indent, "tn_checked_add_u64"),
-            IrNode::MulChecked(node) => self.emit_binary(node, indent, "tn_checked_mul_u64"),
-            IrNode::AlignUp(node) => self.emit_align(node, indent),
-            IrNode::CallNested(node) => self.emit_call_nested(node, indent),
-            IrNode::Switch(node) => self.emit_switch(node, indent),
-            IrNode::SumOverArray(node) => self.emit_sum_over_array(node, indent),
+            IrNode::AddChecked(node) => self.emit_add(node, indent, path, offset),
+            IrNode::MulChecked(node) => self.emit_binary(node, indent, path, "tn_checked_mul_u64"),
+            IrNode::AlignUp(node) => self.emit_align(node, indent, path, offset),
+            IrNode::CallNested(node) => self.emit_call_nested(node, indent, path),
+            IrNode::Switch(node) => self.emit_switch(node, indent, path, offset),
+            IrNode::SumOverArray(node) => self.emit_sum_over_array(node, indent, path, offset),
         }
     }
 
@@ -96,42 +190,117 @@ impl<'a> IrValidateEmitter<'a> {
         &mut self,
         _node: &crate::codegen::shared::ir::SumOverArrayNode,
         _indent: usize,
+        _path: &str,
+        _offset: &str,
     ) -> Result<String, IrValidateError> {
         /* Jagged arrays require iteration over actual data for validation.
            IR helper functions are free functions without access to instance data,
-           so we can't generate validation IR for types containing jagged arrays. */
+           so we can't generate validation IR for types containing jagged arrays.
+           Use emit_ir_validate_data_fn for those instead. */
         Err(IrValidateError::UnsupportedNode)
     }
 
+    /// `AddChecked` is the only node that advances the cursor for its
+    /// right-hand side, since the left-hand bytes come first in the stream.
+    fn emit_add(
+        &mut self,
+        node: &BinaryOpNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
+        let left = self.emit_node(&node.left, indent, path, offset)?;
+        let right_offset = self.new_var();
+        self.emit_checked(
+            indent,
+            &right_offset,
+            "tn_checked_add_u64",
+            offset,
+            &left,
+            "ArithmeticOverflow",
+            path,
+            offset,
+        );
+        let right = self.emit_node(&node.right, indent, path, &right_offset)?;
+        let var = self.new_var();
+        self.emit_checked(
+            indent,
+            &var,
+            "tn_checked_add_u64",
+            &left,
+            &right,
+            "ArithmeticOverflow",
+            path,
+            offset,
+        );
+        Ok(var)
+    }
+
     fn emit_binary(
         &mut self,
         node: &BinaryOpNode,
         indent: usize,
+        path: &str,
         helper: &str,
     ) -> Result<String, IrValidateError> {
-        let left = self.emit_node(&node.left, indent)?;
-        let right = self.emit_node(&node.right, indent)?;
+        let left = self.emit_node(&node.left, indent, path, "0")?;
+        let right = self.emit_node(&node.right, indent, path, "0")?;
         let var = self.new_var();
+        self.emit_checked(
+            indent,
+            &var,
+            helper,
+            &left,
+            &right,
+            "ArithmeticOverflow",
+            path,
+            "0",
+        );
+        Ok(var)
+    }
+
+    /// Emits `let {var} = match {helper}({a}, {b}) { Some(v) => v, None => return Err(..) };`.
+    fn emit_checked(
+        &mut self,
+        indent: usize,
+        var: &str,
+        helper: &str,
+        a: &str,
+        b: &str,
+        kind: &str,
+        path: &str,
+        offset: &str,
+    ) {
         writeln!(
             &mut self.output,
-            "{}let {} = {}({}, {})?;",
+            "{}let {} = match {}({}, {}) {{",
             Self::indent(indent),
             var,
             helper,
-            left,
-            right
+            a,
+            b
         )
         .unwrap();
-        Ok(var)
+        writeln!(&mut self.output, "{}    Some(v) => v,", Self::indent(indent)).unwrap();
+        writeln!(
+            &mut self.output,
+            "{}    None => return Err({}),",
+            Self::indent(indent),
+            error_literal(kind, path, offset, "0", "0")
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}}};", Self::indent(indent)).unwrap();
     }
 
     fn emit_align(
         &mut self,
-        node: &crate::codegen::shared::ir::AlignNode,
+        node: &AlignNode,
         indent: usize,
+        path: &str,
+        offset: &str,
     ) -> Result<String, IrValidateError> {
         let align = node.alignment.max(1);
-        let inner = self.emit_node(&node.node, indent)?;
+        let inner = self.emit_node(&node.node, indent, path, offset)?;
         if align <= 1 {
             return Ok(inner);
         }
@@ -155,13 +324,27 @@ impl<'a> IrValidateEmitter<'a> {
         writeln!(&mut self.output, "{}if rem != 0 {{", Self::indent(indent)).unwrap();
         writeln!(
             &mut self.output,
-            "{}    {} = tn_checked_add_u64({}, {} - rem)?;",
+            "{}    {} = match tn_checked_add_u64({}, {} - rem) {{",
             Self::indent(indent),
             aligned,
             aligned,
             align
         )
         .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        Some(v) => v,",
+            Self::indent(indent)
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        None => return Err({}),",
+            Self::indent(indent),
+            error_literal("ArithmeticOverflow", path, offset, "0", "0")
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}    }};", Self::indent(indent)).unwrap();
         writeln!(&mut self.output, "{}}}", Self::indent(indent)).unwrap();
         Ok(aligned)
     }
@@ -170,6 +353,7 @@ impl<'a> IrValidateEmitter<'a> {
         &mut self,
         node: &CallNestedNode,
         indent: usize,
+        path: &str,
     ) -> Result<String, IrValidateError> {
         let fn_name = format!("{}_footprint_ir", sanitize_param_name(&node.type_name));
         let mut args = String::new();
@@ -193,11 +377,19 @@ impl<'a> IrValidateEmitter<'a> {
             call
         )
         .unwrap();
+        let _ = path;
         Ok(var)
     }
 
-    fn emit_switch(&mut self, node: &SwitchNode, indent: usize) -> Result<String, IrValidateError> {
+    fn emit_switch(
+        &mut self,
+        node: &SwitchNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
         let tag = sanitize_param_name(&node.tag);
+        let switch_path = format!("{}.{}", path, node.tag);
         let result = self.new_var();
         writeln!(
             &mut self.output,
@@ -215,7 +407,7 @@ impl<'a> IrValidateEmitter<'a> {
                 case.tag_value
             )
             .unwrap();
-            let case_expr = self.emit_node(&case.node, indent + 2)?;
+            let case_expr = self.emit_node(&case.node, indent + 2, &switch_path, offset)?;
             writeln!(
                 &mut self.output,
                 "{}{}",
@@ -227,14 +419,15 @@ impl<'a> IrValidateEmitter<'a> {
         }
         if let Some(default_node) = &node.default {
             writeln!(&mut self.output, "{}_ => {{", Self::indent(indent + 1)).unwrap();
-            let expr = self.emit_node(default_node, indent + 2)?;
+            let expr = self.emit_node(default_node, indent + 2, &switch_path, offset)?;
             writeln!(&mut self.output, "{}{}", Self::indent(indent + 2), expr).unwrap();
             writeln!(&mut self.output, "{}}},", Self::indent(indent + 1)).unwrap();
         } else {
             writeln!(
                 &mut self.output,
-                "{}_ => return Err(AbiIrValidateError::InvalidVariant),",
-                Self::indent(indent + 1)
+                "{}tn_tag => return Err({}),",
+                Self::indent(indent + 1),
+                error_literal("InvalidVariant", &switch_path, offset, "0", "tn_tag")
             )
             .unwrap();
         }
@@ -257,6 +450,462 @@ pub fn emit_ir_validate_fn(type_ir: &TypeIr) -> Result<String, IrValidateError>
     IrValidateEmitter::new(type_ir).emit()
 }
 
+/// Same as [`emit_ir_validate_fn`] but with an explicit [`ValidateMode`]
+/// instead of the default [`ValidateMode::AtLeast`].
+pub fn emit_ir_validate_fn_with_mode(
+    type_ir: &TypeIr,
+    mode: ValidateMode,
+) -> Result<String, IrValidateError> {
+    IrValidateEmitter::new(type_ir).with_mode(mode).emit()
+}
+
+/// Emits a second validator that takes `buf: &[u8]` instead of `buf_sz: u64`,
+/// threading a running byte-offset cursor through the tree. Unlike
+/// [`emit_ir_validate_fn`], this one can validate types containing
+/// `SumOverArray` nodes (jagged arrays of variable-size elements), since it
+/// has access to the actual instance bytes rather than just a length.
+pub struct IrValidateDataEmitter<'a> {
+    type_ir: &'a TypeIr,
+    output: String,
+    temp_idx: usize,
+}
+
+impl<'a> IrValidateDataEmitter<'a> {
+    pub fn new(type_ir: &'a TypeIr) -> Self {
+        Self {
+            type_ir,
+            output: String::new(),
+            temp_idx: 0,
+        }
+    }
+
+    pub fn emit(mut self) -> Result<String, IrValidateError> {
+        let fn_name = format!(
+            "{}_validate_ir_data",
+            sanitize_param_name(&self.type_ir.type_name)
+        );
+        let params = format_ir_parameter_list(self.type_ir);
+        let mut signature = format!("pub fn {}(buf: &[u8]", fn_name);
+        if !params.is_empty() {
+            signature.push_str(", ");
+            signature.push_str(&params);
+        }
+        signature.push_str(") -> Result<u64, AbiIrValidateError>");
+
+        writeln!(&mut self.output, "{} {{", signature).unwrap();
+        let root_path = self.type_ir.type_name.clone();
+        let result_var = self.emit_node(&self.type_ir.root, 1, &root_path, "0")?;
+        writeln!(&mut self.output, "    if {} > buf.len() as u64 {{", result_var).unwrap();
+        writeln!(
+            &mut self.output,
+            "        return Err({});",
+            error_literal(
+                "BufferTooSmall",
+                &root_path,
+                "buf.len() as u64",
+                &result_var,
+                "buf.len() as u64"
+            )
+        )
+        .unwrap();
+        writeln!(&mut self.output, "    }}").unwrap();
+        writeln!(&mut self.output, "    Ok({})", result_var).unwrap();
+        writeln!(&mut self.output, "}}\n").unwrap();
+        Ok(self.output)
+    }
+
+    /// Emits code computing the byte span contributed by `node`. `path` is
+    /// the dotted field path leading to this node (for error reporting) and
+    /// `offset` is a Rust expression for the cursor position at which the
+    /// node's bytes begin. Returns the variable holding that span's length.
+    fn emit_node(
+        &mut self,
+        node: &IrNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
+        match node {
+            IrNode::Const(c) => {
+                let var = self.new_var();
+                writeln!(
+                    &mut self.output,
+                    "{}let {}: u64 = {};",
+                    Self::indent(indent),
+                    var,
+                    c.value
+                )
+                .unwrap();
+                Ok(var)
+            }
+            IrNode::ZeroSize { .. } => {
+                let var = self.new_var();
+                writeln!(
+                    &mut self.output,
+                    "{}let {}: u64 = 0;",
+                    Self::indent(indent),
+                    var
+                )
+                .unwrap();
+                Ok(var)
+            }
+            IrNode::FieldRef(field) => Ok(if let Some(param) = &field.parameter {
+                sanitize_param_name(param)
+            } else {
+                sanitize_param_name(&field.path)
+            }),
+            IrNode::AddChecked(node) => self.emit_add(node, indent, path, offset),
+            IrNode::MulChecked(node) => {
+                let left = self.emit_node(&node.left, indent, path, offset)?;
+                let right = self.emit_node(&node.right, indent, path, offset)?;
+                let var = self.new_var();
+                self.emit_checked(indent, &var, "tn_checked_mul_u64", &left, &right, path, offset);
+                Ok(var)
+            }
+            IrNode::AlignUp(node) => self.emit_align(node, indent, path, offset),
+            IrNode::CallNested(node) => self.emit_call_nested(node, indent, path),
+            IrNode::Switch(node) => self.emit_switch(node, indent, path, offset),
+            IrNode::SumOverArray(node) => self.emit_sum_over_array(node, indent, path, offset),
+        }
+    }
+
+    /// `AddChecked` is the only node that advances the cursor for its
+    /// right-hand side, since the left-hand bytes come first in the stream.
+    fn emit_add(
+        &mut self,
+        node: &BinaryOpNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
+        let left = self.emit_node(&node.left, indent, path, offset)?;
+        let right_offset = self.new_var();
+        self.emit_checked(
+            indent,
+            &right_offset,
+            "tn_checked_add_u64",
+            offset,
+            &left,
+            path,
+            offset,
+        );
+        let right = self.emit_node(&node.right, indent, path, &right_offset)?;
+        let var = self.new_var();
+        self.emit_checked(indent, &var, "tn_checked_add_u64", &left, &right, path, offset);
+        Ok(var)
+    }
+
+    /// Emits `let {var} = match {helper}({a}, {b}) { Some(v) => v, None => return Err(..) };`.
+    fn emit_checked(
+        &mut self,
+        indent: usize,
+        var: &str,
+        helper: &str,
+        a: &str,
+        b: &str,
+        path: &str,
+        offset: &str,
+    ) {
+        writeln!(
+            &mut self.output,
+            "{}let {} = match {}({}, {}) {{",
+            Self::indent(indent),
+            var,
+            helper,
+            a,
+            b
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}    Some(v) => v,", Self::indent(indent)).unwrap();
+        writeln!(
+            &mut self.output,
+            "{}    None => return Err({}),",
+            Self::indent(indent),
+            error_literal("ArithmeticOverflow", path, offset, "0", "0")
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}}};", Self::indent(indent)).unwrap();
+    }
+
+    fn emit_align(
+        &mut self,
+        node: &AlignNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
+        let align = node.alignment.max(1);
+        let inner = self.emit_node(&node.node, indent, path, offset)?;
+        if align <= 1 {
+            return Ok(inner);
+        }
+        let aligned = self.new_var();
+        writeln!(
+            &mut self.output,
+            "{}let mut {} = {};",
+            Self::indent(indent),
+            aligned,
+            inner
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}let rem = {} % {};",
+            Self::indent(indent),
+            aligned,
+            align
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}if rem != 0 {{", Self::indent(indent)).unwrap();
+        writeln!(
+            &mut self.output,
+            "{}    {} = match tn_checked_add_u64({}, {} - rem) {{",
+            Self::indent(indent),
+            aligned,
+            aligned,
+            align
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        Some(v) => v,",
+            Self::indent(indent)
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        None => return Err({}),",
+            Self::indent(indent),
+            error_literal("ArithmeticOverflow", path, offset, "0", "0")
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}    }};", Self::indent(indent)).unwrap();
+        writeln!(&mut self.output, "{}}}", Self::indent(indent)).unwrap();
+        Ok(aligned)
+    }
+
+    fn emit_call_nested(
+        &mut self,
+        node: &CallNestedNode,
+        indent: usize,
+        path: &str,
+    ) -> Result<String, IrValidateError> {
+        let fn_name = format!("{}_footprint_ir", sanitize_param_name(&node.type_name));
+        let mut args = String::new();
+        for (idx, arg) in node.arguments.iter().enumerate() {
+            if idx > 0 {
+                args.push_str(", ");
+            }
+            args.push_str(&sanitize_param_name(&arg.value));
+        }
+        let call = if args.is_empty() {
+            format!("{}()", fn_name)
+        } else {
+            format!("{}({})", fn_name, args)
+        };
+        let var = self.new_var();
+        writeln!(
+            &mut self.output,
+            "{}let {} = {};",
+            Self::indent(indent),
+            var,
+            call
+        )
+        .unwrap();
+        let _ = path;
+        Ok(var)
+    }
+
+    /// In data-aware mode the tag is read straight out of the buffer at the
+    /// current offset instead of trusting a caller-supplied parameter, so the
+    /// validator can be trusted even when the tag itself hasn't been checked
+    /// yet. Tags are encoded as little-endian `u32`s, matching this IR's
+    /// existing discriminant convention.
+    fn emit_switch(
+        &mut self,
+        node: &SwitchNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
+        let switch_path = format!("{}.{}", path, node.tag);
+        let tag = self.new_var();
+        writeln!(
+            &mut self.output,
+            "{}let {} = match tn_read_u32_le(buf, {}) {{",
+            Self::indent(indent),
+            tag,
+            offset
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}    Some(v) => v,", Self::indent(indent)).unwrap();
+        let available_expr = format!("buf.len() as u64 - ({})", offset);
+        writeln!(
+            &mut self.output,
+            "{}    None => return Err({}),",
+            Self::indent(indent),
+            error_literal("BufferTooSmall", &switch_path, offset, "4", &available_expr)
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}}};", Self::indent(indent)).unwrap();
+        let result = self.new_var();
+        writeln!(
+            &mut self.output,
+            "{}let {} = match {} {{",
+            Self::indent(indent),
+            result,
+            tag
+        )
+        .unwrap();
+        for case in &node.cases {
+            writeln!(
+                &mut self.output,
+                "{}{} => {{",
+                Self::indent(indent + 1),
+                case.tag_value
+            )
+            .unwrap();
+            let case_expr = self.emit_node(&case.node, indent + 2, &switch_path, offset)?;
+            writeln!(
+                &mut self.output,
+                "{}{}",
+                Self::indent(indent + 2),
+                case_expr
+            )
+            .unwrap();
+            writeln!(&mut self.output, "{}}},", Self::indent(indent + 1)).unwrap();
+        }
+        if let Some(default_node) = &node.default {
+            writeln!(&mut self.output, "{}_ => {{", Self::indent(indent + 1)).unwrap();
+            let expr = self.emit_node(default_node, indent + 2, &switch_path, offset)?;
+            writeln!(&mut self.output, "{}{}", Self::indent(indent + 2), expr).unwrap();
+            writeln!(&mut self.output, "{}}},", Self::indent(indent + 1)).unwrap();
+        } else {
+            writeln!(
+                &mut self.output,
+                "{}tn_tag => return Err({}),",
+                Self::indent(indent + 1),
+                error_literal("InvalidVariant", &switch_path, offset, "0", "tn_tag")
+            )
+            .unwrap();
+        }
+        writeln!(&mut self.output, "{}}};", Self::indent(indent)).unwrap();
+        Ok(result)
+    }
+
+    /// Loops over the (already-validated) element count, calling the
+    /// element's `*_footprint_ir` to learn each element's size and advancing
+    /// the cursor one element at a time. Bounds-checked against `buf.len()`
+    /// on every iteration, so an undersized buffer is caught as soon as it
+    /// would be overrun rather than only at the very end.
+    fn emit_sum_over_array(
+        &mut self,
+        node: &crate::codegen::shared::ir::SumOverArrayNode,
+        indent: usize,
+        path: &str,
+        offset: &str,
+    ) -> Result<String, IrValidateError> {
+        let array_path = format!("{}.{}[]", path, node.field_name);
+        let count = self.emit_node(&node.count, indent, path, offset)?;
+        let cursor = self.new_var();
+        writeln!(
+            &mut self.output,
+            "{}let mut {}: u64 = {};",
+            Self::indent(indent),
+            cursor,
+            offset
+        )
+        .unwrap();
+        let fn_name = format!(
+            "{}_footprint_ir",
+            sanitize_param_name(&node.element_type_name)
+        );
+        writeln!(
+            &mut self.output,
+            "{}for _ in 0..{} {{",
+            Self::indent(indent),
+            count
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}    let tn_elem_sz = {}();",
+            Self::indent(indent),
+            fn_name
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}    {} = match tn_checked_add_u64({}, tn_elem_sz) {{",
+            Self::indent(indent),
+            cursor,
+            cursor
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        Some(v) => v,",
+            Self::indent(indent)
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        None => return Err({}),",
+            Self::indent(indent),
+            error_literal("ArithmeticOverflow", &array_path, cursor.as_str(), "0", "0")
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}    }};", Self::indent(indent)).unwrap();
+        writeln!(
+            &mut self.output,
+            "{}    if {} > buf.len() as u64 {{",
+            Self::indent(indent),
+            cursor
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "{}        return Err({});",
+            Self::indent(indent),
+            error_literal(
+                "BufferTooSmall",
+                &array_path,
+                &cursor,
+                "tn_elem_sz",
+                "buf.len() as u64"
+            )
+        )
+        .unwrap();
+        writeln!(&mut self.output, "{}    }}", Self::indent(indent)).unwrap();
+        writeln!(&mut self.output, "{}}}", Self::indent(indent)).unwrap();
+        let span = self.new_var();
+        writeln!(
+            &mut self.output,
+            "{}let {} = {} - ({});",
+            Self::indent(indent),
+            span,
+            cursor,
+            offset
+        )
+        .unwrap();
+        Ok(span)
+    }
+
+    fn new_var(&mut self) -> String {
+        let name = format!("tn_val_{}", self.temp_idx);
+        self.temp_idx += 1;
+        name
+    }
+
+    fn indent(level: usize) -> String {
+        "    ".repeat(level)
+    }
+}
+
+pub fn emit_ir_validate_data_fn(type_ir: &TypeIr) -> Result<String, IrValidateError> {
+    IrValidateDataEmitter::new(type_ir).emit()
+}
+
 fn format_ir_parameter_list(type_ir: &TypeIr) -> String {
     type_ir
         .parameters
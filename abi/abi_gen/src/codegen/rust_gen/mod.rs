@@ -22,7 +22,10 @@ mod param_cache_tests;
 pub use builder::emit_builder;
 pub use functions_opaque::emit_opaque_functions;
 pub use ir_footprint::{emit_ir_footprint_fn, IrFootprintEmitter, IrFootprintError};
-pub use ir_validate::{emit_ir_validate_fn, IrValidateEmitter, IrValidateError};
+pub use ir_validate::{
+    emit_ir_validate_data_fn, emit_ir_validate_fn, emit_ir_validate_fn_with_mode,
+    IrValidateDataEmitter, IrValidateEmitter, IrValidateError, ValidateMode,
+};
 pub use types::emit_type;
 
 /* Legacy re-exports - only for analyze command comparison */
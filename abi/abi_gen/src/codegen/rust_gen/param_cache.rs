@@ -450,7 +450,7 @@ fn scan_struct(
                     )));
                 }
             }
-            ResolvedTypeKind::Union { variants } => {
+            ResolvedTypeKind::Union { variants, .. } => {
                 let tag_key = format!("{}._union_tag", field_path);
                 let tag_val = state
                     .ctx
@@ -541,7 +541,7 @@ fn scan_type(
                 )));
             }
         }
-        ResolvedTypeKind::Union { variants } => {
+        ResolvedTypeKind::Union { variants, .. } => {
             let tag_key = format!("{}._union_tag", base_path);
             let tag_val = state
                 .ctx
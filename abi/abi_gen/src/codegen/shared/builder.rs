@@ -510,7 +510,7 @@ impl<'a> IrBuilder<'a> {
     ) -> Result<IrNode, IrBuildError> {
         params.extend_with(&ty.dynamic_params);
         let variants = match &ty.kind {
-            ResolvedTypeKind::Union { variants } => variants,
+            ResolvedTypeKind::Union { variants, .. } => variants,
             _ => {
                 return Err(IrBuildError::UnsupportedSize {
                     type_name: ty.name.clone(),
@@ -1109,6 +1109,8 @@ mod tests {
                             tag_ref: ExprKind::FieldRef(FieldRefExpr {
                                 path: vec!["tag".into()],
                             }),
+                            niche: None,
+                            tag_type: None,
                             variants: vec![EnumVariant {
                                 name: "variant".into(),
                                 tag_value: 0,
@@ -1148,6 +1150,8 @@ mod tests {
                 tag_ref: ExprKind::FieldRef(FieldRefExpr {
                     path: vec!["tag".into()],
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "One".into(),
@@ -1205,6 +1209,8 @@ mod tests {
                 tag_ref: ExprKind::FieldRef(FieldRefExpr {
                     path: vec!["tag".into()],
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "Dyn".into(),
@@ -147,6 +147,7 @@ pub enum IrNode {
     CallNested(CallNestedNode),
     AddChecked(BinaryOpNode),
     MulChecked(BinaryOpNode),
+    SumOverArray(SumOverArrayNode),
 }
 
 /// Represents a compile-time constant footprint contribution.
@@ -230,6 +231,23 @@ pub struct BinaryOpNode {
     pub meta: NodeMetadata,
 }
 
+/// Sums the per-element footprint of a jagged array (variable-size elements)
+/// over its element count. Unlike every other node, evaluating this one
+/// requires walking the actual instance bytes rather than just combining
+/// already-known sizes, since each element's footprint can only be computed
+/// once the previous elements have been consumed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SumOverArrayNode {
+    /// Number of elements in the array.
+    pub count: Box<IrNode>,
+    /// Name of the element type, used to look up its footprint/validate fns.
+    pub element_type_name: String,
+    /// Fully-qualified name of the array field itself (for diagnostics).
+    pub field_name: String,
+    #[serde(flatten)]
+    pub meta: NodeMetadata,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,7 +194,7 @@ where
                 collect_typeref_dependencies(&variant.variant_type, visitor);
             }
         }
-        ResolvedTypeKind::Union { variants } => {
+        ResolvedTypeKind::Union { variants, .. } => {
             for variant in variants {
                 collect_typeref_dependencies(&variant.field_type, visitor);
             }
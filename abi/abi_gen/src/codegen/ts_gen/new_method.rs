@@ -179,7 +179,7 @@ fn emit_union_new_method(resolved_type: &ResolvedType) -> String {
     let mut output = String::new();
     let class_name = &resolved_type.name;
 
-    if let ResolvedTypeKind::Union { variants } = &resolved_type.kind {
+    if let ResolvedTypeKind::Union { variants, .. } = &resolved_type.kind {
         /* Emit separate new methods for each variant */
         for variant in variants {
             let variant_name = escape_ts_keyword(&variant.name);
@@ -497,7 +497,7 @@ fn resolve_segments<'a>(
                 resolve_segments(&variant.variant_type, base, &segments[1..], type_lookup)
             }
         }
-        ResolvedTypeKind::Union { variants } => {
+        ResolvedTypeKind::Union { variants, .. } => {
             let current = segments[0];
             let variant = variants.iter().find(|v| v.name == current)?;
             if segments.len() == 1 {
@@ -77,7 +77,7 @@ fn emit_nested_types(type_def: &ResolvedType, type_path: Option<&str>, output: &
         }
       }
     }
-    ResolvedTypeKind::Union { variants } => {
+    ResolvedTypeKind::Union { variants, .. } => {
       let current_path = type_path.unwrap_or(&type_def.name);
       for variant in variants {
         if is_nested_complex_type(&variant.field_type) {
@@ -119,7 +119,7 @@ fn emit_main_type(resolved_type: &ResolvedType, output: &mut String) {
     ResolvedTypeKind::Struct { fields, .. } => {
       emit_struct_class(class_name, fields, resolved_type, output);
     }
-    ResolvedTypeKind::Union { variants } => {
+    ResolvedTypeKind::Union { variants, .. } => {
       emit_union_class(class_name, variants, resolved_type, output);
     }
     ResolvedTypeKind::Enum { variants, .. } => {
@@ -1,9 +1,13 @@
 use crate::abi::expr::{ConstantExpression, ExprKind, FieldRefExpr};
 use crate::abi::types::{
     ArrayType, EnumType, FloatingPointType, IntegralType, PrimitiveType,
-    SizeDiscriminatedUnionType, StructType, TypeDef, TypeKind, TypeRefType, UnionType,
+    SizeDiscriminatedUnionType, StructField, StructType, TypeDef, TypeKind, TypeRefType,
+    UnionType,
 };
+use serde_json;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DependencyKind {
@@ -59,14 +63,43 @@ pub struct ValidationError {
     pub reason: String,
 }
 
-#[derive(Debug)]
+/// Violation of a post-layout invariant -- one that only makes sense to ask
+/// once offsets and sizes have actually been computed, as opposed to
+/// `ValidationError` (schema-level duplicates) and `LayoutConstraintViolation`
+/// (layout *cycles*). These are assertions, analogous to rustc's
+/// `layout_sanity_check`: under correct construction they should never fire,
+/// so a hit here points at a bug in the layout computation itself rather than
+/// a user-facing schema mistake.
+#[derive(Debug, Clone)]
+pub struct LayoutSanityError {
+    pub error_type: String,
+    pub violating_type: String,
+    pub location: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone)]
 pub struct DependencyGraph {
     pub nodes: HashSet<String>,
     pub edges: Vec<Dependency>,
+    /// Distinct successors only -- two fields of the same type referencing
+    /// the same target collapse to one entry here, so graph algorithms (in-
+    /// degree counts, cycle detection, condensation) see one logical edge
+    /// per dependent pair. Per-pair multiplicity lives in `edge_multiplicity`.
     pub adjacency_list: HashMap<String, Vec<String>>,
+    /// Count of how many `Dependency` entries in `edges` contribute to each
+    /// `(from, to)` pair, e.g. two struct fields of the same type both
+    /// referencing `to`. See `dependency_count`.
+    pub edge_multiplicity: HashMap<(String, String), usize>,
     pub layout_dependencies: Vec<LayoutDependency>,
     pub layout_violations: Vec<LayoutConstraintViolation>,
     pub validation_errors: Vec<ValidationError>,
+    pub layout_sanity_errors: Vec<LayoutSanityError>,
+    /// Top-level field/variant names for each type, in declaration order --
+    /// the node set `field_evaluation_order` schedules, populated from the
+    /// outermost scope frame pushed while analyzing that type (so an inline
+    /// nested struct's fields don't get mistaken for the type's own).
+    pub type_fields: HashMap<String, Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -80,8 +113,11 @@ pub struct DependencyAnalysis {
     pub graph: DependencyGraph,
     pub cycles: Vec<CyclePath>,
     pub topological_order: Option<Vec<String>>,
+    pub condensation: Condensation,
+    pub recursive_groups: Vec<RecursiveGroup>,
     pub layout_violations: Vec<LayoutConstraintViolation>,
     pub validation_errors: Vec<ValidationError>,
+    pub layout_sanity_errors: Vec<LayoutSanityError>,
 }
 
 impl DependencyGraph {
@@ -90,9 +126,12 @@ impl DependencyGraph {
             nodes: HashSet::new(),
             edges: Vec::new(),
             adjacency_list: HashMap::new(),
+            edge_multiplicity: HashMap::new(),
             layout_dependencies: Vec::new(),
             layout_violations: Vec::new(),
             validation_errors: Vec::new(),
+            layout_sanity_errors: Vec::new(),
+            type_fields: HashMap::new(),
         }
     }
 
@@ -105,14 +144,31 @@ impl DependencyGraph {
         self.add_node(dep.from.clone());
         self.add_node(dep.to.clone());
 
-        self.adjacency_list
-            .entry(dep.from.clone())
-            .or_insert_with(Vec::new)
-            .push(dep.to.clone());
+        let count = self
+            .edge_multiplicity
+            .entry((dep.from.clone(), dep.to.clone()))
+            .or_insert(0);
+        if *count == 0 {
+            self.adjacency_list
+                .entry(dep.from.clone())
+                .or_insert_with(Vec::new)
+                .push(dep.to.clone());
+        }
+        *count += 1;
 
         self.edges.push(dep);
     }
 
+    /// How many distinct field/context entries in `edges` create the `from ->
+    /// to` edge. Two struct fields of the same type referencing `to` report
+    /// `2` here even though `adjacency_list` only lists `to` once.
+    pub fn dependency_count(&self, from: &str, to: &str) -> usize {
+        self.edge_multiplicity
+            .get(&(from.to_string(), to.to_string()))
+            .copied()
+            .unwrap_or(0)
+    }
+
     pub fn add_layout_dependency(&mut self, layout_dep: LayoutDependency) {
         self.layout_dependencies.push(layout_dep);
     }
@@ -125,6 +181,10 @@ impl DependencyGraph {
         self.validation_errors.push(error);
     }
 
+    pub fn add_layout_sanity_error(&mut self, error: LayoutSanityError) {
+        self.layout_sanity_errors.push(error);
+    }
+
     /// Detect cycles using DFS with cycle detection
     pub fn detect_cycles(&self) -> Vec<CyclePath> {
         let mut cycles = Vec::new();
@@ -194,10 +254,14 @@ impl DependencyGraph {
             let from = &cycle[i];
             let to = &cycle[i + 1];
 
-            // Find the dependency edge from 'from' to 'to'
-            if let Some(dep) = self.edges.iter().find(|d| d.from == *from && d.to == *to) {
-                cycle_deps.push(dep.clone());
-            }
+            // Surface every field/context that contributes to this edge, not
+            // just the first one, so diagnostics show all culprits.
+            cycle_deps.extend(
+                self.edges
+                    .iter()
+                    .filter(|d| d.from == *from && d.to == *to)
+                    .cloned(),
+            );
         }
 
         cycle_deps
@@ -220,17 +284,22 @@ impl DependencyGraph {
             reverse_adjacency.insert(node.clone(), Vec::new());
         }
 
-        // Calculate in-degrees and build reverse adjacency list
+        // Calculate in-degrees and build reverse adjacency list from the
+        // deduplicated adjacency list -- not `self.edges` directly, since two
+        // fields referencing the same type would otherwise double-count the
+        // edge and strand the dependent node at a non-zero in-degree forever.
         // If A -> B means "A depends on B", then:
         // - A has in-degree +1 (A depends on something)
         // - When we process B, we can enable A (reduce A's in-degree)
-        for dep in &self.edges {
-            *in_degree.entry(dep.from.clone()).or_insert(0) += 1;
-            // B points to A in reverse adjacency (when B is processed, A can be enabled)
-            reverse_adjacency
-                .entry(dep.to.clone())
-                .or_insert_with(Vec::new)
-                .push(dep.from.clone());
+        for (from, successors) in &self.adjacency_list {
+            for to in successors {
+                *in_degree.entry(from.clone()).or_insert(0) += 1;
+                // B points to A in reverse adjacency (when B is processed, A can be enabled)
+                reverse_adjacency
+                    .entry(to.clone())
+                    .or_insert_with(Vec::new)
+                    .push(from.clone());
+            }
         }
 
         // Find all nodes with in-degree 0
@@ -263,188 +332,1496 @@ impl DependencyGraph {
             None // Graph has cycles
         }
     }
-}
 
-pub struct DependencyAnalyzer {
-    graph: DependencyGraph,
-    current_type_name: Option<String>,
-    field_path: Vec<String>,
-}
+    /// Topologically orders `type_name`'s own fields so that any field whose
+    /// runtime size or variant selection depends on another field (an
+    /// `ArraySizeAffecting`/`VariantSelector` entry in `layout_dependencies`
+    /// with both ends inside this type) is scheduled after the field it
+    /// depends on. Gives code generators a concrete read/parse order for
+    /// variable-length and tag-discriminated layouts.
+    ///
+    /// Fields with no recorded dependency are scheduled wherever Kahn's
+    /// algorithm has room for them, ahead of their declaration position if
+    /// nothing blocks that -- callers that additionally want declaration
+    /// order preserved among independent fields should stable-sort the
+    /// result by original index themselves.
+    pub fn field_evaluation_order(
+        &self,
+        type_name: &str,
+    ) -> Result<Vec<String>, LayoutConstraintViolation> {
+        let fields = self.type_fields.get(type_name).cloned().unwrap_or_default();
+
+        // depends_on[field] = fields that must be evaluated before `field`
+        let mut depends_on: HashMap<&str, Vec<&str>> =
+            fields.iter().map(|f| (f.as_str(), Vec::new())).collect();
+        // unlocks[field] = fields waiting on `field`
+        let mut unlocks: HashMap<&str, Vec<&str>> =
+            fields.iter().map(|f| (f.as_str(), Vec::new())).collect();
+
+        for dep in &self.layout_dependencies {
+            if dep.from_type != type_name || dep.to_type != type_name {
+                continue;
+            }
+            if !matches!(
+                dep.kind,
+                LayoutDependencyKind::ArraySizeAffecting | LayoutDependencyKind::VariantSelector
+            ) {
+                continue;
+            }
+            let (Some(from_field), Some(to_field)) = (&dep.from_field, &dep.to_field) else {
+                continue;
+            };
+            if from_field == to_field
+                || !depends_on.contains_key(from_field.as_str())
+                || !depends_on.contains_key(to_field.as_str())
+            {
+                continue;
+            }
+            depends_on
+                .entry(from_field.as_str())
+                .or_default()
+                .push(to_field.as_str());
+            unlocks
+                .entry(to_field.as_str())
+                .or_default()
+                .push(from_field.as_str());
+        }
 
-impl DependencyAnalyzer {
-    pub fn new() -> Self {
-        Self {
-            graph: DependencyGraph::new(),
-            current_type_name: None,
-            field_path: Vec::new(),
+        let mut in_degree: HashMap<&str, usize> = depends_on
+            .iter()
+            .map(|(field, deps)| (*field, deps.len()))
+            .collect();
+
+        let mut queue: VecDeque<&str> = fields
+            .iter()
+            .map(|f| f.as_str())
+            .filter(|f| in_degree.get(f).copied().unwrap_or(0) == 0)
+            .collect();
+
+        let mut order: Vec<String> = Vec::with_capacity(fields.len());
+        while let Some(field) = queue.pop_front() {
+            order.push(field.to_string());
+            if let Some(waiters) = unlocks.get(field) {
+                for waiter in waiters {
+                    if let Some(degree) = in_degree.get_mut(waiter) {
+                        *degree -= 1;
+                        if *degree == 0 {
+                            queue.push_back(waiter);
+                        }
+                    }
+                }
+            }
         }
-    }
 
-    pub fn analyze_typedef(&mut self, typedef: &TypeDef) -> DependencyAnalysis {
-        self.current_type_name = Some(typedef.name.clone());
-        self.graph.add_node(typedef.name.clone());
+        if order.len() == fields.len() {
+            return Ok(order);
+        }
 
-        self.analyze_type_kind(&typedef.kind);
+        let stuck: Vec<&str> = fields
+            .iter()
+            .map(|f| f.as_str())
+            .filter(|f| !order.contains(&f.to_string()))
+            .collect();
+        let chain = self
+            .find_field_cycle(&stuck, &depends_on)
+            .unwrap_or_else(|| stuck.iter().map(|f| f.to_string()).collect());
+
+        Err(LayoutConstraintViolation {
+            violating_type: type_name.to_string(),
+            violating_expression: format!("field evaluation schedule for '{}'", type_name),
+            dependency_chain: chain.clone(),
+            reason: format!(
+                "field(s) in '{}' have a circular size/tag dependency: {}",
+                type_name,
+                chain.join(" -> ")
+            ),
+        })
+    }
 
-        let cycles = self.graph.detect_cycles();
-        let topological_order = self.graph.topological_sort();
-        let layout_violations = self.graph.layout_violations.clone();
-        let validation_errors = self.graph.validation_errors.clone();
+    /// Finds one cycle among `stuck` fields (those Kahn's algorithm couldn't
+    /// schedule) by walking `depends_on` edges, for reporting a concrete
+    /// `dependency_chain` rather than just the unordered leftover set.
+    fn find_field_cycle(
+        &self,
+        stuck: &[&str],
+        depends_on: &HashMap<&str, Vec<&str>>,
+    ) -> Option<Vec<String>> {
+        let stuck_set: HashSet<&str> = stuck.iter().copied().collect();
+        let mut visited: HashSet<&str> = HashSet::new();
 
-        DependencyAnalysis {
-            graph: std::mem::replace(&mut self.graph, DependencyGraph::new()),
-            cycles,
-            topological_order,
-            layout_violations,
-            validation_errors,
+        for &start in stuck {
+            if visited.contains(start) {
+                continue;
+            }
+            let mut path: Vec<&str> = Vec::new();
+            let mut on_path: HashSet<&str> = HashSet::new();
+            let mut node = start;
+            loop {
+                if on_path.contains(node) {
+                    let cycle_start = path.iter().position(|f| *f == node).unwrap();
+                    let mut chain: Vec<String> =
+                        path[cycle_start..].iter().map(|f| f.to_string()).collect();
+                    chain.push(node.to_string());
+                    return Some(chain);
+                }
+                if visited.contains(node) {
+                    break;
+                }
+                visited.insert(node);
+                on_path.insert(node);
+                path.push(node);
+
+                // Only follow an edge back into the stuck set -- a dependency
+                // that already got scheduled can't be part of the cycle
+                // keeping this field unscheduled.
+                match depends_on
+                    .get(node)
+                    .and_then(|deps| deps.iter().find(|d| stuck_set.contains(*d)))
+                {
+                    Some(next) => node = next,
+                    None => break,
+                }
+            }
         }
+
+        None
     }
 
-    pub fn analyze_multiple_typedefs(&mut self, typedefs: &[TypeDef]) -> DependencyAnalysis {
-        // First pass: add all type names as nodes
-        for typedef in typedefs {
-            self.graph.add_node(typedef.name.clone());
+    /// Collapse the graph into its strongly-connected components (Tarjan's
+    /// algorithm, run with an explicit stack so it can't blow the call stack
+    /// on a deep or wide graph) and return them in a valid emit order.
+    ///
+    /// Unlike `topological_sort`, this never gives up: a group of mutually
+    /// recursive types (e.g. two structs that reference each other through an
+    /// indirection field) becomes one multi-member `SccComponent` instead of
+    /// making the whole graph unorderable.
+    pub fn condense(&self) -> Condensation {
+        /// One DFS call frame. `pending_merge` is `Some(child)` exactly when
+        /// we just pushed a frame for an unvisited `child` and need to fold
+        /// its lowlink into ours once it returns.
+        #[derive(Clone)]
+        struct Frame {
+            node: String,
+            next: usize,
+            pending_merge: Option<String>,
         }
 
-        // Second pass: analyze dependencies
-        for typedef in typedefs {
-            self.current_type_name = Some(typedef.name.clone());
-            self.analyze_type_kind(&typedef.kind);
-        }
+        let mut counter: u32 = 0;
+        let mut index: HashMap<String, u32> = HashMap::new();
+        let mut lowlink: HashMap<String, u32> = HashMap::new();
+        let mut on_stack: HashSet<String> = HashSet::new();
+        let mut tarjan_stack: Vec<String> = Vec::new();
+        let mut components: Vec<Vec<String>> = Vec::new();
 
-        // Third pass: validate basic structure (no duplicates, etc.)
-        self.validate_basic_structure(typedefs);
+        for start in &self.nodes {
+            if index.contains_key(start) {
+                continue;
+            }
 
-        // Fourth pass: validate layout constraints after building dependencies
-        self.validate_layout_constraints(typedefs);
+            let mut work = vec![Frame {
+                node: start.clone(),
+                next: 0,
+                pending_merge: None,
+            }];
+
+            while let Some(mut frame) = work.pop() {
+                if !index.contains_key(&frame.node) {
+                    index.insert(frame.node.clone(), counter);
+                    lowlink.insert(frame.node.clone(), counter);
+                    counter += 1;
+                    tarjan_stack.push(frame.node.clone());
+                    on_stack.insert(frame.node.clone());
+                }
 
-        let cycles = self.graph.detect_cycles();
-        let topological_order = self.graph.topological_sort();
-        let layout_violations = self.graph.layout_violations.clone();
-        let validation_errors = self.graph.validation_errors.clone();
+                if let Some(child) = frame.pending_merge.take() {
+                    let folded = lowlink[&frame.node].min(lowlink[&child]);
+                    lowlink.insert(frame.node.clone(), folded);
+                }
 
-        DependencyAnalysis {
-            graph: std::mem::replace(&mut self.graph, DependencyGraph::new()),
-            cycles,
-            topological_order,
-            layout_violations,
-            validation_errors,
-        }
-    }
+                let neighbors = self
+                    .adjacency_list
+                    .get(&frame.node)
+                    .cloned()
+                    .unwrap_or_default();
+
+                let mut recursed = false;
+                while frame.next < neighbors.len() {
+                    let neighbor = neighbors[frame.next].clone();
+                    frame.next += 1;
+
+                    if !index.contains_key(&neighbor) {
+                        frame.pending_merge = Some(neighbor.clone());
+                        work.push(frame.clone());
+                        work.push(Frame {
+                            node: neighbor,
+                            next: 0,
+                            pending_merge: None,
+                        });
+                        recursed = true;
+                        break;
+                    } else if on_stack.contains(&neighbor) {
+                        let folded = lowlink[&frame.node].min(index[&neighbor]);
+                        lowlink.insert(frame.node.clone(), folded);
+                    }
+                    // else: already finished into an earlier component -- no edge to fold
+                }
 
-    fn analyze_type_kind(&mut self, type_kind: &TypeKind) {
-        match type_kind {
-            TypeKind::Struct(struct_type) => self.analyze_struct(struct_type),
-            TypeKind::Union(union_type) => self.analyze_union(union_type),
-            TypeKind::Enum(enum_type) => self.analyze_enum(enum_type),
-            TypeKind::Array(array_type) => self.analyze_array(array_type),
-            TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
-                self.analyze_size_discriminated_union(size_disc_union)
+                if recursed {
+                    continue;
+                }
+
+                if lowlink[&frame.node] == index[&frame.node] {
+                    let mut members = Vec::new();
+                    loop {
+                        let member = tarjan_stack.pop().expect("root's own frame is on the stack");
+                        on_stack.remove(&member);
+                        let is_root = member == frame.node;
+                        members.push(member);
+                        if is_root {
+                            break;
+                        }
+                    }
+                    components.push(members);
+                }
             }
-            TypeKind::TypeRef(type_ref) => self.analyze_type_ref(type_ref),
-            TypeKind::Primitive(_) => {} // Primitives have no dependencies
         }
-    }
 
-    fn analyze_struct(&mut self, struct_type: &StructType) {
-        for field in &struct_type.fields {
-            self.field_path.push(field.name.clone());
-            self.analyze_type_kind(&field.field_type);
-            self.field_path.pop();
-        }
+        let components = components
+            .into_iter()
+            .map(|members| {
+                let is_recursive = members.len() > 1
+                    || members.iter().any(|member| {
+                        self.adjacency_list
+                            .get(member)
+                            .is_some_and(|neighbors| neighbors.contains(member))
+                    });
+                SccComponent {
+                    members,
+                    is_recursive,
+                }
+            })
+            .collect();
+
+        Condensation { components }
     }
+}
 
-    fn analyze_union(&mut self, union_type: &UnionType) {
-        for variant in &union_type.variants {
-            self.field_path.push(variant.name.clone());
-            self.analyze_type_kind(&variant.variant_type);
-            self.field_path.pop();
+/// One strongly-connected component of the dependency graph: either a single
+/// non-recursive type, or a group of types that can only be emitted together.
+#[derive(Debug, Clone)]
+pub struct SccComponent {
+    pub members: Vec<String>,
+    /// True for a multi-member component, or a single type with a self-edge
+    pub is_recursive: bool,
+}
+
+/// The dependency graph collapsed to its strongly-connected components and
+/// topologically ordered: a component never appears before one of its own
+/// dependencies, matching the order `topological_sort` produces for acyclic
+/// graphs.
+#[derive(Debug)]
+pub struct Condensation {
+    pub components: Vec<SccComponent>,
+}
+
+/// A strongly-connected group of two or more mutually-referencing types (or a
+/// single type with a self-edge) that `topological_sort` would have reported
+/// as an unbreakable cycle. Codegen can order these by emitting forward
+/// declarations for the group, so this is recorded as an annotation on
+/// `DependencyAnalysis` instead of failing analysis outright.
+#[derive(Debug, Clone)]
+pub struct RecursiveGroup {
+    pub members: Vec<String>,
+}
+
+fn align_up(value: u64, alignment: u64) -> u64 {
+    (value + alignment - 1) & !(alignment - 1)
+}
+
+/// Picks the smallest `IntegralType` that can represent every value in
+/// `values`, mirroring rustc's `repr_discr`: unsigned widths are tried first
+/// when every value is non-negative (the common case for this schema's `u64`
+/// tag values), falling back to the smallest signed width covering both ends
+/// of the range otherwise.
+fn minimal_integral_type_for_values(values: &[i128]) -> IntegralType {
+    let min = values.iter().copied().min().unwrap_or(0);
+    let max = values.iter().copied().max().unwrap_or(0);
+    minimal_integral_type_for_range(min, max)
+}
+
+/// As `minimal_integral_type_for_values`, but for an already-computed
+/// `[min, max]` range.
+fn minimal_integral_type_for_range(min: i128, max: i128) -> IntegralType {
+    if min >= 0 {
+        if max <= u8::MAX as i128 {
+            IntegralType::U8
+        } else if max <= u16::MAX as i128 {
+            IntegralType::U16
+        } else if max <= u32::MAX as i128 {
+            IntegralType::U32
+        } else {
+            IntegralType::U64
         }
+    } else if min >= i8::MIN as i128 && max <= i8::MAX as i128 {
+        IntegralType::I8
+    } else if min >= i16::MIN as i128 && max <= i16::MAX as i128 {
+        IntegralType::I16
+    } else if min >= i32::MIN as i128 && max <= i32::MAX as i128 {
+        IntegralType::I32
+    } else {
+        IntegralType::I64
     }
+}
 
-    fn analyze_size_discriminated_union(&mut self, size_disc_union: &SizeDiscriminatedUnionType) {
-        for variant in &size_disc_union.variants {
-            self.field_path.push(variant.name.clone());
-            self.analyze_type_kind(&variant.variant_type);
-            self.field_path.pop();
-        }
+/// As `minimal_integral_type_for_range`, but for a range expressed as an
+/// inclusive wrap-around pair `(start, end)`: when `start <= end` this is an
+/// ordinary `start..=end` range, but when `start > end` it denotes the
+/// discriminants clustering at both ends of the backing type's domain --
+/// `start..=MAX` followed by `0..=end` -- which happens when an enum's live
+/// tag values are, e.g., `{0, 1, u32::MAX - 1, u32::MAX}`. `domain_max` is
+/// the largest value the *current* backing representation can hold (so the
+/// wrap point is known without already knowing the answer).
+fn minimal_integral_type_for_wraparound_range(start: i128, end: i128, domain_max: i128) -> IntegralType {
+    if start <= end {
+        return minimal_integral_type_for_range(start, end);
     }
+    // The live values span [start, domain_max] union [0, end]; representing
+    // that wrapped span still only needs a type wide enough for domain_max.
+    minimal_integral_type_for_range(0, domain_max)
+}
 
-    fn analyze_enum(&mut self, enum_type: &EnumType) {
-        // Analyze tag expression for field references
-        self.analyze_expression(&enum_type.tag_ref, DependencyKind::TagExpression);
+/// Picks the smallest `IntegralType` that can hold every declared
+/// `tag_value` on an enum, mirroring rustc's `repr_discr`. `tag_value` is a
+/// `u64`, so the inferred type is always unsigned.
+/// `DependencyAnalyzer::infer_minimal_enum_tag_type` delegates here -- pulled
+/// out to a free function so `LayoutCalculator::compute` can size an enum's
+/// tag without a `DependencyAnalyzer` in scope.
+fn infer_minimal_enum_tag_type(enum_type: &EnumType) -> IntegralType {
+    let values: Vec<i128> = enum_type
+        .variants
+        .iter()
+        .map(|v| v.tag_value as i128)
+        .collect();
+    minimal_integral_type_for_values(&values)
+}
 
-        for variant in &enum_type.variants {
-            self.field_path.push(variant.name.clone());
-            self.analyze_type_kind(&variant.variant_type);
-            self.field_path.pop();
+/// Reports whether a type's size can vary at runtime (a non-constant array
+/// length, a size-discriminated union, a non-constant enum tag expression, or
+/// any field/element that itself has variable size).
+/// `DependencyAnalyzer::type_has_variable_size` delegates here -- pulled out
+/// to a free function so the layout-size report can use it without a
+/// `DependencyAnalyzer` in scope.
+fn type_has_variable_size(type_kind: &TypeKind, all_typedefs: &[TypeDef]) -> bool {
+    match type_kind {
+        TypeKind::Primitive(_) => false, // Primitives have fixed size
+        TypeKind::TypeRef(type_ref) => {
+            if let Some(typedef) = all_typedefs.iter().find(|td| td.name == type_ref.name) {
+                type_has_variable_size(&typedef.kind, all_typedefs)
+            } else {
+                false // Unknown type, assume constant for now
+            }
+        }
+        TypeKind::Struct(struct_type) => {
+            // A struct has variable size if any of its fields have variable size
+            for field in &struct_type.fields {
+                if type_has_variable_size(&field.field_type, all_typedefs) {
+                    return true;
+                }
+            }
+            false
+        }
+        TypeKind::Union(_) => false, // Regular unions have fixed size (max of all variants)
+        TypeKind::SizeDiscriminatedUnion(_) => true, // Size-discriminated unions have variable size by definition
+        TypeKind::Enum(enum_type) => {
+            // Enums have variable size if their tag is non-constant or variants have different sizes
+            if !enum_type.tag_ref.is_constant() {
+                return true;
+            }
+            // Check if variants have different sizes (simplified check)
+            // In a full implementation, we'd calculate actual variant sizes
+            false
+        }
+        TypeKind::Array(array_type) => {
+            // Arrays have variable size if their size expression is non-constant
+            // or if their element type has variable size
+            !array_type.size.is_constant()
+                || type_has_variable_size(&array_type.element_type, all_typedefs)
         }
     }
+}
 
-    fn analyze_array(&mut self, array_type: &ArrayType) {
-        // Analyze size expression for field references
-        self.analyze_expression(&array_type.size, DependencyKind::SizeExpression);
+/// Byte size of a primitive type, independent of any `TargetDataLayout` --
+/// `IntegralType`/`FloatingPointType` widths are fixed by the schema, not by
+/// the target. `DependencyAnalyzer::get_primitive_size` delegates here.
+fn primitive_size(prim: &PrimitiveType) -> u64 {
+    match prim {
+        PrimitiveType::Integral(int_type) => match int_type {
+            IntegralType::U8 | IntegralType::I8 => 1,
+            IntegralType::U16 | IntegralType::I16 => 2,
+            IntegralType::U32 | IntegralType::I32 => 4,
+            IntegralType::U64 | IntegralType::I64 => 8,
+        },
+        PrimitiveType::FloatingPoint(float_type) => match float_type {
+            FloatingPointType::F16 => 2,
+            FloatingPointType::F32 => 4,
+            FloatingPointType::F64 => 8,
+        },
+    }
+}
 
-        // Analyze element type
-        self.analyze_type_kind(&array_type.element_type);
+/// Resolves a literal expression to a `u64`, rejecting negative signed
+/// literals. `DependencyAnalyzer::literal_as_u64` delegates here.
+fn literal_as_u64(literal: &crate::abi::expr::LiteralExpr) -> Option<u64> {
+    use crate::abi::expr::LiteralExpr;
+    match literal {
+        LiteralExpr::U64(v) => Some(*v),
+        LiteralExpr::U32(v) => Some(*v as u64),
+        LiteralExpr::U16(v) => Some(*v as u64),
+        LiteralExpr::U8(v) => Some(*v as u64),
+        LiteralExpr::I64(v) if *v >= 0 => Some(*v as u64),
+        LiteralExpr::I32(v) if *v >= 0 => Some(*v as u64),
+        LiteralExpr::I16(v) if *v >= 0 => Some(*v as u64),
+        LiteralExpr::I8(v) if *v >= 0 => Some(*v as u64),
+        _ => None,
     }
+}
 
-    fn analyze_type_ref(&mut self, type_ref: &TypeRefType) {
-        if let Some(current_type) = &self.current_type_name {
-            let context = if self.field_path.is_empty() {
-                "direct type reference".to_string()
-            } else {
-                format!("field: {}", self.field_path.join("."))
-            };
+/// Byte ordering `LayoutCalculator` assumes when asked for a `TargetDataLayout`'s
+/// pointer/primitive sizes. This has no bearing on field offsets or sizes --
+/// it only matters to whatever downstream codegen ultimately emits loads and
+/// stores -- but it's part of the same per-target configuration bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    Little,
+    Big,
+}
 
-            self.graph.add_dependency(Dependency {
-                from: current_type.clone(), // Current type depends on referenced type
-                to: type_ref.name.clone(),  // Referenced type must come first
-                kind: DependencyKind::TypeReference,
-                context,
-            });
+/// Target-specific sizing knobs for `LayoutCalculator`. `pointer_size` mirrors
+/// a real target-data-layout string's pointer width (this schema has no
+/// pointer type yet, so it's unused by `compute` today, but the field exists
+/// so a future pointer-carrying type doesn't require a breaking change here).
+/// `primitive_aligns` overrides a primitive's natural (size-equals-alignment)
+/// alignment for targets where that doesn't hold, e.g. a `u64` that only
+/// aligns to 4 bytes on some 32-bit ABIs.
+#[derive(Debug, Clone)]
+pub struct TargetDataLayout {
+    pub endian: Endian,
+    pub pointer_size: u64,
+    pub primitive_aligns: HashMap<IntegralType, u64>,
+}
+
+impl Default for TargetDataLayout {
+    fn default() -> Self {
+        Self {
+            endian: Endian::Little,
+            pointer_size: 8,
+            primitive_aligns: HashMap::new(),
         }
     }
+}
 
-    fn analyze_expression(&mut self, expr: &ExprKind, dep_kind: DependencyKind) {
-        match expr {
-            ExprKind::Literal(_) => {} // Literals have no dependencies
-            ExprKind::FieldRef(field_ref) => {
-                self.analyze_field_reference(field_ref, dep_kind);
+/// A concrete `(size, align)` pair, analogous to `std::alloc::Layout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub size: u64,
+    pub align: u64,
+}
+
+/// Computes concrete `Layout`s (real byte counts) for any `TypeKind` that
+/// `DependencyAnalyzer::type_has_variable_size` reports as fixed, given a
+/// `TargetDataLayout`. This is what replaces the boolean-only
+/// `type_has_variable_size` approximation once actual bytes are needed --
+/// `SizeDiscriminatedUnion` is always returned as `None` since its size
+/// depends on which variant is actually present at runtime, which this
+/// general-purpose recursive sizer has no way to know.
+pub struct LayoutCalculator<'a> {
+    data_layout: &'a TargetDataLayout,
+}
+
+impl<'a> LayoutCalculator<'a> {
+    pub fn new(data_layout: &'a TargetDataLayout) -> Self {
+        Self { data_layout }
+    }
+
+    pub fn compute(&self, type_kind: &TypeKind, all_typedefs: &[TypeDef]) -> Option<Layout> {
+        match type_kind {
+            TypeKind::Primitive(prim) => {
+                let size = primitive_size(prim);
+                Some(Layout {
+                    size,
+                    align: self.primitive_align(prim, size),
+                })
             }
-            ExprKind::Sizeof(sizeof_expr) => {
-                // Sizeof creates a type dependency
-                if let Some(current_type) = &self.current_type_name {
-                    let context =
-                        format!("sizeof expression in field: {}", self.field_path.join("."));
-                    self.graph.add_dependency(Dependency {
-                        from: current_type.clone(),
-                        to: sizeof_expr.type_name.clone(),
-                        kind: DependencyKind::TypeReference,
-                        context,
-                    });
+            TypeKind::TypeRef(type_ref) => {
+                let typedef = all_typedefs.iter().find(|td| td.name == type_ref.name)?;
+                self.compute(&typedef.kind, all_typedefs)
+            }
+            TypeKind::Struct(struct_type) => {
+                let packed = struct_type.container_attributes.packed;
+                let mut offset = 0u64;
+                let mut align = 1u64;
+                for field in &struct_type.fields {
+                    let field_layout = self.compute(&field.field_type, all_typedefs)?;
+                    offset = if packed {
+                        offset
+                    } else {
+                        align = align.max(field_layout.align);
+                        align_up(offset, field_layout.align)
+                    } + field_layout.size;
                 }
+                if struct_type.container_attributes.aligned > 0 {
+                    align = struct_type.container_attributes.aligned;
+                }
+                let size = if packed { offset } else { align_up(offset, align) };
+                Some(Layout { size, align })
             }
-            ExprKind::Alignof(alignof_expr) => {
-                // Alignof creates a type dependency
-                if let Some(current_type) = &self.current_type_name {
-                    let context =
-                        format!("alignof expression in field: {}", self.field_path.join("."));
-                    self.graph.add_dependency(Dependency {
-                        from: current_type.clone(),
-                        to: alignof_expr.type_name.clone(),
-                        kind: DependencyKind::TypeReference,
-                        context,
-                    });
+            TypeKind::Union(union_type) => {
+                let mut size = 0u64;
+                let mut align = 1u64;
+                for variant in &union_type.variants {
+                    let variant_layout = self.compute(&variant.variant_type, all_typedefs)?;
+                    size = size.max(variant_layout.size);
+                    align = align.max(variant_layout.align);
                 }
+                if union_type.container_attributes.aligned > 0 {
+                    align = union_type.container_attributes.aligned;
+                }
+                Some(Layout {
+                    size: align_up(size, align),
+                    align,
+                })
             }
-
-            // Binary operations - recursively analyze operands
-            ExprKind::Add(expr) => {
-                self.analyze_expression(&expr.left, dep_kind.clone());
-                self.analyze_expression(&expr.right, dep_kind);
+            TypeKind::Array(array_type) => {
+                let element_layout = self.compute(&array_type.element_type, all_typedefs)?;
+                let count = match &array_type.size {
+                    ExprKind::Literal(literal) => literal_as_u64(literal),
+                    _ => None,
+                }?;
+                Some(Layout {
+                    size: element_layout.size * count,
+                    align: element_layout.align,
+                })
             }
-            ExprKind::Sub(expr) => {
+            TypeKind::Enum(enum_type) => self.enum_layout(enum_type, all_typedefs),
+            TypeKind::SizeDiscriminatedUnion(_) => None,
+        }
+    }
+
+    /// Sizes an enum as a niche-filling layout (no tag bytes -- just the
+    /// dataful variant's own layout) or, otherwise, as a tag field sized via
+    /// `infer_minimal_enum_tag_type` (or the declared `tag_type`, when
+    /// present) followed by the max-size/max-align payload over all variants,
+    /// mirroring the struct recurrence's offset-padding-then-align-up shape.
+    fn enum_layout(&self, enum_type: &EnumType, all_typedefs: &[TypeDef]) -> Option<Layout> {
+        if let Some(niche) = &enum_type.niche {
+            let dataful_variant = enum_type
+                .variants
+                .iter()
+                .find(|v| v.name == niche.dataful_variant)?;
+            return self.compute(&dataful_variant.variant_type, all_typedefs);
+        }
+
+        let tag_type = enum_type
+            .tag_type
+            .clone()
+            .unwrap_or_else(|| infer_minimal_enum_tag_type(enum_type));
+        let tag_prim = PrimitiveType::Integral(tag_type);
+        let tag_size = primitive_size(&tag_prim);
+        let tag_align = self.primitive_align(&tag_prim, tag_size);
+
+        let mut payload_size = 0u64;
+        let mut payload_align = 1u64;
+        for variant in &enum_type.variants {
+            let variant_layout = self.compute(&variant.variant_type, all_typedefs)?;
+            payload_size = payload_size.max(variant_layout.size);
+            payload_align = payload_align.max(variant_layout.align);
+        }
+
+        let mut align = tag_align.max(payload_align);
+        if enum_type.container_attributes.aligned > 0 {
+            align = enum_type.container_attributes.aligned;
+        }
+        let payload_offset = align_up(tag_size, payload_align);
+        let size = align_up(payload_offset + payload_size, align);
+        Some(Layout { size, align })
+    }
+
+    fn primitive_align(&self, prim: &PrimitiveType, natural_size: u64) -> u64 {
+        match prim {
+            PrimitiveType::Integral(int_type) => self
+                .data_layout
+                .primitive_aligns
+                .get(int_type)
+                .copied()
+                .unwrap_or(natural_size),
+            PrimitiveType::FloatingPoint(_) => natural_size,
+        }
+    }
+}
+
+/// Whether a reported size is the type's exact, always-true size, or only a
+/// lower bound because the type has a variable-size trailing member -- as in
+/// rustc's own `-Zprint-type-sizes` output, which flags types containing a
+/// trailing DST field the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeKind {
+    Exact,
+    Min,
+}
+
+/// One field's placement within a `VariantInfo`, including the padding bytes
+/// the layout algorithm inserted immediately before it to satisfy its
+/// alignment.
+#[derive(Debug, Clone)]
+pub struct FieldInfo {
+    pub name: String,
+    pub offset: u64,
+    pub size: u64,
+    pub align: u64,
+    pub padding_before: u64,
+}
+
+/// One variant's layout: a struct has exactly one (named after the struct
+/// itself), while an enum, union, or size-discriminated union report one per
+/// declared variant.
+#[derive(Debug, Clone)]
+pub struct VariantInfo {
+    pub name: String,
+    pub size_kind: SizeKind,
+    pub size: u64,
+    pub fields: Vec<FieldInfo>,
+}
+
+/// A full per-type layout report, modeled on compiler `-Zprint-type-sizes`
+/// output: overall size/align, whether that size is `Exact` or only a `Min`
+/// lower bound, and a breakdown of every variant's fields with their offsets
+/// and padding. `discriminant_size` is the tag's byte size for a tagged enum
+/// (`Some(0)` for a niche-filling enum, which spends no bytes on a tag), and
+/// `None` for types with no discriminant at all (structs, unions).
+#[derive(Debug, Clone)]
+pub struct TypeSizeInfo {
+    pub name: String,
+    pub overall_size: u64,
+    pub size_kind: SizeKind,
+    pub align: u64,
+    pub packed: bool,
+    pub discriminant_size: Option<u64>,
+    pub variants: Vec<VariantInfo>,
+}
+
+/// Builds a `TypeSizeInfo` report for a single `TypeDef`, using a
+/// `LayoutCalculator` to size every field/variant that has a fixed size.
+/// Returns `None` for `Primitive`, `TypeRef`, and `Array` typedefs, which
+/// don't have a variant/field breakdown of their own to report.
+pub fn report_type_size(
+    typedef: &TypeDef,
+    all_typedefs: &[TypeDef],
+    data_layout: &TargetDataLayout,
+) -> Option<TypeSizeInfo> {
+    let calculator = LayoutCalculator::new(data_layout);
+    match &typedef.kind {
+        TypeKind::Struct(struct_type) => Some(report_struct_size(
+            &typedef.name,
+            struct_type,
+            all_typedefs,
+            &calculator,
+        )),
+        TypeKind::Union(union_type) => Some(report_union_size(
+            &typedef.name,
+            union_type,
+            all_typedefs,
+            &calculator,
+        )),
+        TypeKind::Enum(enum_type) => Some(report_enum_size(
+            &typedef.name,
+            enum_type,
+            all_typedefs,
+            &calculator,
+        )),
+        TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
+            Some(report_size_discriminated_union_size(
+                &typedef.name,
+                size_disc_union,
+            ))
+        }
+        TypeKind::Primitive(_) | TypeKind::TypeRef(_) | TypeKind::Array(_) => None,
+    }
+}
+
+fn report_struct_size(
+    name: &str,
+    struct_type: &StructType,
+    all_typedefs: &[TypeDef],
+    calculator: &LayoutCalculator<'_>,
+) -> TypeSizeInfo {
+    let packed = struct_type.container_attributes.packed;
+    let mut fields = Vec::new();
+    let mut offset = 0u64;
+    let mut align = 1u64;
+    let mut size_kind = SizeKind::Exact;
+
+    for field in &struct_type.fields {
+        if type_has_variable_size(&field.field_type, all_typedefs) {
+            // The unsized-tail rule (enforced elsewhere) means this is the
+            // struct's last field; its own size is runtime-dependent, so the
+            // struct's reported size becomes a `Min` lower bound that stops
+            // at the fixed prefix before it.
+            fields.push(FieldInfo {
+                name: field.name.clone(),
+                offset,
+                size: 0,
+                align: 1,
+                padding_before: 0,
+            });
+            size_kind = SizeKind::Min;
+            break;
+        }
+
+        let Some(field_layout) = calculator.compute(&field.field_type, all_typedefs) else {
+            // A fixed-size field whose layout we still couldn't compute
+            // (e.g. a non-constant array length that isn't actually this
+            // field's trailing position) -- treat the same as a variable tail.
+            fields.push(FieldInfo {
+                name: field.name.clone(),
+                offset,
+                size: 0,
+                align: 1,
+                padding_before: 0,
+            });
+            size_kind = SizeKind::Min;
+            break;
+        };
+
+        let field_offset = if packed {
+            offset
+        } else {
+            align = align.max(field_layout.align);
+            align_up(offset, field_layout.align)
+        };
+        let padding_before = field_offset - offset;
+        fields.push(FieldInfo {
+            name: field.name.clone(),
+            offset: field_offset,
+            size: field_layout.size,
+            align: field_layout.align,
+            padding_before,
+        });
+        offset = field_offset + field_layout.size;
+    }
+
+    if struct_type.container_attributes.aligned > 0 {
+        align = struct_type.container_attributes.aligned;
+    }
+    let overall_size = if packed || matches!(size_kind, SizeKind::Min) {
+        offset
+    } else {
+        align_up(offset, align)
+    };
+
+    TypeSizeInfo {
+        name: name.to_string(),
+        overall_size,
+        size_kind,
+        align,
+        packed,
+        discriminant_size: None,
+        variants: vec![VariantInfo {
+            name: name.to_string(),
+            size_kind,
+            size: overall_size,
+            fields,
+        }],
+    }
+}
+
+fn report_union_size(
+    name: &str,
+    union_type: &UnionType,
+    all_typedefs: &[TypeDef],
+    calculator: &LayoutCalculator<'_>,
+) -> TypeSizeInfo {
+    let mut variants = Vec::new();
+    let mut max_size = 0u64;
+    let mut align = 1u64;
+    let mut size_kind = SizeKind::Exact;
+
+    for variant in &union_type.variants {
+        match calculator.compute(&variant.variant_type, all_typedefs) {
+            Some(layout) => {
+                max_size = max_size.max(layout.size);
+                align = align.max(layout.align);
+                variants.push(VariantInfo {
+                    name: variant.name.clone(),
+                    size_kind: SizeKind::Exact,
+                    size: layout.size,
+                    fields: vec![FieldInfo {
+                        name: variant.name.clone(),
+                        offset: 0,
+                        size: layout.size,
+                        align: layout.align,
+                        padding_before: 0,
+                    }],
+                });
+            }
+            None => {
+                size_kind = SizeKind::Min;
+                variants.push(VariantInfo {
+                    name: variant.name.clone(),
+                    size_kind: SizeKind::Min,
+                    size: 0,
+                    fields: Vec::new(),
+                });
+            }
+        }
+    }
+
+    if union_type.container_attributes.aligned > 0 {
+        align = union_type.container_attributes.aligned;
+    }
+    let overall_size = align_up(max_size, align);
+
+    TypeSizeInfo {
+        name: name.to_string(),
+        overall_size,
+        size_kind,
+        align,
+        packed: false,
+        discriminant_size: None,
+        variants,
+    }
+}
+
+fn report_enum_size(
+    name: &str,
+    enum_type: &EnumType,
+    all_typedefs: &[TypeDef],
+    calculator: &LayoutCalculator<'_>,
+) -> TypeSizeInfo {
+    if let Some(niche) = &enum_type.niche {
+        // Niche-filling enums spend no bytes on a tag: every variant shares
+        // the dataful variant's own storage.
+        let dataful_layout = enum_type
+            .variants
+            .iter()
+            .find(|v| v.name == niche.dataful_variant)
+            .and_then(|v| calculator.compute(&v.variant_type, all_typedefs));
+        let (size, align) = dataful_layout.map_or((0, 1), |l| (l.size, l.align));
+
+        let variants = enum_type
+            .variants
+            .iter()
+            .map(|variant| VariantInfo {
+                name: variant.name.clone(),
+                size_kind: SizeKind::Exact,
+                size,
+                fields: vec![FieldInfo {
+                    name: variant.name.clone(),
+                    offset: 0,
+                    size,
+                    align,
+                    padding_before: 0,
+                }],
+            })
+            .collect();
+
+        return TypeSizeInfo {
+            name: name.to_string(),
+            overall_size: size,
+            size_kind: SizeKind::Exact,
+            align,
+            packed: false,
+            discriminant_size: Some(0),
+            variants,
+        };
+    }
+
+    let tag_type = enum_type
+        .tag_type
+        .clone()
+        .unwrap_or_else(|| infer_minimal_enum_tag_type(enum_type));
+    let tag_size = primitive_size(&PrimitiveType::Integral(tag_type.clone()));
+    let tag_layout = calculator
+        .compute(&TypeKind::Primitive(PrimitiveType::Integral(tag_type)), &[])
+        .unwrap_or(Layout {
+            size: tag_size,
+            align: tag_size,
+        });
+
+    let mut variants = Vec::new();
+    let mut payload_size = 0u64;
+    let mut payload_align = 1u64;
+    let mut size_kind = SizeKind::Exact;
+
+    for variant in &enum_type.variants {
+        match calculator.compute(&variant.variant_type, all_typedefs) {
+            Some(layout) => {
+                payload_size = payload_size.max(layout.size);
+                payload_align = payload_align.max(layout.align);
+                variants.push(VariantInfo {
+                    name: variant.name.clone(),
+                    size_kind: SizeKind::Exact,
+                    size: layout.size,
+                    fields: vec![FieldInfo {
+                        name: variant.name.clone(),
+                        offset: 0,
+                        size: layout.size,
+                        align: layout.align,
+                        padding_before: 0,
+                    }],
+                });
+            }
+            None => {
+                size_kind = SizeKind::Min;
+                variants.push(VariantInfo {
+                    name: variant.name.clone(),
+                    size_kind: SizeKind::Min,
+                    size: 0,
+                    fields: Vec::new(),
+                });
+            }
+        }
+    }
+
+    let mut align = tag_layout.align.max(payload_align);
+    if enum_type.container_attributes.aligned > 0 {
+        align = enum_type.container_attributes.aligned;
+    }
+    let payload_offset = align_up(tag_layout.size, payload_align);
+    let overall_size = align_up(payload_offset + payload_size, align);
+
+    TypeSizeInfo {
+        name: name.to_string(),
+        overall_size,
+        size_kind,
+        align,
+        packed: false,
+        discriminant_size: Some(tag_layout.size),
+        variants,
+    }
+}
+
+/// A size-discriminated union's variants have no computed layout at all --
+/// each variant's size is the user-declared `expected_size` that drives the
+/// discrimination, not something `LayoutCalculator` derives. Its overall size
+/// is always a `Min` report, since which variant is actually present (and
+/// thus the real byte count) is a runtime fact this static report can't know.
+fn report_size_discriminated_union_size(
+    name: &str,
+    size_disc_union: &SizeDiscriminatedUnionType,
+) -> TypeSizeInfo {
+    let variants: Vec<VariantInfo> = size_disc_union
+        .variants
+        .iter()
+        .map(|variant| VariantInfo {
+            name: variant.name.clone(),
+            size_kind: SizeKind::Exact,
+            size: variant.expected_size,
+            fields: vec![FieldInfo {
+                name: variant.name.clone(),
+                offset: 0,
+                size: variant.expected_size,
+                align: 1,
+                padding_before: 0,
+            }],
+        })
+        .collect();
+    let overall_size = variants.iter().map(|v| v.size).max().unwrap_or(0);
+
+    TypeSizeInfo {
+        name: name.to_string(),
+        overall_size,
+        size_kind: SizeKind::Min,
+        align: 1,
+        packed: false,
+        discriminant_size: None,
+        variants,
+    }
+}
+
+fn recursive_groups_from(condensation: &Condensation) -> Vec<RecursiveGroup> {
+    condensation
+        .components
+        .iter()
+        .filter(|component| component.is_recursive)
+        .map(|component| RecursiveGroup {
+            members: component.members.clone(),
+        })
+        .collect()
+}
+
+/// Stable content hash of a type's structure, for detecting whether a typedef
+/// actually changed between calls to `DependencyAnalyzer::update`.
+/// `TypeKind` doesn't derive `Hash` (it's a deeply nested enum shared with
+/// serialization code), so this hashes its canonical JSON encoding instead --
+/// it already derives `Serialize`, and two equal `TypeKind`s always encode
+/// identically.
+fn type_content_hash(kind: &TypeKind) -> u64 {
+    let encoded = serde_json::to_vec(kind).expect("TypeKind always serializes");
+    let mut hasher = DefaultHasher::new();
+    encoded.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Build a `to -> [from, ...]` map from the graph's edges, the reverse of
+/// `adjacency_list`. Walking this from a dirty node finds every type whose
+/// analysis could be affected by that node changing.
+fn build_reverse_adjacency(graph: &DependencyGraph) -> HashMap<String, Vec<String>> {
+    let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
+    for dep in &graph.edges {
+        reverse
+            .entry(dep.to.clone())
+            .or_insert_with(Vec::new)
+            .push(dep.from.clone());
+    }
+    reverse
+}
+
+/// BFS over `reverse_adjacency` starting from `dirty`, returning `dirty` plus
+/// everything transitively downstream of it (i.e. everything that depends on
+/// a dirty type, directly or indirectly).
+fn affected_closure(
+    dirty: &HashSet<String>,
+    reverse_adjacency: &HashMap<String, Vec<String>>,
+) -> HashSet<String> {
+    let mut affected: HashSet<String> = dirty.clone();
+    let mut queue: VecDeque<String> = dirty.iter().cloned().collect();
+
+    while let Some(node) = queue.pop_front() {
+        if let Some(dependents) = reverse_adjacency.get(&node) {
+            for dependent in dependents {
+                if affected.insert(dependent.clone()) {
+                    queue.push_back(dependent.clone());
+                }
+            }
+        }
+    }
+
+    affected
+}
+
+/// One lexical scope frame, pushed while the analyzer is inside a
+/// struct/union/enum/size-discriminated-union body: the sibling field or
+/// variant names visible there for a `FieldRef` to resolve against without
+/// an explicit type qualifier. Frames nest as the analyzer descends into
+/// inline nested types, and are searched innermost-first.
+struct ScopeFrame {
+    /// Name of the type that owns these fields (the `current_type_name` at
+    /// the point the frame was pushed), used to build an absolute
+    /// `OwnerType::field` reference for the dependency edge.
+    owner_type: String,
+    /// Field/variant names visible at this level.
+    fields: Vec<String>,
+}
+
+/// One segment of a flattened `FieldRef` path: either a name to look up, or
+/// an explicit `..` qualifier asking to skip the innermost scope frame and
+/// resolve against an enclosing one instead.
+enum PathOp {
+    Up,
+    Name(String),
+}
+
+pub struct DependencyAnalyzer {
+    graph: DependencyGraph,
+    current_type_name: Option<String>,
+    field_path: Vec<String>,
+    scope_stack: Vec<ScopeFrame>,
+    /* Incremental-update bookkeeping for `update`: the last full typedef and
+    content hash seen for each type name, so a later call can tell exactly
+    which types changed without re-diffing the whole schema */
+    known_typedefs: HashMap<String, TypeDef>,
+    type_hashes: HashMap<String, u64>,
+}
+
+impl DependencyAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            graph: DependencyGraph::new(),
+            current_type_name: None,
+            field_path: Vec::new(),
+            scope_stack: Vec::new(),
+            known_typedefs: HashMap::new(),
+            type_hashes: HashMap::new(),
+        }
+    }
+
+    pub fn analyze_typedef(&mut self, typedef: &TypeDef) -> DependencyAnalysis {
+        self.current_type_name = Some(typedef.name.clone());
+        self.graph.add_node(typedef.name.clone());
+
+        self.analyze_type_kind(&typedef.kind);
+
+        let cycles = self.graph.detect_cycles();
+        let topological_order = self.graph.topological_sort();
+        let condensation = self.graph.condense();
+        let recursive_groups = recursive_groups_from(&condensation);
+        let layout_violations = self.graph.layout_violations.clone();
+        let validation_errors = self.graph.validation_errors.clone();
+        let layout_sanity_errors = self.graph.layout_sanity_errors.clone();
+
+        DependencyAnalysis {
+            graph: std::mem::replace(&mut self.graph, DependencyGraph::new()),
+            cycles,
+            topological_order,
+            condensation,
+            recursive_groups,
+            layout_violations,
+            validation_errors,
+            layout_sanity_errors,
+        }
+    }
+
+    pub fn analyze_multiple_typedefs(&mut self, typedefs: &[TypeDef]) -> DependencyAnalysis {
+        // First pass: add all type names as nodes
+        for typedef in typedefs {
+            self.graph.add_node(typedef.name.clone());
+        }
+
+        // Second pass: analyze dependencies
+        for typedef in typedefs {
+            self.current_type_name = Some(typedef.name.clone());
+            self.analyze_type_kind(&typedef.kind);
+        }
+
+        // Third pass: validate basic structure (no duplicates, etc.)
+        self.validate_basic_structure(typedefs);
+
+        // Fourth pass: validate layout constraints after building dependencies
+        self.validate_layout_constraints(typedefs);
+
+        // Fifth pass: assert post-layout invariants now that offsets/sizes
+        // can be computed for every type
+        self.validate_layout_sanity(typedefs);
+
+        let cycles = self.graph.detect_cycles();
+        let topological_order = self.graph.topological_sort();
+        let condensation = self.graph.condense();
+        let recursive_groups = recursive_groups_from(&condensation);
+        let layout_violations = self.graph.layout_violations.clone();
+        let validation_errors = self.graph.validation_errors.clone();
+        let layout_sanity_errors = self.graph.layout_sanity_errors.clone();
+
+        DependencyAnalysis {
+            graph: std::mem::replace(&mut self.graph, DependencyGraph::new()),
+            cycles,
+            topological_order,
+            condensation,
+            recursive_groups,
+            layout_violations,
+            validation_errors,
+            layout_sanity_errors,
+        }
+    }
+
+    /// Incremental counterpart to `analyze_multiple_typedefs`: only
+    /// reprocesses the types in `changed` whose content actually differs from
+    /// what was last seen, plus everything transitively downstream of them.
+    /// Unlike the one-shot `analyze_*` methods, the analyzer's graph is kept
+    /// resident across calls rather than being reset, so call this
+    /// repeatedly on the same `DependencyAnalyzer` instance -- pass the full
+    /// initial schema as `changed` on the first call.
+    ///
+    /// The returned `DependencyAnalysis` is always identical to what a fresh
+    /// `DependencyAnalyzer::new().analyze_multiple_typedefs(...)` over the
+    /// same cumulative schema would produce; see
+    /// `debug_assert_matches_full_rebuild` for the `verify-incremental`
+    /// feature flag that checks this at runtime.
+    pub fn update(&mut self, changed: &[TypeDef], removed: &[String]) -> DependencyAnalysis {
+        for name in removed {
+            self.known_typedefs.remove(name);
+            self.type_hashes.remove(name);
+        }
+        for typedef in changed {
+            self.known_typedefs.insert(typedef.name.clone(), typedef.clone());
+        }
+
+        let mut dirty: HashSet<String> = removed.iter().cloned().collect();
+        for typedef in changed {
+            let hash = type_content_hash(&typedef.kind);
+            let unchanged = self.type_hashes.get(&typedef.name) == Some(&hash);
+            self.type_hashes.insert(typedef.name.clone(), hash);
+            if !unchanged {
+                dirty.insert(typedef.name.clone());
+            }
+        }
+
+        /* Everything downstream of a dirty type needs re-validating too (a
+        layout check can depend on another type's shape), even though its own
+        outgoing edges haven't changed */
+        let reverse_adjacency = build_reverse_adjacency(&self.graph);
+        let affected = affected_closure(&dirty, &reverse_adjacency);
+
+        /* Drop the affected types' own outgoing state -- it's about to be
+        recomputed from scratch -- while leaving every untouched type's nodes
+        and edges exactly as they were */
+        for name in &affected {
+            self.graph.nodes.remove(name);
+            self.graph.adjacency_list.remove(name);
+            self.graph.edges.retain(|dep| &dep.from != name);
+            self.graph
+                .edge_multiplicity
+                .retain(|(from, _), _| from != name);
+            self.graph.layout_dependencies.retain(|dep| &dep.from_type != name);
+            self.graph.type_fields.remove(name);
+        }
+
+        let all_typedefs: Vec<TypeDef> = self.known_typedefs.values().cloned().collect();
+        let to_check: Vec<TypeDef> = all_typedefs
+            .iter()
+            .filter(|typedef| affected.contains(&typedef.name))
+            .cloned()
+            .collect();
+
+        for typedef in &to_check {
+            self.graph.add_node(typedef.name.clone());
+        }
+        for typedef in &to_check {
+            // Drop this type's previous UnresolvedFieldReference entries --
+            // unlike the one-shot `analyze_*` methods, `update` keeps
+            // `self.graph` resident across calls, so stale errors from an
+            // earlier revision of this type would otherwise survive
+            // alongside freshly re-derived ones.
+            self.graph.validation_errors.retain(|e| {
+                !(e.error_type == "UnresolvedFieldReference" && e.violating_type == typedef.name)
+            });
+            self.current_type_name = Some(typedef.name.clone());
+            self.analyze_type_kind(&typedef.kind);
+        }
+
+        self.validate_basic_structure_for(&to_check, &all_typedefs);
+        self.validate_layout_constraints_for(&to_check, &all_typedefs);
+        self.validate_layout_sanity_for(&to_check, &all_typedefs);
+
+        let cycles = self.graph.detect_cycles();
+        let topological_order = self.graph.topological_sort();
+        let condensation = self.graph.condense();
+        let recursive_groups = recursive_groups_from(&condensation);
+        let layout_violations = self.graph.layout_violations.clone();
+        let validation_errors = self.graph.validation_errors.clone();
+        let layout_sanity_errors = self.graph.layout_sanity_errors.clone();
+
+        let analysis = DependencyAnalysis {
+            graph: self.graph.clone(),
+            cycles,
+            topological_order,
+            condensation,
+            recursive_groups,
+            layout_violations,
+            validation_errors,
+            layout_sanity_errors,
+        };
+
+        #[cfg(feature = "verify-incremental")]
+        self.debug_assert_matches_full_rebuild(&analysis, &all_typedefs);
+
+        analysis
+    }
+
+    /// Rebuilds the whole schema from scratch with a throwaway analyzer and
+    /// asserts it agrees with the incremental result, gated behind a feature
+    /// flag since it defeats the entire point of `update` (re-running a full
+    /// analysis every call) and is only meant for exercising `update` under
+    /// test.
+    #[cfg(feature = "verify-incremental")]
+    fn debug_assert_matches_full_rebuild(
+        &self,
+        incremental: &DependencyAnalysis,
+        all_typedefs: &[TypeDef],
+    ) {
+        let full = DependencyAnalyzer::new().analyze_multiple_typedefs(all_typedefs);
+
+        let sorted_nodes = |graph: &DependencyGraph| {
+            let mut nodes: Vec<&String> = graph.nodes.iter().collect();
+            nodes.sort();
+            nodes
+        };
+
+        debug_assert_eq!(
+            sorted_nodes(&incremental.graph),
+            sorted_nodes(&full.graph),
+            "incremental update produced a different node set than a full rebuild"
+        );
+        debug_assert_eq!(
+            incremental.topological_order.is_some(),
+            full.topological_order.is_some(),
+            "incremental update disagrees with full rebuild on whether the graph is acyclic"
+        );
+        debug_assert_eq!(
+            incremental.recursive_groups.len(),
+            full.recursive_groups.len(),
+            "incremental update disagrees with full rebuild on recursive groups"
+        );
+        debug_assert_eq!(
+            incremental.layout_violations.len(),
+            full.layout_violations.len(),
+            "incremental update produced different layout violations than a full rebuild"
+        );
+        debug_assert_eq!(
+            incremental.validation_errors.len(),
+            full.validation_errors.len(),
+            "incremental update produced different validation errors than a full rebuild"
+        );
+        debug_assert_eq!(
+            incremental.layout_sanity_errors.len(),
+            full.layout_sanity_errors.len(),
+            "incremental update produced different layout sanity errors than a full rebuild"
+        );
+    }
+
+    fn analyze_type_kind(&mut self, type_kind: &TypeKind) {
+        match type_kind {
+            TypeKind::Struct(struct_type) => self.analyze_struct(struct_type),
+            TypeKind::Union(union_type) => self.analyze_union(union_type),
+            TypeKind::Enum(enum_type) => self.analyze_enum(enum_type),
+            TypeKind::Array(array_type) => self.analyze_array(array_type),
+            TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
+                self.analyze_size_discriminated_union(size_disc_union)
+            }
+            TypeKind::TypeRef(type_ref) => self.analyze_type_ref(type_ref),
+            TypeKind::Primitive(_) => {} // Primitives have no dependencies
+        }
+    }
+
+    fn analyze_struct(&mut self, struct_type: &StructType) {
+        self.push_scope(struct_type.fields.iter().map(|f| f.name.clone()).collect());
+
+        for field in &struct_type.fields {
+            self.field_path.push(field.name.clone());
+            self.analyze_type_kind(&field.field_type);
+            self.field_path.pop();
+        }
+
+        self.scope_stack.pop();
+    }
+
+    fn analyze_union(&mut self, union_type: &UnionType) {
+        self.push_scope(union_type.variants.iter().map(|v| v.name.clone()).collect());
+
+        for variant in &union_type.variants {
+            self.field_path.push(variant.name.clone());
+            self.analyze_type_kind(&variant.variant_type);
+            self.field_path.pop();
+        }
+
+        self.scope_stack.pop();
+    }
+
+    fn analyze_size_discriminated_union(&mut self, size_disc_union: &SizeDiscriminatedUnionType) {
+        self.push_scope(
+            size_disc_union
+                .variants
+                .iter()
+                .map(|v| v.name.clone())
+                .collect(),
+        );
+
+        for variant in &size_disc_union.variants {
+            self.field_path.push(variant.name.clone());
+            self.analyze_type_kind(&variant.variant_type);
+            self.field_path.pop();
+        }
+
+        self.scope_stack.pop();
+    }
+
+    fn analyze_enum(&mut self, enum_type: &EnumType) {
+        self.push_scope(enum_type.variants.iter().map(|v| v.name.clone()).collect());
+
+        // Analyze tag expression for field references
+        self.analyze_expression(&enum_type.tag_ref, DependencyKind::TagExpression);
+
+        for variant in &enum_type.variants {
+            self.field_path.push(variant.name.clone());
+            self.analyze_type_kind(&variant.variant_type);
+            self.field_path.pop();
+        }
+
+        self.scope_stack.pop();
+    }
+
+    /// Pushes a new innermost scope frame naming the fields/variants visible
+    /// at the current nesting level, owned by whichever type is currently
+    /// being analyzed (the enclosing typedef, even for an inline nested
+    /// struct/union/enum).
+    fn push_scope(&mut self, fields: Vec<String>) {
+        let owner_type = self.current_type_name.clone().unwrap_or_default();
+
+        // The outermost frame for a type (field_path is still empty -- we
+        // haven't descended into any of *this* type's own fields yet) names
+        // the fields `field_evaluation_order` schedules. An inline nested
+        // struct/union/enum pushes its own frame deeper in, once field_path
+        // is non-empty, and shouldn't overwrite this.
+        if self.field_path.is_empty() {
+            self.graph.type_fields.insert(owner_type.clone(), fields.clone());
+        }
+
+        self.scope_stack.push(ScopeFrame { owner_type, fields });
+    }
+
+    fn analyze_array(&mut self, array_type: &ArrayType) {
+        // Analyze size expression for field references
+        self.analyze_expression(&array_type.size, DependencyKind::SizeExpression);
+
+        // Analyze element type
+        self.analyze_type_kind(&array_type.element_type);
+    }
+
+    fn analyze_type_ref(&mut self, type_ref: &TypeRefType) {
+        if let Some(current_type) = &self.current_type_name {
+            let context = if self.field_path.is_empty() {
+                "direct type reference".to_string()
+            } else {
+                format!("field: {}", self.field_path.join("."))
+            };
+
+            self.graph.add_dependency(Dependency {
+                from: current_type.clone(), // Current type depends on referenced type
+                to: type_ref.name.clone(),  // Referenced type must come first
+                kind: DependencyKind::TypeReference,
+                context,
+            });
+        }
+    }
+
+    fn analyze_expression(&mut self, expr: &ExprKind, dep_kind: DependencyKind) {
+        match expr {
+            ExprKind::Literal(_) => {} // Literals have no dependencies
+            ExprKind::FieldRef(field_ref) => {
+                self.analyze_field_reference(field_ref, dep_kind);
+            }
+            ExprKind::Sizeof(sizeof_expr) => {
+                // Sizeof creates a type dependency
+                if let Some(current_type) = &self.current_type_name {
+                    let context =
+                        format!("sizeof expression in field: {}", self.field_path.join("."));
+                    self.graph.add_dependency(Dependency {
+                        from: current_type.clone(),
+                        to: sizeof_expr.type_name.clone(),
+                        kind: DependencyKind::TypeReference,
+                        context,
+                    });
+                }
+            }
+            ExprKind::Alignof(alignof_expr) => {
+                // Alignof creates a type dependency
+                if let Some(current_type) = &self.current_type_name {
+                    let context =
+                        format!("alignof expression in field: {}", self.field_path.join("."));
+                    self.graph.add_dependency(Dependency {
+                        from: current_type.clone(),
+                        to: alignof_expr.type_name.clone(),
+                        kind: DependencyKind::TypeReference,
+                        context,
+                    });
+                }
+            }
+
+            // Binary operations - recursively analyze operands
+            ExprKind::Add(expr) => {
+                self.analyze_expression(&expr.left, dep_kind.clone());
+                self.analyze_expression(&expr.right, dep_kind);
+            }
+            ExprKind::Sub(expr) => {
                 self.analyze_expression(&expr.left, dep_kind.clone());
                 self.analyze_expression(&expr.right, dep_kind);
             }
@@ -537,45 +1914,182 @@ impl DependencyAnalyzer {
             ExprKind::Not(expr) => {
                 self.analyze_expression(&expr.operand, dep_kind);
             }
-            ExprKind::Popcount(expr) => {
-                self.analyze_expression(&expr.operand, dep_kind);
+            ExprKind::Popcount(expr) => {
+                self.analyze_expression(&expr.operand, dep_kind);
+            }
+        }
+    }
+
+    fn analyze_field_reference(&mut self, field_ref: &FieldRefExpr, dep_kind: DependencyKind) {
+        let Some(current_type) = self.current_type_name.clone() else {
+            return;
+        };
+
+        let context = format!(
+            "field reference '{}' in field: {}",
+            field_ref.path.join("."),
+            self.field_path.join(".")
+        );
+
+        match self.resolve_field_reference(&field_ref.path) {
+            Some((owner_type, field_name)) => {
+                self.record_layout_dependency(&current_type, &owner_type, &field_name, &dep_kind, &context);
+
+                self.graph.add_dependency(Dependency {
+                    from: current_type,
+                    to: format!("{}::{}", owner_type, field_name),
+                    kind: dep_kind,
+                    context,
+                });
+            }
+            None => {
+                let candidate_scopes: Vec<String> = self
+                    .scope_stack
+                    .iter()
+                    .rev()
+                    .map(|frame| format!("{}[{}]", frame.owner_type, frame.fields.join(", ")))
+                    .collect();
+                let candidates = if candidate_scopes.is_empty() {
+                    "no enclosing scopes".to_string()
+                } else {
+                    candidate_scopes.join(" -> ")
+                };
+
+                self.graph.add_validation_error(ValidationError {
+                    error_type: "UnresolvedFieldReference".to_string(),
+                    violating_type: current_type,
+                    duplicate_name: field_ref.path.join("."),
+                    reason: format!(
+                        "field reference '{}' does not resolve to any field in scope (searched innermost to outermost: {})",
+                        field_ref.path.join("."),
+                        candidates
+                    ),
+                });
+            }
+        }
+    }
+
+    /// Feeds `DependencyGraph::field_evaluation_order`'s field-granular graph:
+    /// a `SizeExpression`/`TagExpression` reference that resolves to a field
+    /// of the *same* type records a `LayoutDependency` between the two
+    /// top-level fields involved (reduced from `self.field_path`/`field_name`,
+    /// which may point deeper into an inline nested type than the schedule
+    /// cares about). Cross-type references and type-level expressions (no
+    /// enclosing field, i.e. `field_path` is empty) aren't field-schedule
+    /// edges and are skipped.
+    fn record_layout_dependency(
+        &mut self,
+        current_type: &str,
+        owner_type: &str,
+        field_name: &str,
+        dep_kind: &DependencyKind,
+        context: &str,
+    ) {
+        if owner_type != current_type {
+            return;
+        }
+        let kind = match dep_kind {
+            DependencyKind::SizeExpression => LayoutDependencyKind::ArraySizeAffecting,
+            DependencyKind::TagExpression => LayoutDependencyKind::VariantSelector,
+            _ => return,
+        };
+        let Some(from_field) = self.field_path.first() else {
+            return;
+        };
+        let to_field = field_name.split('.').next().unwrap_or(field_name);
+        if from_field == to_field {
+            return;
+        }
+
+        self.graph.add_layout_dependency(LayoutDependency {
+            from_type: current_type.to_string(),
+            from_field: Some(from_field.clone()),
+            to_type: owner_type.to_string(),
+            to_field: Some(to_field.to_string()),
+            kind,
+            context: context.to_string(),
+        });
+    }
+
+    /// Resolves a `FieldRef` path to the `(owner_type, field_name)` it names,
+    /// or `None` if no scope binds it. A leading segment matching a known
+    /// type name is an absolute `Type::field` reference; otherwise the path
+    /// is searched against `scope_stack` from innermost frame outward
+    /// (nearest-enclosing binding wins), honoring any leading `..` segments
+    /// as an explicit request to skip that many innermost frames.
+    fn resolve_field_reference(&self, path: &[String]) -> Option<(String, String)> {
+        let ops = Self::flatten_path_ops(path);
+        let (first, rest) = ops.split_first()?;
+
+        if let PathOp::Name(name) = first {
+            if self.graph.nodes.contains(name) && !rest.is_empty() {
+                return Some((name.clone(), Self::join_names(rest)?));
+            }
+        }
+
+        let up_count = ops.iter().take_while(|op| matches!(op, PathOp::Up)).count();
+        let remaining = &ops[up_count..];
+        let PathOp::Name(first_name) = remaining.first()? else {
+            return None;
+        };
+
+        for frame in self.scope_stack.iter().rev().skip(up_count) {
+            if frame.fields.iter().any(|f| f == first_name) {
+                return Some((frame.owner_type.clone(), Self::join_names(remaining)?));
             }
         }
-    }
 
-    fn analyze_field_reference(&mut self, field_ref: &FieldRefExpr, dep_kind: DependencyKind) {
-        if let Some(current_type) = &self.current_type_name {
-            let context = format!(
-                "field reference '{}' in field: {}",
-                field_ref.path.join("."),
-                self.field_path.join(".")
-            );
+        None
+    }
 
-            // Field references create dependencies on the fields they reference
-            let target_field = if field_ref.path.len() == 1 {
-                // Simple field reference within the same type
-                format!("{}::{}", current_type, field_ref.path[0])
-            } else if field_ref.path.len() == 2 {
-                // Field reference to another type: ["TypeName", "field_name"]
-                format!("{}::{}", field_ref.path[0], field_ref.path[1])
-            } else {
-                // Complex nested field path - use the full path as reference
-                format!("{}::{}", field_ref.path[0], field_ref.path[1..].join("."))
-            };
+    /// Flattens a `FieldRefExpr` path into lookup ops. Each path segment may
+    /// itself contain `/`-separated sub-segments and `..` parent qualifiers
+    /// (e.g. `"../hdr/type_slot"`), mirroring `FieldRefExpr::to_c_field_access`.
+    fn flatten_path_ops(path: &[String]) -> Vec<PathOp> {
+        let mut ops = Vec::new();
+        for segment in path {
+            for part in segment.split('/') {
+                if part.is_empty() {
+                    continue;
+                }
+                let trimmed = part.trim_start_matches("..");
+                if trimmed.is_empty() {
+                    ops.push(PathOp::Up);
+                } else {
+                    ops.push(PathOp::Name(trimmed.to_string()));
+                }
+            }
+        }
+        ops
+    }
 
-            self.graph.add_dependency(Dependency {
-                from: current_type.clone(),
-                to: target_field,
-                kind: dep_kind,
-                context,
-            });
+    fn join_names(ops: &[PathOp]) -> Option<String> {
+        let mut parts = Vec::with_capacity(ops.len());
+        for op in ops {
+            match op {
+                PathOp::Name(name) => parts.push(name.clone()),
+                PathOp::Up => return None,
+            }
         }
+        Some(parts.join("."))
     }
 
     fn validate_basic_structure(&mut self, typedefs: &[TypeDef]) {
-        // Check for duplicate type names
+        self.validate_basic_structure_for(typedefs, typedefs);
+    }
+
+    /// Like `validate_basic_structure`, but only re-runs the internal
+    /// duplicate-name checks for `to_check` -- `all_typedefs` is still
+    /// consulted in full for the duplicate-type-name check, since adding or
+    /// removing any single type can change whether an untouched type's name
+    /// now collides with it.
+    fn validate_basic_structure_for(&mut self, to_check: &[TypeDef], all_typedefs: &[TypeDef]) {
+        // Check for duplicate type names across the whole schema
+        self.graph
+            .validation_errors
+            .retain(|e| e.error_type != "DuplicateTypeName");
         let mut type_names = HashSet::new();
-        for typedef in typedefs {
+        for typedef in all_typedefs {
             if !type_names.insert(typedef.name.clone()) {
                 self.graph.add_validation_error(ValidationError {
                     error_type: "DuplicateTypeName".to_string(),
@@ -586,8 +2100,17 @@ impl DependencyAnalyzer {
             }
         }
 
-        // Check each type for internal duplicate names
-        for typedef in typedefs {
+        // Check each affected type for internal duplicate names; untouched
+        // types keep whatever errors they already had. `UnresolvedFieldReference`
+        // is exempt: it's populated by the dependency-analysis pass, not here,
+        // and is cleared/re-added there when a type is re-analyzed.
+        let to_check_names: HashSet<&str> = to_check.iter().map(|t| t.name.as_str()).collect();
+        self.graph.validation_errors.retain(|e| {
+            e.error_type == "DuplicateTypeName"
+                || e.error_type == "UnresolvedFieldReference"
+                || !to_check_names.contains(e.violating_type.as_str())
+        });
+        for typedef in to_check {
             self.validate_type_internal_duplicates(typedef);
         }
     }
@@ -602,6 +2125,7 @@ impl DependencyAnalyzer {
             }
             TypeKind::Enum(enum_type) => {
                 self.validate_enum_variant_duplicates(&typedef.name, enum_type);
+                self.validate_enum_niche_filling(&typedef.name, enum_type);
             }
             TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
                 self.validate_size_discriminated_union_variant_duplicates(
@@ -672,70 +2196,720 @@ impl DependencyAnalyzer {
                     violating_type: type_name.to_string(),
                     duplicate_name: variant.tag_value.to_string(),
                     reason: format!(
-                        "Tag value '{}' is used by multiple variants in enum '{}'",
-                        variant.tag_value, type_name
+                        "Tag value '{}' is used by multiple variants in enum '{}'",
+                        variant.tag_value, type_name
+                    ),
+                });
+            }
+        }
+    }
+
+    /// Validates an enum's `niche` configuration, if present: the niche must
+    /// have room for every data-less variant, and a niche-filled enum emits
+    /// no tag field, so it cannot also carry a meaningful `tag_ref`.
+    fn validate_enum_niche_filling(&mut self, type_name: &str, enum_type: &EnumType) {
+        let Some(niche) = &enum_type.niche else {
+            return;
+        };
+
+        if !enum_type
+            .variants
+            .iter()
+            .any(|v| v.name == niche.dataful_variant)
+        {
+            self.graph.add_validation_error(ValidationError {
+                error_type: "NicheFillingUnknownVariant".to_string(),
+                violating_type: type_name.to_string(),
+                duplicate_name: niche.dataful_variant.clone(),
+                reason: format!(
+                    "Enum '{}' declares niche filling with dataful variant '{}', but no variant \
+                     by that name exists",
+                    type_name, niche.dataful_variant
+                ),
+            });
+        }
+
+        let data_less_variants = enum_type.variants.len().saturating_sub(1) as u64;
+        if niche.niche_count < data_less_variants {
+            self.graph.add_validation_error(ValidationError {
+                error_type: "NicheFillingInsufficientNiche".to_string(),
+                violating_type: type_name.to_string(),
+                duplicate_name: niche.niche_count.to_string(),
+                reason: format!(
+                    "Enum '{}' has {} data-less variant(s) but its niche only has room for {} \
+                     sentinel value(s); the niche is too small to distinguish every variant",
+                    type_name, data_less_variants, niche.niche_count
+                ),
+            });
+        }
+
+        if !enum_type.tag_ref.is_constant() {
+            self.graph.add_validation_error(ValidationError {
+                error_type: "NicheFillingConflictingTagRef".to_string(),
+                violating_type: type_name.to_string(),
+                duplicate_name: type_name.to_string(),
+                reason: format!(
+                    "Enum '{}' declares niche filling, which emits no tag field, but also \
+                     declares a non-constant tag_ref; a niche-filled enum cannot also derive \
+                     its variant from an explicit tag expression",
+                    type_name
+                ),
+            });
+        }
+    }
+
+    /// Validates the placement invariant a niche-filled enum depends on: the
+    /// scalar supplying the niche sits at a fixed byte offset within the
+    /// dataful variant, and that same offset range must actually be covered
+    /// by the storage of every variant sharing the union (a data-less variant
+    /// can be smaller than the dataful one, but it can never be so small that
+    /// writing the niche sentinel would run past its own storage). This is
+    /// the layout-level counterpart to `validate_enum_niche_filling`'s
+    /// schema-level checks, and runs once offsets/sizes are available.
+    fn validate_enum_niche_placement(
+        &mut self,
+        type_name: &str,
+        enum_type: &EnumType,
+        all_typedefs: &[TypeDef],
+    ) {
+        let Some(niche) = &enum_type.niche else {
+            return;
+        };
+        let Some(dataful) = enum_type
+            .variants
+            .iter()
+            .find(|v| v.name == niche.dataful_variant)
+        else {
+            return;
+        };
+
+        let Some((niche_offset, niche_scalar_size)) =
+            self.resolve_field_offset(&dataful.variant_type, &niche.niche_field_path, all_typedefs)
+        else {
+            return;
+        };
+
+        for variant in &enum_type.variants {
+            if variant.name == niche.dataful_variant {
+                continue;
+            }
+            let Some(variant_size) = self.constant_type_size(&variant.variant_type, all_typedefs)
+            else {
+                continue;
+            };
+            if niche_offset + niche_scalar_size > variant_size {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "NicheOffsetExceedsVariantStorage".to_string(),
+                    violating_type: type_name.to_string(),
+                    location: variant.name.clone(),
+                    reason: format!(
+                        "Enum '{}' niche field '{}' occupies bytes [{}, {}) of the dataful \
+                         variant '{}', but data-less variant '{}' is only {} byte(s), too small \
+                         to hold the niche sentinel",
+                        type_name,
+                        niche.niche_field_path,
+                        niche_offset,
+                        niche_offset + niche_scalar_size,
+                        niche.dataful_variant,
+                        variant.name,
+                        variant_size
+                    ),
+                });
+            }
+        }
+    }
+
+    /// Resolves a dot-separated field path (as used by `NicheFilling::niche_field_path`)
+    /// against a (possibly `TypeRef`-indirected) struct type, returning the
+    /// leaf field's `(offset, size)` within its immediate container. Returns
+    /// `None` if any segment doesn't resolve to a struct field -- callers
+    /// treat an unresolvable path as "nothing to check" rather than an error,
+    /// since path resolution is best-effort without a full layout pass.
+    fn resolve_field_offset(
+        &self,
+        type_kind: &TypeKind,
+        field_path: &str,
+        all_typedefs: &[TypeDef],
+    ) -> Option<(u64, u64)> {
+        let resolved = match type_kind {
+            TypeKind::TypeRef(_) => {
+                &self.find_typedef_for_type(type_kind, all_typedefs)?.kind
+            }
+            other => other,
+        };
+        let TypeKind::Struct(struct_type) = resolved else {
+            return None;
+        };
+
+        let (field_name, rest) = match field_path.split_once('.') {
+            Some((head, tail)) => (head, Some(tail)),
+            None => (field_path, None),
+        };
+
+        let packed = struct_type.container_attributes.packed;
+        let mut offset = 0u64;
+        for field in &struct_type.fields {
+            let field_size = self.constant_type_size(&field.field_type, all_typedefs)?;
+            let field_alignment = self.type_alignment(&field.field_type, all_typedefs);
+            let field_offset = if packed { offset } else { align_up(offset, field_alignment) };
+
+            if field.name == field_name {
+                return match rest {
+                    Some(nested_path) => {
+                        let (nested_offset, nested_size) =
+                            self.resolve_field_offset(&field.field_type, nested_path, all_typedefs)?;
+                        Some((field_offset + nested_offset, nested_size))
+                    }
+                    None => Some((field_offset, field_size)),
+                };
+            }
+
+            offset = field_offset + field_size;
+        }
+
+        None
+    }
+
+    fn validate_size_discriminated_union_variant_duplicates(
+        &mut self,
+        type_name: &str,
+        size_disc_union: &SizeDiscriminatedUnionType,
+    ) {
+        let mut variant_names = HashSet::new();
+
+        for variant in &size_disc_union.variants {
+            // Check for duplicate variant names
+            if !variant_names.insert(variant.name.clone()) {
+                self.graph.add_validation_error(ValidationError {
+                    error_type: "DuplicateVariantName".to_string(),
+                    violating_type: type_name.to_string(),
+                    duplicate_name: variant.name.clone(),
+                    reason: format!(
+                        "Variant name '{}' appears multiple times in size-discriminated union '{}'",
+                        variant.name, type_name
+                    ),
+                });
+            }
+        }
+    }
+
+    fn validate_layout_constraints(&mut self, typedefs: &[TypeDef]) {
+        self.validate_layout_constraints_for(typedefs, typedefs);
+    }
+
+    /// Like `validate_layout_constraints`, but only re-runs the layout check
+    /// for `to_check`; layout violations belonging to untouched types are
+    /// left as they were. `all_typedefs` is still passed through so each
+    /// re-checked type can resolve its dependencies against the full schema.
+    fn validate_layout_constraints_for(&mut self, to_check: &[TypeDef], all_typedefs: &[TypeDef]) {
+        let to_check_names: HashSet<&str> = to_check.iter().map(|t| t.name.as_str()).collect();
+        self.graph
+            .layout_violations
+            .retain(|v| !to_check_names.contains(v.violating_type.as_str()));
+
+        // Condense the whole graph into its strongly-connected components
+        // once, up front, rather than re-deriving reachability with a fresh
+        // BFS for every field reference below -- see `layout_cycle_chains`.
+        let cycle_chains = self.layout_cycle_chains();
+
+        for typedef in to_check {
+            self.validate_type_layout_constraints(typedef, all_typedefs, &cycle_chains);
+        }
+    }
+
+    fn validate_type_layout_constraints(
+        &mut self,
+        typedef: &TypeDef,
+        all_typedefs: &[TypeDef],
+        cycle_chains: &HashMap<String, Vec<String>>,
+    ) {
+        match &typedef.kind {
+            TypeKind::Enum(enum_type) => {
+                self.validate_enum_tag_constraints(
+                    &typedef.name,
+                    enum_type,
+                    all_typedefs,
+                    cycle_chains,
+                );
+                self.validate_enum_tag_type_sizing(&typedef.name, enum_type);
+                if let Some(violation) = self.check_enum_non_terminal_variant_variable_size(
+                    &typedef.name,
+                    enum_type,
+                    all_typedefs,
+                ) {
+                    self.graph.add_layout_violation(violation);
+                }
+            }
+            TypeKind::Array(array_type) => {
+                self.validate_array_size_constraints(
+                    &typedef.name,
+                    array_type,
+                    all_typedefs,
+                    cycle_chains,
+                );
+            }
+            TypeKind::Struct(struct_type) => {
+                self.validate_struct_field_constraints(&typedef.name, struct_type, all_typedefs);
+            }
+            TypeKind::Union(union_type) => {
+                if let Some(violation) = self.check_union_variant_variable_size(
+                    &typedef.name,
+                    union_type,
+                    all_typedefs,
+                ) {
+                    self.graph.add_layout_violation(violation);
+                }
+            }
+            TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
+                self.validate_size_discriminated_union_constraints(
+                    &typedef.name,
+                    size_disc_union,
+                    all_typedefs,
+                );
+            }
+            _ => {} // Other types don't have layout-affecting expressions
+        }
+    }
+
+    /// Condenses the dependency graph into its strongly-connected components
+    /// (via `condense`, Tarjan's algorithm) and, for every component with more
+    /// than one member -- or a single type with a self-edge -- walks its
+    /// internal edges once to find a concrete cycle through its members. The
+    /// result maps each type name that participates in a layout cycle to that
+    /// cycle's node chain, so `cycle_chain_between` below is an O(1) lookup
+    /// instead of a fresh BFS per field reference.
+    fn layout_cycle_chains(&self) -> HashMap<String, Vec<String>> {
+        let condensation = self.graph.condense();
+        let mut chains = HashMap::new();
+
+        for component in &condensation.components {
+            if !component.is_recursive {
+                continue;
+            }
+            let chain = self.order_component_as_cycle(component);
+            for member in &component.members {
+                chains.insert(member.clone(), chain.clone());
+            }
+        }
+
+        chains
+    }
+
+    /// Walks forward edges (`adjacency_list`) starting from an arbitrary
+    /// member of a recursive component, staying within the component, until
+    /// it returns to the start -- producing one concrete cycle rather than
+    /// just the component's unordered set. The walk follows the first
+    /// in-component neighbor at each step, so the chain need not pass
+    /// through every member of the component.
+    fn order_component_as_cycle(&self, component: &SccComponent) -> Vec<String> {
+        let members: HashSet<&String> = component.members.iter().collect();
+        let start = component.members[0].clone();
+        let mut chain = vec![start.clone()];
+        let mut visited: HashSet<String> = HashSet::new();
+        visited.insert(start.clone());
+        let mut current = start.clone();
+
+        loop {
+            let next = self
+                .graph
+                .adjacency_list
+                .get(&current)
+                .and_then(|neighbors| neighbors.iter().find(|n| members.contains(*n)));
+
+            match next {
+                Some(next) if *next == start => {
+                    chain.push(start.clone());
+                    break;
+                }
+                Some(next) if !visited.contains(next) => {
+                    visited.insert(next.clone());
+                    chain.push(next.clone());
+                    current = next.clone();
+                }
+                _ => {
+                    // The walk reached an already-visited member other than
+                    // `start` (or dead-ended); close the chain back at
+                    // `start`, so this last hop need not be a direct edge.
+                    chain.push(start.clone());
+                    break;
+                }
+            }
+        }
+
+        chain
+    }
+
+    /// O(1) replacement for the old per-reference BFS: `from_type` and
+    /// `to_type` lie on a layout cycle together exactly when they belong to
+    /// the same recursive component, i.e. the same precomputed chain.
+    fn cycle_chain_between(
+        &self,
+        cycle_chains: &HashMap<String, Vec<String>>,
+        from_type: &str,
+        to_type: &str,
+    ) -> Option<Vec<String>> {
+        let chain = cycle_chains.get(from_type)?;
+        if cycle_chains.get(to_type) == Some(chain) {
+            Some(chain.clone())
+        } else {
+            None
+        }
+    }
+
+    fn validate_layout_sanity(&mut self, typedefs: &[TypeDef]) {
+        self.validate_layout_sanity_for(typedefs, typedefs);
+    }
+
+    /// Like `validate_layout_constraints_for`, but asserts invariants on the
+    /// *computed* layout (offsets, sizes, tag ranges) rather than on the
+    /// dependency graph -- see `LayoutSanityError`.
+    fn validate_layout_sanity_for(&mut self, to_check: &[TypeDef], all_typedefs: &[TypeDef]) {
+        let to_check_names: HashSet<&str> = to_check.iter().map(|t| t.name.as_str()).collect();
+        self.graph
+            .layout_sanity_errors
+            .retain(|e| !to_check_names.contains(e.violating_type.as_str()));
+        for typedef in to_check {
+            self.check_type_layout_sanity(typedef, all_typedefs);
+        }
+    }
+
+    fn check_type_layout_sanity(&mut self, typedef: &TypeDef, all_typedefs: &[TypeDef]) {
+        match &typedef.kind {
+            TypeKind::Struct(struct_type) => {
+                self.check_struct_layout_sanity(&typedef.name, struct_type, all_typedefs);
+            }
+            TypeKind::Union(union_type) => {
+                self.check_union_layout_sanity(&typedef.name, union_type, all_typedefs);
+            }
+            TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
+                self.check_size_discriminated_union_layout_sanity(
+                    &typedef.name,
+                    size_disc_union,
+                    all_typedefs,
+                );
+            }
+            TypeKind::Enum(enum_type) => {
+                self.check_enum_layout_sanity(&typedef.name, enum_type);
+                self.validate_enum_niche_placement(&typedef.name, enum_type, all_typedefs);
+            }
+            _ => {} // Primitives and type refs have no layout of their own to sanity-check
+        }
+    }
+
+    /// Walks a struct's fields in declared order, recomputing each field's
+    /// offset the same way layout generation does, and asserts the result is
+    /// internally consistent: every offset is a multiple of that field's
+    /// alignment, no field starts before the previous one ends, and the
+    /// struct's total size is at least the end of the last field and a
+    /// multiple of the struct's own alignment. Bails out (rather than
+    /// guessing) the moment a field's size can't be determined statically --
+    /// nothing past that point can be checked either.
+    fn check_struct_layout_sanity(
+        &mut self,
+        struct_name: &str,
+        struct_type: &StructType,
+        all_typedefs: &[TypeDef],
+    ) {
+        let packed = struct_type.container_attributes.packed;
+        let mut offset = 0u64;
+        let mut alignment = 1u64;
+
+        for field in &struct_type.fields {
+            let Some(field_size) = self.constant_type_size(&field.field_type, all_typedefs) else {
+                return;
+            };
+            let field_alignment = self.type_alignment(&field.field_type, all_typedefs);
+            let field_offset = if packed { offset } else { align_up(offset, field_alignment) };
+
+            if !packed && field_offset % field_alignment != 0 {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "MisalignedField".to_string(),
+                    violating_type: struct_name.to_string(),
+                    location: field.name.clone(),
+                    reason: format!(
+                        "Field '{}' of struct '{}' is at offset {}, which is not a multiple of \
+                         its alignment {}",
+                        field.name, struct_name, field_offset, field_alignment
+                    ),
+                });
+            }
+            if field_offset < offset {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "OverlappingField".to_string(),
+                    violating_type: struct_name.to_string(),
+                    location: field.name.clone(),
+                    reason: format!(
+                        "Field '{}' of struct '{}' starts at offset {}, before the end of the \
+                         preceding field at {}",
+                        field.name, struct_name, field_offset, offset
+                    ),
+                });
+            }
+
+            offset = field_offset + field_size;
+            if !packed {
+                alignment = alignment.max(field_alignment);
+            }
+        }
+
+        if struct_type.container_attributes.aligned > 0 {
+            alignment = struct_type.container_attributes.aligned;
+        }
+
+        if !alignment.is_power_of_two() {
+            self.graph.add_layout_sanity_error(LayoutSanityError {
+                error_type: "AlignmentNotPowerOfTwo".to_string(),
+                violating_type: struct_name.to_string(),
+                location: struct_name.to_string(),
+                reason: format!(
+                    "Struct '{}' has computed alignment {}, which is not a power of two",
+                    struct_name, alignment
+                ),
+            });
+        }
+
+        let total_size = if packed { offset } else { align_up(offset, alignment) };
+
+        if total_size < offset {
+            self.graph.add_layout_sanity_error(LayoutSanityError {
+                error_type: "StructSizeTooSmall".to_string(),
+                violating_type: struct_name.to_string(),
+                location: struct_name.to_string(),
+                reason: format!(
+                    "Struct '{}' has computed size {} which is smaller than the end of its last \
+                     field at {}",
+                    struct_name, total_size, offset
+                ),
+            });
+        }
+        if !packed && total_size % alignment != 0 {
+            self.graph.add_layout_sanity_error(LayoutSanityError {
+                error_type: "StructSizeNotAlignmentMultiple".to_string(),
+                violating_type: struct_name.to_string(),
+                location: struct_name.to_string(),
+                reason: format!(
+                    "Struct '{}' has computed size {} which is not a multiple of its alignment {}",
+                    struct_name, total_size, alignment
+                ),
+            });
+        }
+    }
+
+    /// Unions and size-discriminated unions always place every variant at
+    /// offset 0 by construction; the invariant worth asserting is that no
+    /// variant's statically-known size exceeds the union's own computed size.
+    fn check_union_layout_sanity(
+        &mut self,
+        union_name: &str,
+        union_type: &UnionType,
+        all_typedefs: &[TypeDef],
+    ) {
+        let Some(union_size) = self.constant_type_size(
+            &TypeKind::Union(union_type.clone()),
+            all_typedefs,
+        ) else {
+            return;
+        };
+
+        let alignment = if union_type.container_attributes.aligned > 0 {
+            union_type.container_attributes.aligned
+        } else {
+            union_type
+                .variants
+                .iter()
+                .map(|v| self.type_alignment(&v.variant_type, all_typedefs))
+                .max()
+                .unwrap_or(1)
+        };
+        if !alignment.is_power_of_two() {
+            self.graph.add_layout_sanity_error(LayoutSanityError {
+                error_type: "AlignmentNotPowerOfTwo".to_string(),
+                violating_type: union_name.to_string(),
+                location: union_name.to_string(),
+                reason: format!(
+                    "Union '{}' has computed alignment {}, which is not a power of two",
+                    union_name, alignment
+                ),
+            });
+        }
+
+        for variant in &union_type.variants {
+            let Some(variant_size) = self.constant_type_size(&variant.variant_type, all_typedefs)
+            else {
+                continue;
+            };
+            if variant_size > union_size {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "UnionVariantExceedsUnionSize".to_string(),
+                    violating_type: union_name.to_string(),
+                    location: variant.name.clone(),
+                    reason: format!(
+                        "Variant '{}' of union '{}' has size {} which exceeds the union's \
+                         computed size {}",
+                        variant.name, union_name, variant_size, union_size
                     ),
                 });
             }
         }
     }
 
-    fn validate_size_discriminated_union_variant_duplicates(
+    fn check_size_discriminated_union_layout_sanity(
         &mut self,
-        type_name: &str,
+        union_name: &str,
         size_disc_union: &SizeDiscriminatedUnionType,
+        all_typedefs: &[TypeDef],
     ) {
-        let mut variant_names = HashSet::new();
-
         for variant in &size_disc_union.variants {
-            // Check for duplicate variant names
-            if !variant_names.insert(variant.name.clone()) {
-                self.graph.add_validation_error(ValidationError {
-                    error_type: "DuplicateVariantName".to_string(),
-                    violating_type: type_name.to_string(),
-                    duplicate_name: variant.name.clone(),
+            let Some(variant_size) = self.constant_type_size(&variant.variant_type, all_typedefs)
+            else {
+                continue;
+            };
+            if variant_size > variant.expected_size {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "UnionVariantExceedsUnionSize".to_string(),
+                    violating_type: union_name.to_string(),
+                    location: variant.name.clone(),
                     reason: format!(
-                        "Variant name '{}' appears multiple times in size-discriminated union '{}'",
-                        variant.name, type_name
+                        "Variant '{}' of size-discriminated union '{}' has computed size {} \
+                         which exceeds its declared expected_size {}",
+                        variant.name, union_name, variant_size, variant.expected_size
                     ),
                 });
             }
         }
     }
 
-    fn validate_layout_constraints(&mut self, typedefs: &[TypeDef]) {
-        for typedef in typedefs {
-            self.validate_type_layout_constraints(typedef, typedefs);
+    /// Asserts that every declared `tag_value` fits the enum's tag type (the
+    /// declared `tag_type`, or the inferred minimal type when absent), and
+    /// that a niche's declared `[niche_start, niche_start + niche_count)`
+    /// range also lies within that same tag type's representable range.
+    fn check_enum_layout_sanity(&mut self, enum_name: &str, enum_type: &EnumType) {
+        let effective_tag_type = enum_type
+            .tag_type
+            .clone()
+            .unwrap_or_else(|| self.infer_minimal_enum_tag_type(enum_type));
+        let (min, max) = self.integral_type_range(&effective_tag_type);
+
+        for variant in &enum_type.variants {
+            if (variant.tag_value as i128) > max {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "EnumTagValueOutOfRange".to_string(),
+                    violating_type: enum_name.to_string(),
+                    location: variant.name.clone(),
+                    reason: format!(
+                        "Enum '{}' variant '{}' has tag value {} which does not fit in tag type \
+                         {:?} (representable range [{}, {}])",
+                        enum_name, variant.name, variant.tag_value, effective_tag_type, min, max
+                    ),
+                });
+            }
+        }
+
+        if let Some(niche) = &enum_type.niche {
+            let niche_end = niche.niche_start as i128 + niche.niche_count as i128 - 1;
+            if (niche.niche_start as i128) < min || niche_end > max {
+                self.graph.add_layout_sanity_error(LayoutSanityError {
+                    error_type: "NicheRangeOutOfBounds".to_string(),
+                    violating_type: enum_name.to_string(),
+                    location: niche.niche_field_path.clone(),
+                    reason: format!(
+                        "Enum '{}' niche range [{}, {}] does not lie within tag type {:?}'s \
+                         representable range [{}, {}]",
+                        enum_name, niche.niche_start, niche_end, effective_tag_type, min, max
+                    ),
+                });
+            }
         }
     }
 
-    fn validate_type_layout_constraints(&mut self, typedef: &TypeDef, all_typedefs: &[TypeDef]) {
-        match &typedef.kind {
-            TypeKind::Enum(enum_type) => {
-                self.validate_enum_tag_constraints(&typedef.name, enum_type, all_typedefs);
+    /// Inclusive `(min, max)` representable range of an `IntegralType`, as
+    /// `i128` so the unsigned 64-bit case doesn't overflow the signed
+    /// comparisons done against it.
+    fn integral_type_range(&self, int_type: &IntegralType) -> (i128, i128) {
+        match int_type {
+            IntegralType::U8 => (0, u8::MAX as i128),
+            IntegralType::U16 => (0, u16::MAX as i128),
+            IntegralType::U32 => (0, u32::MAX as i128),
+            IntegralType::U64 => (0, u64::MAX as i128),
+            IntegralType::I8 => (i8::MIN as i128, i8::MAX as i128),
+            IntegralType::I16 => (i16::MIN as i128, i16::MAX as i128),
+            IntegralType::I32 => (i32::MIN as i128, i32::MAX as i128),
+            IntegralType::I64 => (i64::MIN as i128, i64::MAX as i128),
+        }
+    }
+
+    /// Statically-known size of a type, or `None` if it can only be known at
+    /// runtime (e.g. an array whose size expression isn't a literal, or a
+    /// size-discriminated union). Mirrors the offset/alignment rules
+    /// `type_alignment` and the struct/union branches below apply during
+    /// layout, so it stays consistent with what a real layout pass would
+    /// compute.
+    fn constant_type_size(&self, type_kind: &TypeKind, all_typedefs: &[TypeDef]) -> Option<u64> {
+        match type_kind {
+            TypeKind::Primitive(prim) => Some(self.get_primitive_size(prim)),
+            TypeKind::TypeRef(_) => self
+                .find_typedef_for_type(type_kind, all_typedefs)
+                .and_then(|typedef| self.constant_type_size(&typedef.kind, all_typedefs)),
+            TypeKind::Struct(struct_type) => {
+                let mut offset = 0u64;
+                let mut alignment = 1u64;
+                for field in &struct_type.fields {
+                    let field_size = self.constant_type_size(&field.field_type, all_typedefs)?;
+                    let field_alignment = self.type_alignment(&field.field_type, all_typedefs);
+                    if struct_type.container_attributes.packed {
+                        offset += field_size;
+                    } else {
+                        offset = align_up(offset, field_alignment) + field_size;
+                        alignment = alignment.max(field_alignment);
+                    }
+                }
+                if struct_type.container_attributes.aligned > 0 {
+                    alignment = struct_type.container_attributes.aligned;
+                }
+                Some(if struct_type.container_attributes.packed {
+                    offset
+                } else {
+                    align_up(offset, alignment)
+                })
             }
-            TypeKind::Array(array_type) => {
-                self.validate_array_size_constraints(&typedef.name, array_type, all_typedefs);
+            TypeKind::Union(union_type) => {
+                let mut max_size = 0u64;
+                for variant in &union_type.variants {
+                    max_size =
+                        max_size.max(self.constant_type_size(&variant.variant_type, all_typedefs)?);
+                }
+                Some(max_size)
             }
-            TypeKind::Struct(struct_type) => {
-                self.validate_struct_field_constraints(&typedef.name, struct_type, all_typedefs);
+            TypeKind::Enum(enum_type) => {
+                let mut max_size = 0u64;
+                for variant in &enum_type.variants {
+                    max_size =
+                        max_size.max(self.constant_type_size(&variant.variant_type, all_typedefs)?);
+                }
+                Some(max_size)
             }
-            TypeKind::SizeDiscriminatedUnion(size_disc_union) => {
-                self.validate_size_discriminated_union_constraints(
-                    &typedef.name,
-                    size_disc_union,
-                    all_typedefs,
-                );
+            TypeKind::Array(array_type) => {
+                let element_size = self.constant_type_size(&array_type.element_type, all_typedefs)?;
+                let count = match &array_type.size {
+                    ExprKind::Literal(literal) => self.literal_as_u64(literal),
+                    _ => None,
+                }?;
+                Some(element_size * count)
             }
-            _ => {} // Other types don't have layout-affecting expressions
+            TypeKind::SizeDiscriminatedUnion(_) => None,
         }
     }
 
+    fn literal_as_u64(&self, literal: &crate::abi::expr::LiteralExpr) -> Option<u64> {
+        literal_as_u64(literal)
+    }
+
     fn validate_enum_tag_constraints(
         &mut self,
         enum_name: &str,
         enum_type: &EnumType,
         all_typedefs: &[TypeDef],
+        cycle_chains: &HashMap<String, Vec<String>>,
     ) {
         // Collect field references in the tag expression
         let field_refs = self.collect_field_references_from_expr(&enum_type.tag_ref);
@@ -743,18 +2917,67 @@ impl DependencyAnalyzer {
         for field_ref in field_refs {
             // Check if this field reference creates a layout cycle
             if let Some(violation) =
-                self.check_enum_tag_layout_cycle(enum_name, &field_ref, all_typedefs)
+                self.check_enum_tag_layout_cycle(enum_name, &field_ref, all_typedefs, cycle_chains)
             {
                 self.graph.add_layout_violation(violation);
             }
         }
     }
 
+    /// Infers the smallest `IntegralType` that can hold every declared
+    /// `tag_value`, mirroring rustc's `repr_discr`. `tag_value` is a `u64`,
+    /// so the inferred type is always unsigned.
+    fn infer_minimal_enum_tag_type(&self, enum_type: &EnumType) -> IntegralType {
+        infer_minimal_enum_tag_type(enum_type)
+    }
+
+    /// If this enum declares a manual `tag_type`, checks it against the
+    /// smallest `IntegralType` that actually fits the declared tag values and
+    /// emits a diagnostic when the declared type is wider or narrower than
+    /// necessary. Enums that omit `tag_type` are inferred and need no check.
+    fn validate_enum_tag_type_sizing(&mut self, enum_name: &str, enum_type: &EnumType) {
+        let Some(declared) = &enum_type.tag_type else {
+            return;
+        };
+
+        let minimal = self.infer_minimal_enum_tag_type(enum_type);
+        if *declared == minimal {
+            return;
+        }
+
+        let declared_size = self.integral_type_size(declared);
+        let minimal_size = self.integral_type_size(&minimal);
+        let error_type = if declared_size < minimal_size {
+            "EnumTagTypeTooNarrow"
+        } else {
+            "EnumTagTypeWiderThanNecessary"
+        };
+        self.graph.add_validation_error(ValidationError {
+            error_type: error_type.to_string(),
+            violating_type: enum_name.to_string(),
+            duplicate_name: format!("{:?}", declared),
+            reason: format!(
+                "Enum '{}' declares tag_type {:?}, but its tag values fit in {:?}",
+                enum_name, declared, minimal
+            ),
+        });
+    }
+
+    fn integral_type_size(&self, int_type: &IntegralType) -> u64 {
+        match int_type {
+            IntegralType::U8 | IntegralType::I8 => 1,
+            IntegralType::U16 | IntegralType::I16 => 2,
+            IntegralType::U32 | IntegralType::I32 => 4,
+            IntegralType::U64 | IntegralType::I64 => 8,
+        }
+    }
+
     fn validate_array_size_constraints(
         &mut self,
         array_name: &str,
         array_type: &ArrayType,
         all_typedefs: &[TypeDef],
+        cycle_chains: &HashMap<String, Vec<String>>,
     ) {
         // Check if element type has non-constant size
         if let Some(violation) =
@@ -767,7 +2990,7 @@ impl DependencyAnalyzer {
 
         for field_ref in field_refs {
             if let Some(violation) =
-                self.check_array_size_layout_cycle(array_name, &field_ref, all_typedefs)
+                self.check_array_size_layout_cycle(array_name, &field_ref, all_typedefs, cycle_chains)
             {
                 self.graph.add_layout_violation(violation);
             }
@@ -788,6 +3011,25 @@ impl DependencyAnalyzer {
                 self.graph.add_layout_violation(violation);
             }
         }
+
+        // If this struct opts into field reordering, make sure the reordered
+        // form still passes the same forward-reference checks -- a
+        // correctly-computed order should never produce new violations here,
+        // but this is the safety net if it ever does.
+        let (_, reorder_violations) =
+            self.optimize_struct_field_order(struct_name, struct_type, all_typedefs);
+        for violation in reorder_violations {
+            self.graph.add_layout_violation(violation);
+        }
+
+        // Enforce the unsized-tail rule for every struct, not just ones that
+        // happen to contain a size-discriminated union (see
+        // `check_variable_size_field_is_trailing`'s own doc comment).
+        if let Some(violation) =
+            self.check_variable_size_field_is_trailing(struct_name, struct_type, all_typedefs)
+        {
+            self.graph.add_layout_violation(violation);
+        }
     }
 
     fn check_enum_tag_layout_cycle(
@@ -795,6 +3037,7 @@ impl DependencyAnalyzer {
         enum_name: &str,
         field_ref: &str,
         all_typedefs: &[TypeDef],
+        cycle_chains: &HashMap<String, Vec<String>>,
     ) -> Option<LayoutConstraintViolation> {
         // Parse field reference to determine which type and field it refers to
         let (ref_type, ref_field) = self.parse_field_reference(field_ref);
@@ -835,7 +3078,7 @@ impl DependencyAnalyzer {
 
         // Check if the referenced field's offset could be affected by this enum's size
         if let Some(dependency_chain) =
-            self.find_layout_dependency_chain(&ref_type, enum_name, all_typedefs)
+            self.cycle_chain_between(cycle_chains, &ref_type, enum_name)
         {
             return Some(LayoutConstraintViolation {
                 violating_type: enum_name.to_string(),
@@ -857,11 +3100,12 @@ impl DependencyAnalyzer {
         array_name: &str,
         field_ref: &str,
         all_typedefs: &[TypeDef],
+        cycle_chains: &HashMap<String, Vec<String>>,
     ) -> Option<LayoutConstraintViolation> {
         let (ref_type, ref_field) = self.parse_field_reference(field_ref);
 
         if let Some(dependency_chain) =
-            self.find_layout_dependency_chain(&ref_type, array_name, all_typedefs)
+            self.cycle_chain_between(cycle_chains, &ref_type, array_name)
         {
             return Some(LayoutConstraintViolation {
                 violating_type: array_name.to_string(),
@@ -960,6 +3204,229 @@ impl DependencyAnalyzer {
         None
     }
 
+    /// Computes a physical field order for `struct_type`, minimizing padding
+    /// by packing high-alignment fields first -- rustc's `Struct::new`
+    /// `optimize`/`sort_ascending` logic, adapted to this crate's
+    /// declarative types. Returns the declared order unchanged when
+    /// `container_attributes.optimize_layout` is off.
+    ///
+    /// Returns `(logical_to_physical, violations)`: `logical_to_physical[i]`
+    /// is the field that now occupies physical slot `i` (as its *original*
+    /// declared index), and `violations` is the result of re-running the
+    /// forward-reference layout check against the reordered field list, as
+    /// a safety net against a reordering that broke an expression's
+    /// resolvability.
+    pub fn optimize_struct_field_order(
+        &self,
+        struct_name: &str,
+        struct_type: &StructType,
+        all_typedefs: &[TypeDef],
+    ) -> (Vec<usize>, Vec<LayoutConstraintViolation>) {
+        let field_count = struct_type.fields.len();
+        if !struct_type.container_attributes.optimize_layout {
+            return ((0..field_count).collect(), Vec::new());
+        }
+
+        let discriminant =
+            self.find_pinned_discriminant_field(struct_name, struct_type, all_typedefs);
+        let locked =
+            self.struct_fields_with_layout_dependencies(struct_name, struct_type, all_typedefs);
+
+        let remaining: Vec<usize> = (0..field_count)
+            .filter(|i| Some(*i) != discriminant)
+            .collect();
+        let mut free_by_alignment: Vec<usize> = remaining
+            .iter()
+            .copied()
+            .filter(|i| !locked.contains(i))
+            .collect();
+        free_by_alignment.sort_by(|a, b| {
+            let align_a = self.type_alignment(&struct_type.fields[*a].field_type, all_typedefs);
+            let align_b = self.type_alignment(&struct_type.fields[*b].field_type, all_typedefs);
+            align_b.cmp(&align_a) // descending alignment
+        });
+
+        let mut order = Vec::with_capacity(field_count);
+        if let Some(d) = discriminant {
+            order.push(d);
+        }
+        let mut free_iter = free_by_alignment.into_iter();
+        for i in remaining {
+            if locked.contains(&i) {
+                order.push(i);
+            } else {
+                order.push(free_iter.next().expect(
+                    "every unlocked original index has exactly one slot in free_by_alignment",
+                ));
+            }
+        }
+
+        let reordered_fields: Vec<StructField> =
+            order.iter().map(|&i| struct_type.fields[i].clone()).collect();
+        let mut violations = Vec::new();
+        for (field_index, field) in reordered_fields.iter().enumerate() {
+            if let Some(violation) =
+                self.check_field_layout_dependency(struct_name, field_index, field, all_typedefs)
+            {
+                violations.push(violation);
+            }
+        }
+
+        (order, violations)
+    }
+
+    /// Reports how many bytes of padding `order` (as returned by
+    /// `optimize_struct_field_order`) saves over the struct's declared field
+    /// order, for a struct whose field sizes are all statically computable.
+    /// Returns `None` if either layout can't be sized (e.g. a field's size
+    /// depends on a field reference).
+    pub fn struct_padding_bytes_saved(
+        &self,
+        struct_type: &StructType,
+        order: &[usize],
+        all_typedefs: &[TypeDef],
+    ) -> Option<u64> {
+        let field_count = struct_type.fields.len();
+        let declared_order: Vec<usize> = (0..field_count).collect();
+        let declared_size = self.struct_size_for_order(struct_type, &declared_order, all_typedefs)?;
+        let reordered_size = self.struct_size_for_order(struct_type, order, all_typedefs)?;
+        Some(declared_size.saturating_sub(reordered_size))
+    }
+
+    /// Computes the total size of `struct_type` if its fields were laid out
+    /// in `order` (a permutation of field indices) instead of declaration
+    /// order, using the same packing rules as `check_struct_layout_sanity`.
+    fn struct_size_for_order(
+        &self,
+        struct_type: &StructType,
+        order: &[usize],
+        all_typedefs: &[TypeDef],
+    ) -> Option<u64> {
+        let packed = struct_type.container_attributes.packed;
+        let mut offset = 0u64;
+        let mut alignment = 1u64;
+
+        for &field_index in order {
+            let field = &struct_type.fields[field_index];
+            let field_size = self.constant_type_size(&field.field_type, all_typedefs)?;
+            let field_alignment = self.type_alignment(&field.field_type, all_typedefs);
+            offset = if packed {
+                offset
+            } else {
+                alignment = alignment.max(field_alignment);
+                align_up(offset, field_alignment)
+            } + field_size;
+        }
+
+        if struct_type.container_attributes.aligned > 0 {
+            alignment = struct_type.container_attributes.aligned;
+        }
+        Some(if packed { offset } else { align_up(offset, alignment) })
+    }
+
+    /// Finds the field in `struct_type` that serves as some enum's tag --
+    /// i.e. some `Enum` typedef's `tag_ref` resolves to a field in this
+    /// struct. That field is always placed first when optimizing layout,
+    /// regardless of its alignment, matching how a discriminant must lead
+    /// an enum-variant struct's payload.
+    fn find_pinned_discriminant_field(
+        &self,
+        struct_name: &str,
+        struct_type: &StructType,
+        all_typedefs: &[TypeDef],
+    ) -> Option<usize> {
+        for typedef in all_typedefs {
+            let TypeKind::Enum(enum_type) = &typedef.kind else {
+                continue;
+            };
+            let mut field_refs = Vec::new();
+            self.collect_field_references_recursive(&enum_type.tag_ref, &mut field_refs);
+            for field_ref in field_refs {
+                let (ref_type, ref_field) = self.parse_field_reference(&field_ref);
+                if ref_type != struct_name {
+                    continue;
+                }
+                if let Some(index) = struct_type.fields.iter().position(|f| f.name == ref_field) {
+                    return Some(index);
+                }
+            }
+        }
+        None
+    }
+
+    /// Indices of fields in `struct_type` that participate in an intra-struct
+    /// layout dependency -- either a field whose type's expressions
+    /// reference a sibling field (e.g. an array size), or a field that is
+    /// itself referenced that way. These must keep their declared relative
+    /// position so the referencing expression stays resolvable, mirroring
+    /// the forward-reference detection in `check_field_layout_dependency`.
+    fn struct_fields_with_layout_dependencies(
+        &self,
+        struct_name: &str,
+        struct_type: &StructType,
+        all_typedefs: &[TypeDef],
+    ) -> HashSet<usize> {
+        let mut locked = HashSet::new();
+        for (field_index, field) in struct_type.fields.iter().enumerate() {
+            let mut field_refs = Vec::new();
+            if let Some(field_type_def) = self.find_typedef_for_type(&field.field_type, all_typedefs)
+            {
+                field_refs = self.collect_all_field_references_in_type(field_type_def);
+            } else {
+                self.collect_field_references_in_type_kind(&field.field_type, &mut field_refs);
+            }
+
+            for field_ref in field_refs {
+                // An unqualified reference names a sibling field of this struct.
+                let (ref_type, ref_field) = if field_ref.contains("::") || field_ref.contains('.') {
+                    self.parse_field_reference(&field_ref)
+                } else {
+                    (struct_name.to_string(), field_ref.clone())
+                };
+                if ref_type != struct_name {
+                    continue;
+                }
+                if let Some(ref_field_index) =
+                    struct_type.fields.iter().position(|f| f.name == ref_field)
+                {
+                    locked.insert(field_index);
+                    locked.insert(ref_field_index);
+                }
+            }
+        }
+        locked
+    }
+
+    /// Best-effort natural alignment for a field's type, in bytes. Integral
+    /// and floating-point primitives are aligned to their own size; a
+    /// struct or enum's alignment is the max of its members'/variants'.
+    /// Arrays, unions, and size-discriminated unions don't have a statically
+    /// known alignment in this simplified model, so they're treated as
+    /// byte-aligned (1) -- conservative, since it never lets them jump ahead
+    /// of a field whose alignment is actually known.
+    fn type_alignment(&self, type_kind: &TypeKind, all_typedefs: &[TypeDef]) -> u64 {
+        match type_kind {
+            TypeKind::Primitive(prim) => self.get_primitive_size(prim),
+            TypeKind::TypeRef(_) => self
+                .find_typedef_for_type(type_kind, all_typedefs)
+                .map(|typedef| self.type_alignment(&typedef.kind, all_typedefs))
+                .unwrap_or(1),
+            TypeKind::Struct(struct_type) => struct_type
+                .fields
+                .iter()
+                .map(|f| self.type_alignment(&f.field_type, all_typedefs))
+                .max()
+                .unwrap_or(1),
+            TypeKind::Enum(enum_type) => enum_type
+                .variants
+                .iter()
+                .map(|v| self.type_alignment(&v.variant_type, all_typedefs))
+                .max()
+                .unwrap_or(1),
+            TypeKind::Array(_) | TypeKind::Union(_) | TypeKind::SizeDiscriminatedUnion(_) => 1,
+        }
+    }
+
     fn type_size_depends_on_field_refs(&self, typedef: &TypeDef, all_typedefs: &[TypeDef]) -> bool {
         // Check if a type's size depends on field references in its expressions
         match &typedef.kind {
@@ -1013,6 +3480,28 @@ impl DependencyAnalyzer {
             }
 
             TypeKind::Enum(enum_type) => {
+                // Niche-filled enums have no separate tag field: their size is
+                // simply the dataful variant's size, independent of tag_ref.
+                if let Some(niche) = &enum_type.niche {
+                    return enum_type
+                        .variants
+                        .iter()
+                        .find(|v| v.name == niche.dataful_variant)
+                        .map(|dataful| {
+                            if let Some(variant_typedef) =
+                                self.find_typedef_for_type(&dataful.variant_type, all_typedefs)
+                            {
+                                self.type_size_depends_on_field_refs(variant_typedef, all_typedefs)
+                            } else {
+                                self.type_size_depends_on_field_refs_recursive(
+                                    &dataful.variant_type,
+                                    all_typedefs,
+                                )
+                            }
+                        })
+                        .unwrap_or(false);
+                }
+
                 // An enum's size depends on field refs if:
                 // 1. Its variants have different sizes AND the tag expression contains field refs, OR
                 // 2. Any of its variants' sizes depend on field refs
@@ -1125,62 +3614,7 @@ impl DependencyAnalyzer {
     }
 
     fn get_primitive_size(&self, prim: &PrimitiveType) -> u64 {
-        match prim {
-            PrimitiveType::Integral(int_type) => match int_type {
-                IntegralType::U8 | IntegralType::I8 => 1,
-                IntegralType::U16 | IntegralType::I16 => 2,
-                IntegralType::U32 | IntegralType::I32 => 4,
-                IntegralType::U64 | IntegralType::I64 => 8,
-            },
-            PrimitiveType::FloatingPoint(float_type) => match float_type {
-                FloatingPointType::F16 => 2,
-                FloatingPointType::F32 => 4,
-                FloatingPointType::F64 => 8,
-            },
-        }
-    }
-
-    fn find_layout_dependency_chain(
-        &self,
-        from_type: &str,
-        to_type: &str,
-        _all_typedefs: &[TypeDef],
-    ) -> Option<Vec<String>> {
-        // Use BFS to find if there's a path from from_type to to_type through layout dependencies
-        let mut queue = VecDeque::new();
-        let mut visited = HashSet::new();
-        let mut parent_map: HashMap<String, String> = HashMap::new();
-
-        queue.push_back(from_type.to_string());
-        visited.insert(from_type.to_string());
-
-        while let Some(current) = queue.pop_front() {
-            if current == to_type {
-                // Found a path - reconstruct it
-                let mut chain = Vec::new();
-                let mut node = to_type.to_string();
-
-                while let Some(parent) = parent_map.get(&node) {
-                    chain.push(node.clone());
-                    node = parent.clone();
-                }
-                chain.push(from_type.to_string());
-                chain.reverse();
-                return Some(chain);
-            }
-
-            // Follow dependency edges to find path from from_type to to_type
-            // If A -> B means "A depends on B", then to go from A to B we follow edges where edge.from == current
-            for edge in &self.graph.edges {
-                if edge.from == current && !visited.contains(&edge.to) {
-                    visited.insert(edge.to.clone());
-                    parent_map.insert(edge.to.clone(), current.clone());
-                    queue.push_back(edge.to.clone());
-                }
-            }
-        }
-
-        None
+        primitive_size(prim)
     }
 
     fn parse_field_reference(&self, field_ref: &str) -> (String, String) {
@@ -1456,12 +3890,12 @@ impl DependencyAnalyzer {
         _enum_field_index: usize,
         _ref_field_index: usize,
         enum_typedef: &TypeDef,
-        _all_typedefs: &[TypeDef],
+        all_typedefs: &[TypeDef],
     ) -> bool {
         // Check if the enum's size is constant or depends on its tag value
         if let TypeKind::Enum(_enum_type) = &enum_typedef.kind {
             // If the enum has constant size (all variants same size), then it doesn't affect later field offsets
-            if self.is_enum_with_constant_size_variants(enum_typedef) {
+            if self.is_enum_with_constant_size_variants(enum_typedef, all_typedefs) {
                 return false; // Constant size enum doesn't affect field offsets
             }
 
@@ -1475,17 +3909,32 @@ impl DependencyAnalyzer {
         true
     }
 
-    fn is_enum_with_constant_size_variants(&self, typedef: &TypeDef) -> bool {
+    fn is_enum_with_constant_size_variants(
+        &self,
+        typedef: &TypeDef,
+        all_typedefs: &[TypeDef],
+    ) -> bool {
         if let TypeKind::Enum(enum_type) = &typedef.kind {
             // Require multiple variants - single variant enums are still considered problematic
             if enum_type.variants.len() <= 1 {
                 return false;
             }
 
-            // Check if all variants have the same size
+            // Check if all variants have the same size. A variant that's a
+            // `TypeRef` is resolved one level so a named primitive alias
+            // (e.g. `type ErrorCode = u32;`) counts the same as writing the
+            // primitive inline -- the discriminant's own width is irrelevant
+            // here, since this only asks about the *variant payload* size.
             let mut variant_sizes = std::collections::HashSet::new();
             for variant in &enum_type.variants {
-                match &variant.variant_type {
+                let resolved = match &variant.variant_type {
+                    TypeKind::TypeRef(_) => self
+                        .find_typedef_for_type(&variant.variant_type, all_typedefs)
+                        .map(|td| &td.kind)
+                        .unwrap_or(&variant.variant_type),
+                    other => other,
+                };
+                match resolved {
                     TypeKind::Primitive(prim) => {
                         let size = self.get_primitive_size(prim);
                         variant_sizes.insert(size);
@@ -1657,6 +4106,11 @@ impl DependencyAnalyzer {
               ),
             });
                     }
+
+                    // The unsized-tail rule itself (variable-size field must be
+                    // the struct's last field) is enforced generally for every
+                    // struct by `validate_struct_field_constraints`, not just
+                    // ones that reach here via a size-discriminated union.
                 }
             }
             TypeKind::Array(array_type) => {
@@ -1751,6 +4205,115 @@ impl DependencyAnalyzer {
         has_union && !has_other_variable_size_component
     }
 
+    /// Enforces the unsized-tail rule: a variable-size component (a
+    /// size-discriminated union, or any other field `type_has_variable_size`
+    /// reports as non-constant) may only appear as a struct's final field --
+    /// matching the DST rule that only the trailing field of an aggregate may
+    /// be unsized. A variable-size field anywhere else makes every
+    /// subsequent field's offset runtime-dependent and thus uncomputable.
+    fn check_variable_size_field_is_trailing(
+        &self,
+        struct_name: &str,
+        struct_type: &StructType,
+        all_typedefs: &[TypeDef],
+    ) -> Option<LayoutConstraintViolation> {
+        let last_index = struct_type.fields.len().checked_sub(1)?;
+
+        for (index, field) in struct_type.fields.iter().enumerate() {
+            if index == last_index {
+                continue;
+            }
+            if self.type_has_variable_size(&field.field_type, all_typedefs) {
+                let first_indeterminable = &struct_type.fields[index + 1];
+                return Some(LayoutConstraintViolation {
+                    violating_type: struct_name.to_string(),
+                    violating_expression: format!("field '{}' has variable size", field.name),
+                    dependency_chain: vec![
+                        struct_name.to_string(),
+                        field.name.clone(),
+                        first_indeterminable.name.clone(),
+                    ],
+                    reason: format!(
+                        "Struct '{}' field '{}' has variable size but is not the struct's final \
+                         field; the offset of field '{}' (and every field after it) can't be \
+                         computed without first decoding '{}'",
+                        struct_name, field.name, first_indeterminable.name, field.name
+                    ),
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Extends the unsized-tail rule to `Union`: unlike a struct's fields,
+    /// every variant starts at offset 0, so there's no "trailing" position
+    /// that would make a variable-size variant safe -- any variable-size
+    /// variant makes the union's own overall size indeterminate.
+    fn check_union_variant_variable_size(
+        &self,
+        union_name: &str,
+        union_type: &UnionType,
+        all_typedefs: &[TypeDef],
+    ) -> Option<LayoutConstraintViolation> {
+        for variant in &union_type.variants {
+            if self.type_has_variable_size(&variant.variant_type, all_typedefs) {
+                return Some(LayoutConstraintViolation {
+                    violating_type: union_name.to_string(),
+                    violating_expression: format!("variant '{}' has variable size", variant.name),
+                    dependency_chain: vec![union_name.to_string(), variant.name.clone()],
+                    reason: format!(
+                        "Union '{}' variant '{}' has variable size, but a union's overall size \
+                         must be the fixed maximum over all its variants; a variable-size variant \
+                         makes that maximum uncomputable",
+                        union_name, variant.name
+                    ),
+                });
+            }
+        }
+        None
+    }
+
+    /// Extends the unsized-tail rule to `Enum`: a variant's payload may only
+    /// have variable size if it's the last declared variant, mirroring the
+    /// struct rule -- an enum with a variable-size variant encoded ahead of
+    /// others would make every subsequent variant's payload offset (and thus
+    /// its decodability) depend on first decoding the earlier one.
+    fn check_enum_non_terminal_variant_variable_size(
+        &self,
+        enum_name: &str,
+        enum_type: &EnumType,
+        all_typedefs: &[TypeDef],
+    ) -> Option<LayoutConstraintViolation> {
+        let last_index = enum_type.variants.len().checked_sub(1)?;
+
+        for (index, variant) in enum_type.variants.iter().enumerate() {
+            if index == last_index {
+                continue;
+            }
+            if self.type_has_variable_size(&variant.variant_type, all_typedefs) {
+                let next_variant = &enum_type.variants[index + 1];
+                return Some(LayoutConstraintViolation {
+                    violating_type: enum_name.to_string(),
+                    violating_expression: format!("variant '{}' has variable size", variant.name),
+                    dependency_chain: vec![
+                        enum_name.to_string(),
+                        variant.name.clone(),
+                        next_variant.name.clone(),
+                    ],
+                    reason: format!(
+                        "Enum '{}' variant '{}' has variable size but is not the enum's final \
+                         variant; treat variable-size payloads as only valid in the terminal \
+                         variant position, matching the struct unsized-tail rule",
+                        enum_name, variant.name
+                    ),
+                });
+            }
+        }
+
+        None
+    }
+
     fn type_contains_size_discriminated_union(
         &self,
         type_kind: &TypeKind,
@@ -1839,42 +4402,7 @@ impl DependencyAnalyzer {
     }
 
     fn type_has_variable_size(&self, type_kind: &TypeKind, all_typedefs: &[TypeDef]) -> bool {
-        match type_kind {
-            TypeKind::Primitive(_) => false, // Primitives have fixed size
-            TypeKind::TypeRef(type_ref) => {
-                if let Some(typedef) = all_typedefs.iter().find(|td| td.name == type_ref.name) {
-                    self.type_has_variable_size(&typedef.kind, all_typedefs)
-                } else {
-                    false // Unknown type, assume constant for now
-                }
-            }
-            TypeKind::Struct(struct_type) => {
-                // A struct has variable size if any of its fields have variable size
-                for field in &struct_type.fields {
-                    if self.type_has_variable_size(&field.field_type, all_typedefs) {
-                        return true;
-                    }
-                }
-                false
-            }
-            TypeKind::Union(_) => false, // Regular unions have fixed size (max of all variants)
-            TypeKind::SizeDiscriminatedUnion(_) => true, // Size-discriminated unions have variable size by definition
-            TypeKind::Enum(enum_type) => {
-                // Enums have variable size if their tag is non-constant or variants have different sizes
-                if !enum_type.tag_ref.is_constant() {
-                    return true;
-                }
-                // Check if variants have different sizes (simplified check)
-                // In a full implementation, we'd calculate actual variant sizes
-                false
-            }
-            TypeKind::Array(array_type) => {
-                // Arrays have variable size if their size expression is non-constant
-                // or if their element type has variable size
-                !array_type.size.is_constant()
-                    || self.type_has_variable_size(&array_type.element_type, all_typedefs)
-            }
-        }
+        type_has_variable_size(type_kind, all_typedefs)
     }
 
     fn validate_size_discriminated_union_variant_sizes(
@@ -101,6 +101,265 @@ mod dependency_tests {
         assert!(cycle.cycle.contains(&"TypeB".to_string()));
     }
 
+    #[test]
+    fn test_condense_acyclic_graph_matches_topological_order() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![
+            TypeDef {
+                name: "BaseType".to_string(),
+                kind: create_u32_primitive(),
+            },
+            TypeDef {
+                name: "DerivedType".to_string(),
+                kind: TypeKind::Struct(StructType {
+                    container_attributes: Default::default(),
+                    fields: vec![StructField {
+                        name: "base_field".to_string(),
+                        field_type: create_type_ref("BaseType"),
+                    }],
+                }),
+            },
+        ];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis.recursive_groups.is_empty());
+        assert!(analysis
+            .condensation
+            .components
+            .iter()
+            .all(|component| !component.is_recursive && component.members.len() == 1));
+
+        let component_order: Vec<&String> = analysis
+            .condensation
+            .components
+            .iter()
+            .map(|component| &component.members[0])
+            .collect();
+        let base_pos = component_order
+            .iter()
+            .position(|x| *x == "BaseType")
+            .unwrap();
+        let derived_pos = component_order
+            .iter()
+            .position(|x| *x == "DerivedType")
+            .unwrap();
+        assert!(base_pos < derived_pos);
+    }
+
+    #[test]
+    fn test_condense_groups_mutually_recursive_types() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![
+            TypeDef {
+                name: "TypeA".to_string(),
+                kind: TypeKind::Struct(StructType {
+                    container_attributes: Default::default(),
+                    fields: vec![StructField {
+                        name: "field_b".to_string(),
+                        field_type: create_type_ref("TypeB"),
+                    }],
+                }),
+            },
+            TypeDef {
+                name: "TypeB".to_string(),
+                kind: TypeKind::Struct(StructType {
+                    container_attributes: Default::default(),
+                    fields: vec![StructField {
+                        name: "field_a".to_string(),
+                        field_type: create_type_ref("TypeA"),
+                    }],
+                }),
+            },
+        ];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        // Still reported as a cycle for diagnostics, but no longer fatal --
+        // `topological_sort` still fails, while `condense` succeeds by
+        // grouping the pair into one recursive component.
+        assert!(!analysis.cycles.is_empty());
+        assert!(analysis.topological_order.is_none());
+
+        assert_eq!(analysis.recursive_groups.len(), 1);
+        let group = &analysis.recursive_groups[0];
+        assert!(group.members.contains(&"TypeA".to_string()));
+        assert!(group.members.contains(&"TypeB".to_string()));
+
+        let recursive_components: Vec<_> = analysis
+            .condensation
+            .components
+            .iter()
+            .filter(|component| component.is_recursive)
+            .collect();
+        assert_eq!(recursive_components.len(), 1);
+        assert_eq!(recursive_components[0].members.len(), 2);
+    }
+
+    #[test]
+    fn test_condense_flags_self_referencing_type_as_recursive() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "SelfRef".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![StructField {
+                    name: "next".to_string(),
+                    field_type: create_type_ref("SelfRef"),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert_eq!(analysis.recursive_groups.len(), 1);
+        assert_eq!(analysis.recursive_groups[0].members, vec!["SelfRef".to_string()]);
+    }
+
+    #[test]
+    fn test_update_matches_full_rebuild_after_changing_one_type() {
+        let base_type = TypeDef {
+            name: "BaseType".to_string(),
+            kind: create_u32_primitive(),
+        };
+        let derived_type = TypeDef {
+            name: "DerivedType".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![StructField {
+                    name: "base_field".to_string(),
+                    field_type: create_type_ref("BaseType"),
+                }],
+            }),
+        };
+
+        let mut analyzer = DependencyAnalyzer::new();
+        analyzer.update(&[base_type.clone(), derived_type.clone()], &[]);
+
+        // Changing DerivedType to add a second field should leave BaseType's
+        // own state untouched while re-deriving DerivedType's edges.
+        let changed_derived = TypeDef {
+            name: "DerivedType".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "base_field".to_string(),
+                        field_type: create_type_ref("BaseType"),
+                    },
+                    StructField {
+                        name: "extra_field".to_string(),
+                        field_type: create_u8_primitive(),
+                    },
+                ],
+            }),
+        };
+
+        let incremental = analyzer.update(&[changed_derived.clone()], &[]);
+
+        let mut full_analyzer = DependencyAnalyzer::new();
+        let full = full_analyzer.analyze_multiple_typedefs(&[base_type, changed_derived]);
+
+        assert_eq!(
+            incremental.topological_order.is_some(),
+            full.topological_order.is_some()
+        );
+        assert_eq!(incremental.recursive_groups.len(), full.recursive_groups.len());
+        assert_eq!(incremental.layout_violations.len(), full.layout_violations.len());
+        assert_eq!(incremental.validation_errors.len(), full.validation_errors.len());
+
+        let mut incremental_nodes: Vec<&String> = incremental.graph.nodes.iter().collect();
+        let mut full_nodes: Vec<&String> = full.graph.nodes.iter().collect();
+        incremental_nodes.sort();
+        full_nodes.sort();
+        assert_eq!(incremental_nodes, full_nodes);
+    }
+
+    #[test]
+    fn test_update_removed_type_drops_its_node_and_keeps_unrelated_types() {
+        let base_type = TypeDef {
+            name: "BaseType".to_string(),
+            kind: create_u32_primitive(),
+        };
+        let derived_type = TypeDef {
+            name: "DerivedType".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![StructField {
+                    name: "base_field".to_string(),
+                    field_type: create_type_ref("BaseType"),
+                }],
+            }),
+        };
+        let standalone_type = TypeDef {
+            name: "StandaloneType".to_string(),
+            kind: create_u8_primitive(),
+        };
+
+        let mut analyzer = DependencyAnalyzer::new();
+        analyzer.update(&[base_type, derived_type, standalone_type], &[]);
+
+        let analysis = analyzer.update(&[], &["StandaloneType".to_string()]);
+
+        assert!(!analysis.graph.nodes.contains("StandaloneType"));
+        assert!(analysis.graph.nodes.contains("BaseType"));
+        assert!(analysis.graph.nodes.contains("DerivedType"));
+        assert!(analysis
+            .graph
+            .edges
+            .iter()
+            .any(|dep| dep.from == "DerivedType" && dep.to == "BaseType"));
+    }
+
+    #[test]
+    fn test_duplicate_dependency_edges_do_not_strand_topological_sort() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        // DerivedType has two fields that both reference BaseType, so
+        // `add_dependency` is called twice for the same (from, to) pair.
+        let typedefs = vec![
+            TypeDef {
+                name: "BaseType".to_string(),
+                kind: create_u32_primitive(),
+            },
+            TypeDef {
+                name: "DerivedType".to_string(),
+                kind: TypeKind::Struct(StructType {
+                    container_attributes: Default::default(),
+                    fields: vec![
+                        StructField {
+                            name: "first_field".to_string(),
+                            field_type: create_type_ref("BaseType"),
+                        },
+                        StructField {
+                            name: "second_field".to_string(),
+                            field_type: create_type_ref("BaseType"),
+                        },
+                    ],
+                }),
+            },
+        ];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis.cycles.is_empty());
+        let topo_order = analysis
+            .topological_order
+            .expect("duplicate edges must not be reported as a cycle");
+        let base_pos = topo_order.iter().position(|x| x == "BaseType").unwrap();
+        let derived_pos = topo_order.iter().position(|x| x == "DerivedType").unwrap();
+        assert!(base_pos < derived_pos);
+
+        assert_eq!(analysis.graph.dependency_count("DerivedType", "BaseType"), 2);
+        assert_eq!(
+            analysis.graph.adjacency_list.get("DerivedType"),
+            Some(&vec!["BaseType".to_string()])
+        );
+    }
+
     #[test]
     fn test_valid_enum_with_constant_tag() {
         let mut analyzer = DependencyAnalyzer::new();
@@ -110,6 +369,8 @@ mod dependency_tests {
             kind: TypeKind::Enum(EnumType {
                 container_attributes: Default::default(),
                 tag_ref: create_literal_expr(42), // Constant tag - valid
+                niche: None,
+                tag_type: None,
                 variants: vec![EnumVariant {
                     name: "Variant1".to_string(),
                     tag_value: 1,
@@ -151,6 +412,8 @@ mod dependency_tests {
                     container_attributes: Default::default(),
                     // Invalid: tag references a field whose offset depends on this enum's size
                     tag_ref: create_field_ref_expr(vec!["Container", "other_field"]),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![EnumVariant {
                         name: "Variant1".to_string(),
                         tag_value: 1,
@@ -308,6 +571,8 @@ mod dependency_tests {
                             right: Box::new(create_literal_expr(2)),
                         })),
                     }),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![EnumVariant {
                         name: "Variant1".to_string(),
                         tag_value: 1,
@@ -447,6 +712,8 @@ mod dependency_tests {
                     packed: true,
                     aligned: 0,
                     comment: None,
+                    optimize_layout: false,
+                    tagged: None,
                 },
                 fields: vec![
                     StructField {
@@ -478,6 +745,8 @@ mod dependency_tests {
                     packed: false,
                     aligned: 16,
                     comment: Some("16-byte aligned".to_string()),
+                    optimize_layout: false,
+                    tagged: None,
                 },
                 fields: vec![StructField {
                     name: "field1".to_string(),
@@ -761,6 +1030,8 @@ mod dependency_tests {
                     container_attributes: Default::default(),
                     // Non-constant tag makes this enum's size non-constant
                     tag_ref: create_field_ref_expr(vec!["some_external_field"]),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![
                         EnumVariant {
                             name: "Variant1".to_string(),
@@ -1059,6 +1330,8 @@ mod dependency_tests {
             kind: TypeKind::Enum(EnumType {
                 container_attributes: Default::default(),
                 tag_ref: create_literal_expr(0),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "duplicate_variant".to_string(),
@@ -1096,6 +1369,8 @@ mod dependency_tests {
             kind: TypeKind::Enum(EnumType {
                 container_attributes: Default::default(),
                 tag_ref: create_literal_expr(0),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "variant1".to_string(),
@@ -1161,6 +1436,8 @@ mod dependency_tests {
                 kind: TypeKind::Enum(EnumType {
                     container_attributes: Default::default(),
                     tag_ref: create_literal_expr(0),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![
                         EnumVariant {
                             name: "bad_variant".to_string(),
@@ -1221,6 +1498,8 @@ mod dependency_tests {
                 kind: TypeKind::Enum(EnumType {
                     container_attributes: Default::default(),
                     tag_ref: create_literal_expr(0),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![
                         EnumVariant {
                             name: "variant1".to_string(),
@@ -1351,6 +1630,8 @@ mod dependency_tests {
                     container_attributes: Default::default(),
                     // Tag references a field that comes after the enum field
                     tag_ref: create_field_ref_expr(vec!["ValidStruct", "tag_field"]),
+                    niche: None,
+                    tag_type: None,
                     variants: vec![
                         EnumVariant {
                             name: "VariantA".to_string(),
@@ -1860,6 +2141,8 @@ mod resolved_tests {
                     packed: true,
                     aligned: 0,
                     comment: None,
+                    optimize_layout: false,
+                    tagged: None,
                 },
                 fields: vec![
                     StructField {
@@ -1994,7 +2277,7 @@ mod resolved_tests {
         assert_eq!(resolved.size, Size::Const(4)); // Size of largest variant (u32)
         assert_eq!(resolved.alignment, 4);
 
-        if let ResolvedTypeKind::Union { variants } = &resolved.kind {
+        if let ResolvedTypeKind::Union { variants, .. } = &resolved.kind {
             assert_eq!(variants.len(), 2);
             // All variants should have offset 0 in a union
             assert_eq!(variants[0].offset, Some(0));
@@ -2013,6 +2296,8 @@ mod resolved_tests {
             kind: TypeKind::Enum(EnumType {
                 container_attributes: Default::default(),
                 tag_ref: ExprKind::Literal(LiteralExpr::U64(0)),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "Variant1".to_string(),
@@ -2308,4 +2593,1945 @@ mod resolved_tests {
             panic!("Expected size-discriminated union type");
         }
     }
+
+    #[test]
+    fn test_unqualified_sibling_field_reference_resolves_in_scope() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Packet".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "len".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "payload".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            // Unqualified: should resolve against the enclosing
+                            // struct's fields rather than needing "Packet.len".
+                            size: create_field_ref_expr(vec!["len"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            analysis
+                .validation_errors
+                .iter()
+                .all(|e| e.error_type != "UnresolvedFieldReference"),
+            "unqualified reference to a sibling field should resolve"
+        );
+        let dep = analysis
+            .graph
+            .edges
+            .iter()
+            .find(|dep| dep.from == "Packet")
+            .expect("Should find dependency from Packet");
+        assert_eq!(dep.to, "Packet::len");
+    }
+
+    #[test]
+    fn test_unqualified_reference_resolves_against_enclosing_scope() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        // "count" lives on the outer union's variant list, not on the inline
+        // nested struct -- the reference should walk outward to find it.
+        let typedefs = vec![TypeDef {
+            name: "Message".to_string(),
+            kind: TypeKind::Union(UnionType {
+                container_attributes: Default::default(),
+                variants: vec![
+                    UnionVariant {
+                        name: "count".to_string(),
+                        variant_type: create_u32_primitive(),
+                    },
+                    UnionVariant {
+                        name: "items".to_string(),
+                        variant_type: TypeKind::Struct(StructType {
+                            container_attributes: Default::default(),
+                            fields: vec![StructField {
+                                name: "items_array".to_string(),
+                                field_type: TypeKind::Array(ArrayType {
+                                    container_attributes: Default::default(),
+                                    size: create_field_ref_expr(vec!["count"]),
+                                    element_type: Box::new(create_u8_primitive()),
+                                    jagged: false,
+                                }),
+                            }],
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            analysis
+                .validation_errors
+                .iter()
+                .all(|e| e.error_type != "UnresolvedFieldReference"),
+            "reference should resolve against the enclosing union scope"
+        );
+        let dep = analysis
+            .graph
+            .edges
+            .iter()
+            .find(|dep| dep.from == "Message" && dep.to == "Message::count");
+        assert!(dep.is_some(), "expected a dependency edge on Message::count");
+    }
+
+    #[test]
+    fn test_unresolved_field_reference_reports_validation_error() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Lonely".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![StructField {
+                    name: "payload".to_string(),
+                    field_type: TypeKind::Array(ArrayType {
+                        container_attributes: Default::default(),
+                        // No field named this exists anywhere in scope.
+                        size: create_field_ref_expr(vec!["nonexistent_field"]),
+                        element_type: Box::new(create_u8_primitive()),
+                        jagged: false,
+                    }),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "UnresolvedFieldReference")
+            .expect("Should report an unresolved field reference");
+        assert_eq!(error.violating_type, "Lonely");
+        assert_eq!(error.duplicate_name, "nonexistent_field");
+    }
+
+    #[test]
+    fn test_explicit_up_qualifier_skips_innermost_scope() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        // "inner_only" exists solely on the inline nested struct's own scope.
+        // "../inner_only" asks to skip that innermost frame, so it must NOT
+        // resolve even though the name would be found without the "..".
+        let typedefs = vec![TypeDef {
+            name: "Frame".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "outer_count".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "inner".to_string(),
+                        field_type: TypeKind::Struct(StructType {
+                            container_attributes: Default::default(),
+                            fields: vec![
+                                StructField {
+                                    name: "inner_only".to_string(),
+                                    field_type: create_u8_primitive(),
+                                },
+                                StructField {
+                                    name: "data".to_string(),
+                                    field_type: TypeKind::Array(ArrayType {
+                                        container_attributes: Default::default(),
+                                        size: create_field_ref_expr(vec!["../inner_only"]),
+                                        element_type: Box::new(create_u8_primitive()),
+                                        jagged: false,
+                                    }),
+                                },
+                            ],
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "UnresolvedFieldReference")
+            .expect("'../inner_only' should skip the only frame that has 'inner_only'");
+        assert_eq!(error.violating_type, "Frame");
+        assert_eq!(error.duplicate_name, "inner_only");
+    }
+
+    #[test]
+    fn test_unqualified_reference_still_resolves_through_nested_scope() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        // Without the "..", the same "inner_only" reference should resolve
+        // fine by searching the innermost frame first.
+        let typedefs = vec![TypeDef {
+            name: "Frame".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "outer_count".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "inner".to_string(),
+                        field_type: TypeKind::Struct(StructType {
+                            container_attributes: Default::default(),
+                            fields: vec![
+                                StructField {
+                                    name: "inner_only".to_string(),
+                                    field_type: create_u8_primitive(),
+                                },
+                                StructField {
+                                    name: "data".to_string(),
+                                    field_type: TypeKind::Array(ArrayType {
+                                        container_attributes: Default::default(),
+                                        size: create_field_ref_expr(vec!["inner_only"]),
+                                        element_type: Box::new(create_u8_primitive()),
+                                        jagged: false,
+                                    }),
+                                },
+                            ],
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            analysis
+                .validation_errors
+                .iter()
+                .all(|e| e.error_type != "UnresolvedFieldReference"),
+            "unqualified 'inner_only' should resolve against the nested scope"
+        );
+        let dep = analysis
+            .graph
+            .edges
+            .iter()
+            .find(|dep| dep.from == "Frame" && dep.to == "Frame::inner_only");
+        assert!(dep.is_some(), "expected a dependency edge on Frame::inner_only");
+    }
+
+    #[test]
+    fn test_field_evaluation_order_schedules_dependent_field_last() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Packet".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "len".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "payload".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["len"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let order = analysis
+            .graph
+            .field_evaluation_order("Packet")
+            .expect("Packet's fields should have an acyclic schedule");
+        assert_eq!(order, vec!["len".to_string(), "payload".to_string()]);
+    }
+
+    #[test]
+    fn test_field_evaluation_order_includes_independent_fields() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Pair".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "a".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "b".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let order = analysis
+            .graph
+            .field_evaluation_order("Pair")
+            .expect("independent fields should always have a schedule");
+        assert_eq!(order.len(), 2);
+        assert!(order.contains(&"a".to_string()));
+        assert!(order.contains(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_field_evaluation_order_reports_cycle_with_dependency_chain() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        // "a"'s size depends on "b", and "b"'s size depends on "a" -- neither
+        // can be scheduled first.
+        let typedefs = vec![TypeDef {
+            name: "Circular".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "a".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["b"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                    StructField {
+                        name: "b".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["a"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let violation = analysis
+            .graph
+            .field_evaluation_order("Circular")
+            .expect_err("mutually dependent fields should not produce a schedule");
+
+        assert_eq!(violation.violating_type, "Circular");
+        assert!(violation.dependency_chain.contains(&"a".to_string()));
+        assert!(violation.dependency_chain.contains(&"b".to_string()));
+        // The chain should loop back to its own start.
+        assert_eq!(
+            violation.dependency_chain.first(),
+            violation.dependency_chain.last()
+        );
+    }
+
+    #[test]
+    fn test_field_evaluation_order_unknown_type_is_trivially_empty() {
+        let graph = DependencyGraph::new();
+
+        let order = graph
+            .field_evaluation_order("NeverAnalyzed")
+            .expect("a type with no recorded fields has a vacuously valid empty schedule");
+        assert!(order.is_empty());
+    }
+
+    #[test]
+    fn test_niche_filling_valid_configuration_has_no_errors() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "MaybeIndex".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: Some(NicheFilling {
+                    dataful_variant: "some_index".to_string(),
+                    niche_field_path: "value".to_string(),
+                    niche_start: 1,
+                    niche_count: 1,
+                }),
+                tag_type: None,
+                variants: vec![
+                    EnumVariant {
+                        name: "some_index".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "none".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            !analysis
+                .validation_errors
+                .iter()
+                .any(|e| e.error_type.starts_with("NicheFilling")),
+            "a well-formed niche config should not raise any NicheFilling* error: {:?}",
+            analysis.validation_errors
+        );
+    }
+
+    #[test]
+    fn test_niche_filling_insufficient_niche_is_rejected() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "ThreeWayEnum".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: Some(NicheFilling {
+                    dataful_variant: "dataful".to_string(),
+                    niche_field_path: "value".to_string(),
+                    niche_start: 1,
+                    // Only one sentinel value, but there are two data-less variants.
+                    niche_count: 1,
+                }),
+                tag_type: None,
+                variants: vec![
+                    EnumVariant {
+                        name: "dataful".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "empty_a".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "empty_b".to_string(),
+                        tag_value: 2,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "NicheFillingInsufficientNiche")
+            .expect("Should find an insufficient-niche error");
+        assert_eq!(error.violating_type, "ThreeWayEnum");
+    }
+
+    #[test]
+    fn test_niche_filling_conflicting_tag_ref_is_rejected() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![
+            TypeDef {
+                name: "Container".to_string(),
+                kind: TypeKind::Struct(StructType {
+                    container_attributes: Default::default(),
+                    fields: vec![StructField {
+                        name: "selector".to_string(),
+                        field_type: create_u32_primitive(),
+                    }],
+                }),
+            },
+            TypeDef {
+                name: "NicheWithTag".to_string(),
+                kind: TypeKind::Enum(EnumType {
+                    container_attributes: Default::default(),
+                    // A niche-filled enum emits no tag field, so a non-constant
+                    // tag_ref conflicts with the niche encoding.
+                    tag_ref: create_field_ref_expr(vec!["Container", "selector"]),
+                    niche: Some(NicheFilling {
+                        dataful_variant: "some_value".to_string(),
+                        niche_field_path: "value".to_string(),
+                        niche_start: 1,
+                        niche_count: 1,
+                    }),
+                    tag_type: None,
+                    variants: vec![
+                        EnumVariant {
+                            name: "some_value".to_string(),
+                            tag_value: 0,
+                            variant_type: create_u32_primitive(),
+                        },
+                        EnumVariant {
+                            name: "none".to_string(),
+                            tag_value: 1,
+                            variant_type: create_u32_primitive(),
+                        },
+                    ],
+                }),
+            },
+        ];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "NicheFillingConflictingTagRef")
+            .expect("Should find a conflicting-tag_ref error");
+        assert_eq!(error.violating_type, "NicheWithTag");
+    }
+
+    #[test]
+    fn test_niche_filling_unknown_dataful_variant_is_rejected() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "TypoEnum".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: Some(NicheFilling {
+                    dataful_variant: "no_such_variant".to_string(),
+                    niche_field_path: "value".to_string(),
+                    niche_start: 1,
+                    niche_count: 1,
+                }),
+                tag_type: None,
+                variants: vec![
+                    EnumVariant {
+                        name: "some_value".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "none".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "NicheFillingUnknownVariant")
+            .expect("Should find an unknown-dataful-variant error");
+        assert_eq!(error.violating_type, "TypoEnum");
+        assert_eq!(error.duplicate_name, "no_such_variant");
+    }
+
+    fn enum_with_tag_type_and_max_tag_value(
+        name: &str,
+        tag_type: Option<IntegralType>,
+        max_tag_value: u64,
+    ) -> TypeDef {
+        TypeDef {
+            name: name.to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: None,
+                tag_type,
+                variants: vec![
+                    EnumVariant {
+                        name: "first".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "last".to_string(),
+                        tag_value: max_tag_value,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }
+    }
+
+    #[test]
+    fn test_enum_tag_type_omitted_raises_no_sizing_diagnostic() {
+        let mut analyzer = DependencyAnalyzer::new();
+        let typedefs = vec![enum_with_tag_type_and_max_tag_value("Auto", None, 300)];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            !analysis
+                .validation_errors
+                .iter()
+                .any(|e| e.error_type.starts_with("EnumTagType")),
+            "omitting tag_type should not be flagged: {:?}",
+            analysis.validation_errors
+        );
+    }
+
+    #[test]
+    fn test_enum_tag_type_correctly_sized_raises_no_diagnostic() {
+        let mut analyzer = DependencyAnalyzer::new();
+        // Max tag value 300 needs at least a u16.
+        let typedefs = vec![enum_with_tag_type_and_max_tag_value(
+            "CorrectlySized",
+            Some(IntegralType::U16),
+            300,
+        )];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            !analysis
+                .validation_errors
+                .iter()
+                .any(|e| e.error_type.starts_with("EnumTagType")),
+            "a minimally-sized declared tag_type should not be flagged: {:?}",
+            analysis.validation_errors
+        );
+    }
+
+    #[test]
+    fn test_enum_tag_type_wider_than_necessary_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+        // Max tag value 1 fits in a u8, but the schema declares u32.
+        let typedefs = vec![enum_with_tag_type_and_max_tag_value(
+            "TooWide",
+            Some(IntegralType::U32),
+            1,
+        )];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "EnumTagTypeWiderThanNecessary")
+            .expect("Should find a wider-than-necessary tag_type error");
+        assert_eq!(error.violating_type, "TooWide");
+    }
+
+    #[test]
+    fn test_enum_tag_type_too_narrow_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+        // Max tag value 300 doesn't fit in a u8.
+        let typedefs = vec![enum_with_tag_type_and_max_tag_value(
+            "TooNarrow",
+            Some(IntegralType::U8),
+            300,
+        )];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let error = analysis
+            .validation_errors
+            .iter()
+            .find(|e| e.error_type == "EnumTagTypeTooNarrow")
+            .expect("Should find a too-narrow tag_type error");
+        assert_eq!(error.violating_type, "TooNarrow");
+    }
+
+    #[test]
+    fn test_optimize_struct_field_order_is_identity_when_not_opted_in() {
+        let analyzer = DependencyAnalyzer::new();
+
+        let struct_type = StructType {
+            container_attributes: Default::default(),
+            fields: vec![
+                StructField {
+                    name: "a".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "b".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+            ],
+        };
+
+        let (order, violations) = analyzer.optimize_struct_field_order("Plain", &struct_type, &[]);
+
+        assert_eq!(order, vec![0, 1]);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_optimize_struct_field_order_packs_by_descending_alignment() {
+        let analyzer = DependencyAnalyzer::new();
+
+        let struct_type = StructType {
+            container_attributes: ContainerAttributes {
+                packed: false,
+                aligned: 0,
+                comment: None,
+                optimize_layout: true,
+                tagged: None,
+            },
+            fields: vec![
+                StructField {
+                    name: "byte_a".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "count".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+                StructField {
+                    name: "payload".to_string(),
+                    field_type: TypeKind::Array(ArrayType {
+                        container_attributes: Default::default(),
+                        size: create_field_ref_expr(vec!["count"]),
+                        element_type: Box::new(create_u8_primitive()),
+                        jagged: false,
+                    }),
+                },
+                StructField {
+                    name: "byte_b".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "word".to_string(),
+                    field_type: TypeKind::Primitive(PrimitiveType::Integral(IntegralType::U16)),
+                },
+            ],
+        };
+
+        let (order, violations) =
+            analyzer.optimize_struct_field_order("Packet", &struct_type, &[]);
+
+        // "count" and "payload" are locked in place by their size dependency;
+        // the free fields ("byte_a", "byte_b", "word") fill the remaining
+        // slots (0, 3, 4) with the highest-alignment free field ("word")
+        // going first and equal-alignment fields keeping their relative order.
+        assert_eq!(order, vec![4, 1, 2, 0, 3]);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_optimize_struct_field_order_pins_discriminant_first() {
+        let analyzer = DependencyAnalyzer::new();
+
+        let struct_type = StructType {
+            container_attributes: ContainerAttributes {
+                packed: false,
+                aligned: 0,
+                comment: None,
+                optimize_layout: true,
+                tagged: None,
+            },
+            fields: vec![
+                StructField {
+                    name: "data".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+                StructField {
+                    name: "kind".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+            ],
+        };
+
+        let all_typedefs = vec![TypeDef {
+            name: "Choice".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_field_ref_expr(vec!["VariantPayload", "kind"]),
+                niche: None,
+                tag_type: None,
+                variants: vec![EnumVariant {
+                    name: "only".to_string(),
+                    tag_value: 0,
+                    variant_type: create_u8_primitive(),
+                }],
+            }),
+        }];
+
+        let (order, _) =
+            analyzer.optimize_struct_field_order("VariantPayload", &struct_type, &all_typedefs);
+
+        // "kind" (index 1) is the discriminant some enum's tag_ref resolves
+        // to, so it leads even though "data" has higher alignment.
+        assert_eq!(order.first(), Some(&1));
+    }
+
+    #[test]
+    fn test_struct_layout_sanity_passes_for_well_formed_struct() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Header".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "flag".to_string(),
+                        field_type: create_u8_primitive(),
+                    },
+                    StructField {
+                        name: "count".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis.layout_sanity_errors.is_empty());
+    }
+
+    #[test]
+    fn test_struct_layout_sanity_skips_fields_with_unknown_size() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Packet".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "count".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "payload".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["count"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        // "payload"'s size depends on a field reference rather than a
+        // literal, so its size can't be known statically -- the checker
+        // bails rather than asserting anything about it.
+        assert!(analysis.layout_sanity_errors.is_empty());
+    }
+
+    #[test]
+    fn test_size_discriminated_union_variant_exceeding_expected_size_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Frame".to_string(),
+            kind: TypeKind::SizeDiscriminatedUnion(SizeDiscriminatedUnionType {
+                container_attributes: Default::default(),
+                variants: vec![SizeDiscriminatedVariant {
+                    name: "small".to_string(),
+                    expected_size: 2,
+                    variant_type: create_u32_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert_eq!(analysis.layout_sanity_errors.len(), 1);
+        assert_eq!(
+            analysis.layout_sanity_errors[0].error_type,
+            "UnionVariantExceedsUnionSize"
+        );
+    }
+
+    #[test]
+    fn test_enum_tag_value_out_of_range_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Status".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: None,
+                tag_type: Some(IntegralType::U8),
+                variants: vec![EnumVariant {
+                    name: "huge".to_string(),
+                    tag_value: 300,
+                    variant_type: create_u8_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis
+            .layout_sanity_errors
+            .iter()
+            .any(|e| e.error_type == "EnumTagValueOutOfRange"));
+    }
+
+    #[test]
+    fn test_enum_niche_range_out_of_bounds_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "MaybeByte".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: Some(NicheFilling {
+                    dataful_variant: "some".to_string(),
+                    niche_field_path: "some.value".to_string(),
+                    niche_start: 250,
+                    niche_count: 10,
+                }),
+                tag_type: Some(IntegralType::U8),
+                variants: vec![EnumVariant {
+                    name: "some".to_string(),
+                    tag_value: 0,
+                    variant_type: create_u8_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis
+            .layout_sanity_errors
+            .iter()
+            .any(|e| e.error_type == "NicheRangeOutOfBounds"));
+    }
+
+    #[test]
+    fn test_enum_layout_sanity_passes_when_tag_type_omitted() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Simple".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: None,
+                tag_type: None,
+                variants: vec![EnumVariant {
+                    name: "only".to_string(),
+                    tag_value: 1,
+                    variant_type: create_u8_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis.layout_sanity_errors.is_empty());
+    }
+
+    #[test]
+    fn test_niche_offset_fitting_in_every_variant_has_no_placement_error() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "MaybeHandle".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: Some(NicheFilling {
+                    dataful_variant: "some_handle".to_string(),
+                    niche_field_path: "value".to_string(),
+                    niche_start: 0,
+                    niche_count: 1,
+                }),
+                tag_type: None,
+                variants: vec![
+                    EnumVariant {
+                        name: "some_handle".to_string(),
+                        tag_value: 0,
+                        variant_type: TypeKind::Struct(StructType {
+                            container_attributes: Default::default(),
+                            fields: vec![StructField {
+                                name: "value".to_string(),
+                                field_type: create_u32_primitive(),
+                            }],
+                        }),
+                    },
+                    EnumVariant {
+                        name: "none".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            !analysis
+                .layout_sanity_errors
+                .iter()
+                .any(|e| e.error_type == "NicheOffsetExceedsVariantStorage"),
+            "niche fits within every variant's storage, no placement error expected: {:?}",
+            analysis.layout_sanity_errors
+        );
+    }
+
+    #[test]
+    fn test_niche_offset_exceeding_dataless_variant_storage_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "MaybeWidePrefixed".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                niche: Some(NicheFilling {
+                    dataful_variant: "some_wide".to_string(),
+                    niche_field_path: "tag.value".to_string(),
+                    niche_start: 0,
+                    niche_count: 1,
+                }),
+                tag_type: None,
+                variants: vec![
+                    EnumVariant {
+                        name: "some_wide".to_string(),
+                        tag_value: 0,
+                        variant_type: TypeKind::Struct(StructType {
+                            container_attributes: Default::default(),
+                            fields: vec![
+                                StructField {
+                                    name: "prefix".to_string(),
+                                    field_type: create_u32_primitive(),
+                                },
+                                StructField {
+                                    name: "tag".to_string(),
+                                    field_type: TypeKind::Struct(StructType {
+                                        container_attributes: Default::default(),
+                                        fields: vec![StructField {
+                                            name: "value".to_string(),
+                                            field_type: create_u8_primitive(),
+                                        }],
+                                    }),
+                                },
+                            ],
+                        }),
+                    },
+                    EnumVariant {
+                        name: "none".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u8_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            analysis
+                .layout_sanity_errors
+                .iter()
+                .any(|e| e.error_type == "NicheOffsetExceedsVariantStorage"),
+            "niche at offset 4 can't fit in a 1-byte data-less variant: {:?}",
+            analysis.layout_sanity_errors
+        );
+    }
+
+    #[test]
+    fn test_minimal_integral_type_for_range_picks_smallest_unsigned_width() {
+        assert_eq!(minimal_integral_type_for_range(0, 0), IntegralType::U8);
+        assert_eq!(minimal_integral_type_for_range(0, 255), IntegralType::U8);
+        assert_eq!(minimal_integral_type_for_range(0, 256), IntegralType::U16);
+        assert_eq!(minimal_integral_type_for_range(0, 70_000), IntegralType::U32);
+        assert_eq!(
+            minimal_integral_type_for_range(0, u32::MAX as i128 + 1),
+            IntegralType::U64
+        );
+    }
+
+    #[test]
+    fn test_minimal_integral_type_for_range_picks_smallest_signed_width_when_negative() {
+        assert_eq!(minimal_integral_type_for_range(-1, 1), IntegralType::I8);
+        assert_eq!(minimal_integral_type_for_range(-200, 1), IntegralType::I16);
+        assert_eq!(
+            minimal_integral_type_for_range(i32::MIN as i128, 0),
+            IntegralType::I32
+        );
+    }
+
+    #[test]
+    fn test_minimal_integral_type_for_wraparound_range_uses_full_domain() {
+        // Discriminants clustered at both ends of a u32's range still need
+        // the full u32 width to represent, not something narrower.
+        let domain_max = u32::MAX as i128;
+        assert_eq!(
+            minimal_integral_type_for_wraparound_range(domain_max - 1, 1, domain_max),
+            IntegralType::U32
+        );
+    }
+
+    #[test]
+    fn test_enum_with_typeref_variants_of_equal_primitive_size_is_constant_size() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![
+            TypeDef {
+                name: "ErrorCode".to_string(),
+                kind: create_u32_primitive(),
+            },
+            TypeDef {
+                name: "StatusCode".to_string(),
+                kind: create_u32_primitive(),
+            },
+            TypeDef {
+                name: "Result".to_string(),
+                kind: TypeKind::Enum(EnumType {
+                    container_attributes: Default::default(),
+                    tag_ref: create_literal_expr(0),
+                    niche: None,
+                    tag_type: None,
+                    variants: vec![
+                        EnumVariant {
+                            name: "ok".to_string(),
+                            tag_value: 0,
+                            variant_type: create_type_ref("StatusCode"),
+                        },
+                        EnumVariant {
+                            name: "err".to_string(),
+                            tag_value: 1,
+                            variant_type: create_type_ref("ErrorCode"),
+                        },
+                    ],
+                }),
+            },
+        ];
+
+        let result_typedef = &typedefs[2];
+        assert!(analyzer.is_enum_with_constant_size_variants(result_typedef, &typedefs));
+    }
+
+    #[test]
+    fn test_struct_with_non_power_of_two_forced_alignment_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Weird".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: ContainerAttributes {
+                    aligned: 3,
+                    ..Default::default()
+                },
+                fields: vec![StructField {
+                    name: "value".to_string(),
+                    field_type: create_u8_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis
+            .layout_sanity_errors
+            .iter()
+            .any(|e| e.error_type == "AlignmentNotPowerOfTwo"));
+    }
+
+    #[test]
+    fn test_union_with_non_power_of_two_forced_alignment_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "WeirdUnion".to_string(),
+            kind: TypeKind::Union(UnionType {
+                container_attributes: ContainerAttributes {
+                    aligned: 6,
+                    ..Default::default()
+                },
+                variants: vec![UnionVariant {
+                    name: "value".to_string(),
+                    variant_type: create_u32_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(analysis
+            .layout_sanity_errors
+            .iter()
+            .any(|e| e.error_type == "AlignmentNotPowerOfTwo"));
+    }
+
+    #[test]
+    fn test_struct_with_naturally_aligned_fields_has_no_alignment_error() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "Plain".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![StructField {
+                    name: "value".to_string(),
+                    field_type: create_u32_primitive(),
+                }],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(
+            !analysis
+                .layout_sanity_errors
+                .iter()
+                .any(|e| e.error_type == "AlignmentNotPowerOfTwo"),
+            "naturally-aligned fields always yield a power-of-two alignment: {:?}",
+            analysis.layout_sanity_errors
+        );
+    }
+
+    #[test]
+    fn test_size_discriminated_union_not_in_trailing_position_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![
+            TypeDef {
+                name: "TokenAccountUnion".to_string(),
+                kind: create_size_discriminated_union(
+                    "TokenAccountUnion",
+                    vec![
+                        ("token_account", 165, create_u32_primitive()),
+                        ("token_mint", 82, create_u64_primitive()),
+                    ],
+                ),
+            },
+            TypeDef {
+                name: "MisorderedContainer".to_string(),
+                kind: TypeKind::Struct(StructType {
+                    container_attributes: Default::default(),
+                    fields: vec![
+                        StructField {
+                            name: "account_data".to_string(),
+                            field_type: create_type_ref("TokenAccountUnion"),
+                        },
+                        StructField {
+                            name: "trailer".to_string(),
+                            field_type: create_u32_primitive(),
+                        },
+                    ],
+                }),
+            },
+        ];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let violation = analysis
+            .layout_violations
+            .iter()
+            .find(|v| v.violating_type == "MisorderedContainer")
+            .expect("variable-size field before the struct's last field should be flagged");
+        assert!(violation.reason.contains("not the struct's final field"));
+        assert!(violation.dependency_chain.contains(&"trailer".to_string()));
+    }
+
+    #[test]
+    fn test_struct_with_non_constant_array_not_in_trailing_position_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "MisorderedTail".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "count".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "items".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["count"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                    StructField {
+                        name: "checksum".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let violation = analysis
+            .layout_violations
+            .iter()
+            .find(|v| v.violating_type == "MisorderedTail")
+            .expect("non-constant-size array before the struct's last field should be flagged");
+        assert!(violation.reason.contains("not the struct's final field"));
+        assert!(violation.dependency_chain.contains(&"checksum".to_string()));
+    }
+
+    #[test]
+    fn test_union_variant_with_variable_size_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "BadUnion".to_string(),
+            kind: TypeKind::Union(UnionType {
+                container_attributes: Default::default(),
+                variants: vec![
+                    UnionVariant {
+                        name: "fixed".to_string(),
+                        variant_type: create_u32_primitive(),
+                    },
+                    UnionVariant {
+                        name: "tail".to_string(),
+                        variant_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["count"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let violation = analysis
+            .layout_violations
+            .iter()
+            .find(|v| v.violating_type == "BadUnion")
+            .expect("a variable-size union variant should be flagged");
+        assert!(violation.reason.contains("variable size"));
+        assert!(violation.dependency_chain.contains(&"tail".to_string()));
+    }
+
+    #[test]
+    fn test_enum_non_terminal_variant_with_variable_size_is_flagged() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "BadEnum".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                variants: vec![
+                    EnumVariant {
+                        name: "dynamic".to_string(),
+                        tag_value: 0,
+                        variant_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["count"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                    EnumVariant {
+                        name: "fixed".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+                niche: None,
+                tag_type: None,
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        let violation = analysis
+            .layout_violations
+            .iter()
+            .find(|v| v.violating_type == "BadEnum")
+            .expect("a non-terminal variable-size enum variant should be flagged");
+        assert!(violation.reason.contains("not the enum's final variant"));
+        assert!(violation.dependency_chain.contains(&"fixed".to_string()));
+    }
+
+    #[test]
+    fn test_enum_terminal_variable_size_variant_has_no_violation() {
+        let mut analyzer = DependencyAnalyzer::new();
+
+        let typedefs = vec![TypeDef {
+            name: "GoodEnum".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                variants: vec![
+                    EnumVariant {
+                        name: "fixed".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "dynamic".to_string(),
+                        tag_value: 1,
+                        variant_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["count"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+                niche: None,
+                tag_type: None,
+            }),
+        }];
+
+        let analysis = analyzer.analyze_multiple_typedefs(&typedefs);
+
+        assert!(!analysis
+            .layout_violations
+            .iter()
+            .any(|v| v.violating_type == "GoodEnum"));
+    }
+
+    #[test]
+    fn test_struct_padding_bytes_saved_reports_reduction_from_reordering() {
+        let analyzer = DependencyAnalyzer::new();
+
+        // Declared order u8, u32, u8, u32 pads each u8 up to a 4-byte
+        // boundary (16 bytes total); sorted by descending alignment
+        // (u32, u32, u8, u8) packs down to 12 bytes.
+        let struct_type = StructType {
+            container_attributes: ContainerAttributes {
+                packed: false,
+                aligned: 0,
+                comment: None,
+                optimize_layout: true,
+                tagged: None,
+            },
+            fields: vec![
+                StructField {
+                    name: "a".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "b".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+                StructField {
+                    name: "c".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "d".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+            ],
+        };
+
+        let (order, _) = analyzer.optimize_struct_field_order("Packed4", &struct_type, &[]);
+        let saved = analyzer
+            .struct_padding_bytes_saved(&struct_type, &order, &[])
+            .expect("every field here has a statically computable size");
+
+        assert_eq!(saved, 4);
+    }
+
+    #[test]
+    fn test_struct_padding_bytes_saved_is_none_for_non_constant_field_size() {
+        let analyzer = DependencyAnalyzer::new();
+
+        let struct_type = StructType {
+            container_attributes: Default::default(),
+            fields: vec![
+                StructField {
+                    name: "count".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+                StructField {
+                    name: "payload".to_string(),
+                    field_type: TypeKind::Array(ArrayType {
+                        container_attributes: Default::default(),
+                        size: create_field_ref_expr(vec!["count"]),
+                        element_type: Box::new(create_u8_primitive()),
+                        jagged: false,
+                    }),
+                },
+            ],
+        };
+
+        let order: Vec<usize> = (0..struct_type.fields.len()).collect();
+        assert!(analyzer
+            .struct_padding_bytes_saved(&struct_type, &order, &[])
+            .is_none());
+    }
+
+    #[test]
+    fn test_layout_calculator_computes_struct_size_with_padding() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let struct_type = TypeKind::Struct(StructType {
+            container_attributes: Default::default(),
+            fields: vec![
+                StructField {
+                    name: "flag".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "value".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+            ],
+        });
+
+        let layout = calculator.compute(&struct_type, &[]).unwrap();
+        assert_eq!(layout.align, 4);
+        assert_eq!(layout.size, 8); // u8 + 3 padding + u32
+    }
+
+    #[test]
+    fn test_layout_calculator_computes_packed_struct_with_no_padding() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let struct_type = TypeKind::Struct(StructType {
+            container_attributes: ContainerAttributes {
+                packed: true,
+                ..Default::default()
+            },
+            fields: vec![
+                StructField {
+                    name: "flag".to_string(),
+                    field_type: create_u8_primitive(),
+                },
+                StructField {
+                    name: "value".to_string(),
+                    field_type: create_u32_primitive(),
+                },
+            ],
+        });
+
+        let layout = calculator.compute(&struct_type, &[]).unwrap();
+        assert_eq!(layout.size, 5);
+        assert_eq!(layout.align, 1);
+    }
+
+    #[test]
+    fn test_layout_calculator_computes_union_as_max_size_and_align() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let union_type = TypeKind::Union(UnionType {
+            container_attributes: Default::default(),
+            variants: vec![
+                UnionVariant {
+                    name: "small".to_string(),
+                    variant_type: create_u8_primitive(),
+                },
+                UnionVariant {
+                    name: "large".to_string(),
+                    variant_type: create_u32_primitive(),
+                },
+            ],
+        });
+
+        let layout = calculator.compute(&union_type, &[]).unwrap();
+        assert_eq!(layout.size, 4);
+        assert_eq!(layout.align, 4);
+    }
+
+    #[test]
+    fn test_layout_calculator_computes_array_of_constant_literal_size() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let array_type = TypeKind::Array(ArrayType {
+            container_attributes: Default::default(),
+            size: create_literal_expr(5),
+            element_type: Box::new(create_u32_primitive()),
+            jagged: false,
+        });
+
+        let layout = calculator.compute(&array_type, &[]).unwrap();
+        assert_eq!(layout.size, 20);
+        assert_eq!(layout.align, 4);
+    }
+
+    #[test]
+    fn test_layout_calculator_returns_none_for_array_with_field_ref_size() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let array_type = TypeKind::Array(ArrayType {
+            container_attributes: Default::default(),
+            size: create_field_ref_expr(vec!["count"]),
+            element_type: Box::new(create_u32_primitive()),
+            jagged: false,
+        });
+
+        assert!(calculator.compute(&array_type, &[]).is_none());
+    }
+
+    #[test]
+    fn test_layout_calculator_sizes_single_variant_enum_with_u8_tag() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let enum_type = TypeKind::Enum(EnumType {
+            container_attributes: Default::default(),
+            tag_ref: create_literal_expr(0),
+            variants: vec![EnumVariant {
+                name: "a".to_string(),
+                tag_value: 0,
+                variant_type: create_u8_primitive(),
+            }],
+            niche: None,
+            tag_type: None,
+        });
+
+        // u8 tag at offset 0, u8 payload at offset 1.
+        let layout = calculator.compute(&enum_type, &[]).unwrap();
+        assert_eq!(layout.size, 2);
+        assert_eq!(layout.align, 1);
+    }
+
+    #[test]
+    fn test_layout_calculator_resolves_type_ref_through_typedefs() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let typedefs = vec![TypeDef {
+            name: "Base".to_string(),
+            kind: create_u32_primitive(),
+        }];
+
+        let layout = calculator
+            .compute(&create_type_ref("Base"), &typedefs)
+            .unwrap();
+        assert_eq!(layout.size, 4);
+        assert_eq!(layout.align, 4);
+    }
+
+    #[test]
+    fn test_layout_calculator_honors_primitive_align_override() {
+        let mut primitive_aligns = HashMap::new();
+        primitive_aligns.insert(IntegralType::U64, 4);
+        let data_layout = TargetDataLayout {
+            primitive_aligns,
+            ..Default::default()
+        };
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let layout = calculator.compute(&create_u64_primitive(), &[]).unwrap();
+        assert_eq!(layout.size, 8);
+        assert_eq!(layout.align, 4);
+    }
+
+    #[test]
+    fn test_layout_calculator_sizes_tagged_enum_with_inferred_minimal_tag() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let enum_type = TypeKind::Enum(EnumType {
+            container_attributes: Default::default(),
+            tag_ref: create_literal_expr(0),
+            variants: vec![
+                EnumVariant {
+                    name: "small".to_string(),
+                    tag_value: 0,
+                    variant_type: create_u8_primitive(),
+                },
+                EnumVariant {
+                    name: "large".to_string(),
+                    tag_value: 1,
+                    variant_type: create_u32_primitive(),
+                },
+            ],
+            niche: None,
+            tag_type: None,
+        });
+
+        // Two variants fit in a u8 tag; payload max size/align is u32's (4, 4).
+        // Tag occupies offset 0..1, padded to 4, payload at 4..8, total 8.
+        let layout = calculator.compute(&enum_type, &[]).unwrap();
+        assert_eq!(layout.align, 4);
+        assert_eq!(layout.size, 8);
+    }
+
+    #[test]
+    fn test_layout_calculator_sizes_tagged_enum_using_declared_tag_type() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let enum_type = TypeKind::Enum(EnumType {
+            container_attributes: Default::default(),
+            tag_ref: create_literal_expr(0),
+            variants: vec![EnumVariant {
+                name: "only".to_string(),
+                tag_value: 0,
+                variant_type: create_u8_primitive(),
+            }],
+            niche: None,
+            tag_type: Some(IntegralType::U32),
+        });
+
+        // Declared tag_type widens the tag to a u32 even though a u8 would
+        // have been the minimal inferred width.
+        let layout = calculator.compute(&enum_type, &[]).unwrap();
+        assert_eq!(layout.align, 4);
+        assert_eq!(layout.size, 8);
+    }
+
+    #[test]
+    fn test_layout_calculator_sizes_niche_filling_enum_as_dataful_variant_only() {
+        let data_layout = TargetDataLayout::default();
+        let calculator = LayoutCalculator::new(&data_layout);
+
+        let enum_type = TypeKind::Enum(EnumType {
+            container_attributes: Default::default(),
+            tag_ref: create_literal_expr(0),
+            variants: vec![
+                EnumVariant {
+                    name: "present".to_string(),
+                    tag_value: 0,
+                    variant_type: create_u32_primitive(),
+                },
+                EnumVariant {
+                    name: "absent".to_string(),
+                    tag_value: 1,
+                    variant_type: create_u32_primitive(),
+                },
+            ],
+            niche: Some(NicheFilling {
+                dataful_variant: "present".to_string(),
+                niche_field_path: "present".to_string(),
+                niche_start: 0,
+                niche_count: 1,
+            }),
+            tag_type: None,
+        });
+
+        // No separate tag bytes: the niche-filling enum's size is exactly
+        // its dataful variant's size.
+        let layout = calculator.compute(&enum_type, &[]).unwrap();
+        assert_eq!(layout.size, 4);
+        assert_eq!(layout.align, 4);
+    }
+
+    #[test]
+    fn test_report_type_size_for_struct_shows_padding_between_fields() {
+        let data_layout = TargetDataLayout::default();
+
+        let typedef = TypeDef {
+            name: "Mixed".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "flag".to_string(),
+                        field_type: create_u8_primitive(),
+                    },
+                    StructField {
+                        name: "value".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        };
+
+        let report = report_type_size(&typedef, &[], &data_layout).unwrap();
+        assert_eq!(report.size_kind, SizeKind::Exact);
+        assert_eq!(report.overall_size, 8);
+        assert_eq!(report.align, 4);
+        assert!(!report.packed);
+
+        let variant = &report.variants[0];
+        assert_eq!(variant.fields[0].padding_before, 0);
+        assert_eq!(variant.fields[1].offset, 4);
+        assert_eq!(variant.fields[1].padding_before, 3);
+    }
+
+    #[test]
+    fn test_report_type_size_for_struct_with_trailing_variable_field_is_min() {
+        let data_layout = TargetDataLayout::default();
+
+        let typedef = TypeDef {
+            name: "Jagged".to_string(),
+            kind: TypeKind::Struct(StructType {
+                container_attributes: Default::default(),
+                fields: vec![
+                    StructField {
+                        name: "count".to_string(),
+                        field_type: create_u32_primitive(),
+                    },
+                    StructField {
+                        name: "items".to_string(),
+                        field_type: TypeKind::Array(ArrayType {
+                            container_attributes: Default::default(),
+                            size: create_field_ref_expr(vec!["count"]),
+                            element_type: Box::new(create_u8_primitive()),
+                            jagged: false,
+                        }),
+                    },
+                ],
+            }),
+        };
+
+        let report = report_type_size(&typedef, &[], &data_layout).unwrap();
+        assert_eq!(report.size_kind, SizeKind::Min);
+        assert_eq!(report.overall_size, 4); // just the fixed "count" prefix
+        assert_eq!(report.variants[0].fields.last().unwrap().name, "items");
+    }
+
+    #[test]
+    fn test_report_type_size_for_union_reports_each_variant() {
+        let data_layout = TargetDataLayout::default();
+
+        let typedef = TypeDef {
+            name: "Either".to_string(),
+            kind: TypeKind::Union(UnionType {
+                container_attributes: Default::default(),
+                variants: vec![
+                    UnionVariant {
+                        name: "small".to_string(),
+                        variant_type: create_u8_primitive(),
+                    },
+                    UnionVariant {
+                        name: "large".to_string(),
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+            }),
+        };
+
+        let report = report_type_size(&typedef, &[], &data_layout).unwrap();
+        assert_eq!(report.overall_size, 4);
+        assert_eq!(report.align, 4);
+        assert_eq!(report.variants.len(), 2);
+        assert_eq!(report.variants[1].size, 4);
+    }
+
+    #[test]
+    fn test_report_type_size_for_tagged_enum_includes_discriminant_size() {
+        let data_layout = TargetDataLayout::default();
+
+        let typedef = TypeDef {
+            name: "Shape".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                variants: vec![
+                    EnumVariant {
+                        name: "circle".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "point".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u8_primitive(),
+                    },
+                ],
+                niche: None,
+                tag_type: None,
+            }),
+        };
+
+        let report = report_type_size(&typedef, &[], &data_layout).unwrap();
+        assert_eq!(report.discriminant_size, Some(1)); // two variants fit a u8 tag
+        assert_eq!(report.overall_size, 8); // u8 tag padded to 4, then u32 payload
+        assert_eq!(report.variants.len(), 2);
+    }
+
+    #[test]
+    fn test_report_type_size_for_niche_filling_enum_has_zero_discriminant_size() {
+        let data_layout = TargetDataLayout::default();
+
+        let typedef = TypeDef {
+            name: "MaybeU32".to_string(),
+            kind: TypeKind::Enum(EnumType {
+                container_attributes: Default::default(),
+                tag_ref: create_literal_expr(0),
+                variants: vec![
+                    EnumVariant {
+                        name: "present".to_string(),
+                        tag_value: 0,
+                        variant_type: create_u32_primitive(),
+                    },
+                    EnumVariant {
+                        name: "absent".to_string(),
+                        tag_value: 1,
+                        variant_type: create_u32_primitive(),
+                    },
+                ],
+                niche: Some(NicheFilling {
+                    dataful_variant: "present".to_string(),
+                    niche_field_path: "present".to_string(),
+                    niche_start: 0,
+                    niche_count: 1,
+                }),
+                tag_type: None,
+            }),
+        };
+
+        let report = report_type_size(&typedef, &[], &data_layout).unwrap();
+        assert_eq!(report.discriminant_size, Some(0));
+        assert_eq!(report.overall_size, 4);
+        assert_eq!(report.size_kind, SizeKind::Exact);
+    }
+
+    #[test]
+    fn test_report_type_size_for_size_discriminated_union_is_min_with_declared_sizes() {
+        let data_layout = TargetDataLayout::default();
+
+        let typedef = TypeDef {
+            name: "TokenAccountUnion".to_string(),
+            kind: create_size_discriminated_union(
+                "TokenAccountUnion",
+                vec![
+                    ("token_account", 165, create_u32_primitive()),
+                    ("token_mint", 82, create_u64_primitive()),
+                ],
+            ),
+        };
+
+        let report = report_type_size(&typedef, &[], &data_layout).unwrap();
+        assert_eq!(report.size_kind, SizeKind::Min);
+        assert_eq!(report.overall_size, 165);
+        assert_eq!(report.discriminant_size, None);
+        assert_eq!(report.variants.len(), 2);
+    }
+
+    #[test]
+    fn test_report_type_size_returns_none_for_primitive_and_type_ref() {
+        let data_layout = TargetDataLayout::default();
+
+        let primitive_typedef = TypeDef {
+            name: "Raw".to_string(),
+            kind: create_u32_primitive(),
+        };
+        let type_ref_typedef = TypeDef {
+            name: "Alias".to_string(),
+            kind: create_type_ref("Raw"),
+        };
+
+        assert!(report_type_size(&primitive_typedef, &[], &data_layout).is_none());
+        assert!(report_type_size(&type_ref_typedef, &[], &data_layout).is_none());
+    }
 }
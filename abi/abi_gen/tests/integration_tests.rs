@@ -49,6 +49,8 @@ fn test_complete_abi_analysis_pipeline() {
                 tag_ref: ExprKind::Sizeof(SizeofExpr {
                     type_name: "BaseType".to_string(),
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![EnumVariant {
                     name: "Variant1".to_string(),
                     tag_value: 1,
@@ -156,6 +158,8 @@ fn test_invalid_enum_tag_layout_cycle_detection() {
                 tag_ref: ExprKind::FieldRef(FieldRefExpr {
                     path: vec!["Container".to_string(), "referenced_field".to_string()],
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![EnumVariant {
                     name: "Variant1".to_string(),
                     tag_value: 1,
@@ -400,6 +404,7 @@ fn test_packed_vs_aligned_struct_analysis() {
                     packed: true,
                     aligned: 0,
                     comment: None,
+                    optimize_layout: false,
                 },
                 fields: vec![
                     StructField {
@@ -421,6 +426,7 @@ fn test_packed_vs_aligned_struct_analysis() {
                     packed: false,
                     aligned: 16,
                     comment: None,
+                    optimize_layout: false,
                 },
                 fields: vec![StructField {
                     name: "byte_field".to_string(),
@@ -578,6 +584,8 @@ fn test_comprehensive_error_reporting() {
                         "reference_field".to_string(),
                     ],
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![EnumVariant {
                     name: "Variant1".to_string(),
                     tag_value: 1,
@@ -648,6 +656,7 @@ fn test_real_world_scenario_token_account() {
                 packed: true,
                 aligned: 1,
                 comment: Some("Token account structure".to_string()),
+                optimize_layout: false,
             },
             fields: vec![
                 StructField {
@@ -781,6 +790,8 @@ fn test_comprehensive_validation_integration() {
                 tag_ref: ExprKind::FieldRef(FieldRefExpr {
                     path: vec!["BadStruct".to_string(), "reference_field".to_string()],
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "duplicate".to_string(),
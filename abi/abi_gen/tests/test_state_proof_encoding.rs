@@ -41,6 +41,7 @@ fn test_state_proof_encoding() {
                     packed: true,
                     aligned: 0,
                     comment: Some("State proof header".to_string()),
+                    optimize_layout: false,
                 },
                 fields: vec![
                     StructField {
@@ -65,6 +66,7 @@ fn test_state_proof_encoding() {
                     packed: true,
                     aligned: 0,
                     comment: Some("Proof body for account creation".to_string()),
+                    optimize_layout: false,
                 },
                 fields: vec![
                     StructField {
@@ -109,6 +111,7 @@ fn test_state_proof_encoding() {
                     packed: true,
                     aligned: 0,
                     comment: Some("Proof body for existing account".to_string()),
+                    optimize_layout: false,
                 },
                 fields: vec![StructField {
                     name: "sibling_hashes".to_string(),
@@ -135,6 +138,7 @@ fn test_state_proof_encoding() {
                     packed: true,
                     aligned: 0,
                     comment: Some("Proof body for account update".to_string()),
+                    optimize_layout: false,
                 },
                 fields: vec![
                     StructField {
@@ -174,6 +178,8 @@ fn test_state_proof_encoding() {
                     })),
                     right: Box::new(ExprKind::Literal(LiteralExpr::U64(62))),
                 }),
+                niche: None,
+                tag_type: None,
                 variants: vec![
                     EnumVariant {
                         name: "creation".to_string(),
@@ -210,6 +216,7 @@ fn test_state_proof_encoding() {
                     packed: true,
                     aligned: 0,
                     comment: Some("State proof structure matching tn_state_proof_t".to_string()),
+                    optimize_layout: false,
                 },
                 fields: vec![
                     StructField {
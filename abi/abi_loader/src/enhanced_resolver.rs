@@ -7,7 +7,7 @@
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
-use crate::fetcher::{CompositeFetcher, FetchContext, FetchError, FetcherConfig};
+use crate::fetcher::{CompositeFetcher, FetchContext, FetchError, FetchedFormat, FetcherConfig};
 use crate::file::{AbiFile, ImportSource};
 use crate::package::{PackageId, ResolutionResult, ResolveError, ResolvedPackage};
 
@@ -176,7 +176,11 @@ impl EnhancedImportResolver {
         })?;
 
         if self.verbose {
-            println!("[~] Fetched: {}", fetch_result.canonical_location);
+            if fetch_result.from_cache {
+                println!("[~] Fetched (cached): {}", fetch_result.canonical_location);
+            } else {
+                println!("[~] Fetched: {}", fetch_result.canonical_location);
+            }
         }
 
         /* Check for cycle using canonical location */
@@ -199,12 +203,24 @@ impl EnhancedImportResolver {
             return Ok(pkg_id.clone());
         }
 
-        /* Parse the ABI file */
-        let abi_file: AbiFile =
-            serde_yml::from_str(&fetch_result.content).map_err(|e| ResolveError::ParseError {
-                location: fetch_result.canonical_location.clone(),
-                message: e.to_string(),
-            })?;
+        /* Parse the ABI file using whichever format the fetcher detected --
+        a JSON-served import must go through serde_json since serde_yml
+        doesn't accept e.g. JSON's unquoted-key-free, trailing-comma-free
+        object syntax for every edge case YAML allows */
+        let abi_file: AbiFile = match fetch_result.format {
+            FetchedFormat::Json => {
+                serde_json::from_str(&fetch_result.content).map_err(|e| ResolveError::ParseError {
+                    location: fetch_result.canonical_location.clone(),
+                    message: e.to_string(),
+                })?
+            }
+            FetchedFormat::Yaml | FetchedFormat::Unknown => {
+                serde_yml::from_str(&fetch_result.content).map_err(|e| ResolveError::ParseError {
+                    location: fetch_result.canonical_location.clone(),
+                    message: e.to_string(),
+                })?
+            }
+        };
 
         let pkg_id = PackageId::from_abi_file(&abi_file);
 
@@ -247,6 +263,7 @@ impl EnhancedImportResolver {
             abi_file,
             dependencies,
             is_remote: fetch_result.is_remote,
+            digest: fetch_result.computed_digest.clone(),
         };
 
         /* Mark as fully resolved */
@@ -0,0 +1,237 @@
+//! Async HTTP/HTTPS Import Fetcher
+//!
+//! Non-blocking counterpart of [`super::http::HttpFetcher`], built on
+//! `reqwest::Client` instead of `reqwest::blocking::Client` so it can be
+//! driven from inside an application's own Tokio runtime alongside its
+//! other I/O. Shares the redirect policy, conditional-caching, and JSON
+//! sidecar logic with the blocking fetcher via `super::http`.
+
+use crate::fetcher::http::{self, CacheEntry};
+use crate::fetcher::{AsyncImportFetcher, FetchContext, FetchError, FetchResult, HttpFetcherConfig};
+use crate::file::ImportSource;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::time::Duration;
+
+/* Async HTTP/HTTPS URL fetcher */
+pub struct AsyncHttpFetcher {
+    client: reqwest::Client,
+    max_response_bytes: u64,
+    cache_dir: Option<PathBuf>,
+}
+
+impl AsyncHttpFetcher {
+    /* Create a new async HTTP fetcher with default configuration */
+    pub fn new() -> Result<Self, FetchError> {
+        Self::with_config(HttpFetcherConfig::default())
+    }
+
+    /* Create an async fetcher from an explicit [`HttpFetcherConfig`]
+    (redirect limit, redirect allow-list, response size cap) */
+    pub fn with_config(config: HttpFetcherConfig) -> Result<Self, FetchError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .user_agent("thru-abi-loader/1.0")
+            .redirect(http::redirect_policy(
+                config.max_redirects,
+                config.allowed_redirect_hosts.clone(),
+            ))
+            .build()
+            .map_err(|e| FetchError::Http {
+                status: 0,
+                message: format!("Failed to create HTTP client: {}", e),
+            })?;
+
+        Ok(Self {
+            client,
+            max_response_bytes: config.max_response_bytes,
+            cache_dir: None,
+        })
+    }
+
+    /* Attach an on-disk cache directory to an already-built fetcher */
+    pub fn with_cache_dir(mut self, dir: PathBuf) -> Self {
+        self.cache_dir = Some(dir);
+        self
+    }
+
+    async fn fetch_async(&self, source: &ImportSource) -> Result<FetchResult, FetchError> {
+        let ImportSource::Http { url, integrity } = source else {
+            return Err(FetchError::UnsupportedSource(
+                "AsyncHttpFetcher only handles Http imports".to_string(),
+            ));
+        };
+
+        /* Soft-miss: a missing/unreadable sidecar or body just means no
+        conditional headers get attached below, falling back to a normal
+        unconditional fetch. The sidecar lookup is a quick local read, not
+        worth spawn_blocking-ing off the executor. */
+        let cached = self
+            .cache_dir
+            .as_deref()
+            .and_then(|dir| CacheEntry::load(dir, url));
+
+        let mut request = self.client.get(url);
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified.as_str());
+            }
+        }
+
+        let response = request.send().await.map_err(|e| {
+            if e.is_redirect() {
+                FetchError::Http {
+                    status: 0,
+                    message: format!("Redirect blocked by policy for {}: {}", url, e),
+                }
+            } else {
+                FetchError::Http {
+                    status: 0,
+                    message: format!("Request failed: {}", e),
+                }
+            }
+        })?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            let dir = self.cache_dir.as_deref().expect("304 implies a cache dir");
+            let content = std::fs::read_to_string(CacheEntry::body_path(dir, url))?;
+            let computed_digest = http::verify_integrity(&content, integrity.as_deref())?;
+            /* The original Content-Type isn't persisted in the cache sidecar,
+            so a cache hit falls back to sniffing the URL and body */
+            let format = http::detect_format(url, None, &content);
+            return Ok(FetchResult {
+                content,
+                canonical_location: url.clone(),
+                is_remote: true,
+                resolved_path: None,
+                from_cache: true,
+                computed_digest: Some(computed_digest),
+                format,
+            });
+        }
+
+        if !status.is_success() {
+            return Err(FetchError::Http {
+                status: status.as_u16(),
+                message: format!("HTTP {} for {}", status, url),
+            });
+        }
+
+        let etag = http::header_value(response.headers(), reqwest::header::ETAG);
+        let last_modified = http::header_value(response.headers(), reqwest::header::LAST_MODIFIED);
+        let content_type = http::header_value(response.headers(), reqwest::header::CONTENT_TYPE);
+
+        /* Bound the body read the same way the blocking fetcher does: a
+        byte beyond the cap means the response is rejected rather than
+        buffered in full */
+        let mut body = Vec::new();
+        let mut stream = response.bytes_stream();
+        {
+            use futures::StreamExt;
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk.map_err(|e| FetchError::Http {
+                    status: 0,
+                    message: format!("Failed to read response body: {}", e),
+                })?;
+                body.extend_from_slice(&chunk);
+                if body.len() as u64 > self.max_response_bytes {
+                    return Err(FetchError::ResponseTooLarge {
+                        url: url.clone(),
+                        limit: self.max_response_bytes,
+                    });
+                }
+            }
+        }
+
+        let content = String::from_utf8(body)
+            .map_err(|e| FetchError::Parse(format!("Response body is not valid UTF-8: {}", e)))?;
+
+        let computed_digest = http::verify_integrity(&content, integrity.as_deref())?;
+        let format = http::detect_format(url, content_type.as_deref(), &content);
+
+        if let Some(dir) = &self.cache_dir {
+            /* Cache writes are best-effort: an IO error here can never
+            break resolution, only cost us a cache hit next time */
+            let _ = CacheEntry {
+                etag,
+                last_modified,
+                fetched_at: http::unix_now(),
+            }
+            .store(dir, url, &content);
+        }
+
+        Ok(FetchResult {
+            content,
+            canonical_location: url.clone(),
+            is_remote: true,
+            resolved_path: None,
+            from_cache: false,
+            computed_digest: Some(computed_digest),
+            format,
+        })
+    }
+}
+
+impl AsyncImportFetcher for AsyncHttpFetcher {
+    fn handles(&self, source: &ImportSource) -> bool {
+        matches!(source, ImportSource::Http { .. })
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        source: &'a ImportSource,
+        _ctx: &'a FetchContext,
+    ) -> Pin<Box<dyn Future<Output = Result<FetchResult, FetchError>> + Send + 'a>> {
+        Box::pin(self.fetch_async(source))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_async_http_fetcher_handles() {
+        let fetcher = AsyncHttpFetcher::new().unwrap();
+
+        let http_import = ImportSource::Http {
+            url: "https://example.com/types.abi.yaml".to_string(),
+            integrity: None,
+        };
+        let path_import = ImportSource::Path {
+            path: "local.abi.yaml".to_string(),
+        };
+
+        assert!(fetcher.handles(&http_import));
+        assert!(!fetcher.handles(&path_import));
+    }
+
+    /* Integration test - requires network access */
+    #[tokio::test]
+    #[ignore] /* Run with: cargo test -- --ignored */
+    async fn test_async_http_fetcher_real_request() {
+        let fetcher = AsyncHttpFetcher::new().unwrap();
+        let source = ImportSource::Http {
+            url: "https://httpbin.org/get".to_string(),
+            integrity: None,
+        };
+        let ctx = FetchContext {
+            base_path: None,
+            parent_is_remote: false,
+            include_dirs: vec![],
+        };
+
+        let result = fetcher.fetch(&source, &ctx).await;
+        assert!(result.is_ok());
+
+        let fetch_result = result.unwrap();
+        assert!(fetch_result.is_remote);
+        assert!(fetch_result.content.contains("httpbin"));
+    }
+}
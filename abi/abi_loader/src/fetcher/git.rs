@@ -2,7 +2,7 @@
 //!
 //! Fetches ABI files from git repositories with support for branch, tag, and commit pinning.
 
-use crate::fetcher::{FetchContext, FetchError, FetchResult, GitFetcherConfig, ImportFetcher};
+use crate::fetcher::{FetchContext, FetchError, FetchResult, FetchedFormat, GitFetcherConfig, ImportFetcher};
 use crate::file::ImportSource;
 use git2::{Cred, FetchOptions, RemoteCallbacks, Repository};
 use sha2::{Digest, Sha256};
@@ -228,6 +228,9 @@ impl ImportFetcher for GitFetcher {
             canonical_location,
             is_remote: true,
             resolved_path: None,
+            from_cache: false,
+            computed_digest: None,
+            format: FetchedFormat::Yaml,
         })
     }
 }
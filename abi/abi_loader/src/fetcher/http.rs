@@ -1,61 +1,190 @@
 //! HTTP/HTTPS Import Fetcher
 //!
-//! Fetches ABI files from HTTP/HTTPS URLs.
+//! Fetches ABI files from HTTP/HTTPS URLs, optionally through an on-disk
+//! cache that validates with conditional requests (`If-None-Match` /
+//! `If-Modified-Since`) instead of re-downloading unchanged content on
+//! every build.
 
-use crate::fetcher::{FetchContext, FetchError, FetchResult, ImportFetcher};
+use crate::fetcher::{FetchContext, FetchError, FetchResult, FetchedFormat, HttpFetcherConfig, ImportFetcher};
 use crate::file::ImportSource;
-use std::time::Duration;
+use base64::engine::general_purpose;
+use base64::Engine as _;
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /* HTTP/HTTPS URL fetcher */
 pub struct HttpFetcher {
     client: reqwest::blocking::Client,
+    max_response_bytes: u64,
+    cache_dir: Option<PathBuf>,
 }
 
 impl HttpFetcher {
     /* Create a new HTTP fetcher with default configuration */
     pub fn new() -> Result<Self, FetchError> {
-        Self::with_timeout(30)
+        Self::with_config(HttpFetcherConfig::default())
     }
 
-    /* Create with custom timeout */
+    /* Create with custom timeout, otherwise default configuration */
     pub fn with_timeout(timeout_seconds: u64) -> Result<Self, FetchError> {
-        let client = reqwest::blocking::Client::builder()
-            .timeout(Duration::from_secs(timeout_seconds))
-            .user_agent("thru-abi-loader/1.0")
-            .build()
-            .map_err(|e| FetchError::Http {
-                status: 0,
-                message: format!("Failed to create HTTP client: {}", e),
-            })?;
+        Self::with_config(HttpFetcherConfig {
+            timeout_seconds,
+            ..HttpFetcherConfig::default()
+        })
+    }
+
+    /* Create a fetcher that caches responses under `dir`, keyed by URL, and
+    validates them with conditional requests instead of re-downloading
+    unchanged content on every build */
+    pub fn with_cache(dir: PathBuf) -> Result<Self, FetchError> {
+        Ok(Self::with_config(HttpFetcherConfig::default())?.with_cache_dir(dir))
+    }
+
+    /* Create a fetcher from an explicit [`HttpFetcherConfig`] (redirect
+    limit, redirect allow-list, response size cap) */
+    pub fn with_config(config: HttpFetcherConfig) -> Result<Self, FetchError> {
+        Ok(Self {
+            client: build_client(&config)?,
+            max_response_bytes: config.max_response_bytes,
+            cache_dir: None,
+        })
+    }
 
-        Ok(Self { client })
+    /* Attach an on-disk cache directory to an already-built fetcher */
+    pub fn with_cache_dir(mut self, dir: PathBuf) -> Self {
+        self.cache_dir = Some(dir);
+        self
     }
 }
 
+fn build_client(config: &HttpFetcherConfig) -> Result<reqwest::blocking::Client, FetchError> {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(config.timeout_seconds))
+        .user_agent("thru-abi-loader/1.0")
+        .redirect(redirect_policy(
+            config.max_redirects,
+            config.allowed_redirect_hosts.clone(),
+        ))
+        .build()
+        .map_err(|e| FetchError::Http {
+            status: 0,
+            message: format!("Failed to create HTTP client: {}", e),
+        })
+}
+
+/* Build a redirect policy that caps the number of hops and, when
+`allowed_hosts` is set, refuses to follow a redirect off the original
+request's scheme/host unless the new host is in the allow-list -- e.g. an
+`https://` import silently downgraded to `http://`, or redirected to an
+unexpected (possibly internal) host. */
+pub(crate) fn redirect_policy(
+    max_redirects: usize,
+    allowed_hosts: Option<Vec<String>>,
+) -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(move |attempt| {
+        if attempt.previous().len() > max_redirects {
+            return attempt.error(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("exceeded max redirect count ({})", max_redirects),
+            ));
+        }
+
+        if let Some(hosts) = &allowed_hosts {
+            let origin = attempt.previous().first();
+            let target = attempt.url();
+
+            let scheme_ok = origin
+                .map(|o| o.scheme() == target.scheme())
+                .unwrap_or(true);
+            let host_ok = origin.and_then(|o| o.host_str()) == target.host_str()
+                || target
+                    .host_str()
+                    .map(|h| hosts.iter().any(|allowed| allowed == h))
+                    .unwrap_or(false);
+
+            if !scheme_ok || !host_ok {
+                return attempt.error(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("redirect to '{}' not allowed by redirect policy", target),
+                ));
+            }
+        }
+
+        attempt.follow()
+    })
+}
+
 impl ImportFetcher for HttpFetcher {
     fn handles(&self, source: &ImportSource) -> bool {
         matches!(source, ImportSource::Http { .. })
     }
 
     fn fetch(&self, source: &ImportSource, _ctx: &FetchContext) -> Result<FetchResult, FetchError> {
-        let ImportSource::Http { url } = source else {
+        let ImportSource::Http { url, integrity } = source else {
             return Err(FetchError::UnsupportedSource(
                 "HttpFetcher only handles Http imports".to_string(),
             ));
         };
 
+        /* Soft-miss: a missing/unreadable sidecar or body just means no
+        conditional headers get attached below, falling back to a normal
+        unconditional fetch */
+        let cached = self
+            .cache_dir
+            .as_deref()
+            .and_then(|dir| CacheEntry::load(dir, url));
+
+        let mut request = self.client.get(url);
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified.as_str());
+            }
+        }
+
         /* Perform the HTTP request */
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .map_err(|e| FetchError::Http {
-                status: 0,
-                message: format!("Request failed: {}", e),
-            })?;
+        let response = request.send().map_err(|e| {
+            if e.is_redirect() {
+                FetchError::Http {
+                    status: 0,
+                    message: format!("Redirect blocked by policy for {}: {}", url, e),
+                }
+            } else {
+                FetchError::Http {
+                    status: 0,
+                    message: format!("Request failed: {}", e),
+                }
+            }
+        })?;
 
-        /* Check response status */
         let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            /* `cached` is only `Some` when its body file was confirmed
+            present, so this read should succeed; if it doesn't, the cache
+            is in a weird state and we report it rather than silently
+            serving empty content */
+            let dir = self.cache_dir.as_deref().expect("304 implies a cache dir");
+            let content = std::fs::read_to_string(CacheEntry::body_path(dir, url))?;
+            let computed_digest = verify_integrity(&content, integrity.as_deref())?;
+            /* The original Content-Type isn't persisted in the cache sidecar,
+            so a cache hit falls back to sniffing the URL and body */
+            let format = detect_format(url, None, &content);
+            return Ok(FetchResult {
+                content,
+                canonical_location: url.clone(),
+                is_remote: true,
+                resolved_path: None,
+                from_cache: true,
+                computed_digest: Some(computed_digest),
+                format,
+            });
+        }
+
         if !status.is_success() {
             return Err(FetchError::Http {
                 status: status.as_u16(),
@@ -63,21 +192,247 @@ impl ImportFetcher for HttpFetcher {
             });
         }
 
-        /* Read response body */
-        let content = response.text().map_err(|e| FetchError::Http {
-            status: 0,
-            message: format!("Failed to read response body: {}", e),
-        })?;
+        let etag = header_value(response.headers(), reqwest::header::ETAG);
+        let last_modified = header_value(response.headers(), reqwest::header::LAST_MODIFIED);
+        let content_type = header_value(response.headers(), reqwest::header::CONTENT_TYPE);
+
+        /* Read the body through a bounded reader rather than `response.text()`
+        so an oversized or unbounded response can't be buffered into memory
+        in full before we notice it's too large */
+        let mut buf = Vec::new();
+        response
+            .take(self.max_response_bytes + 1)
+            .read_to_end(&mut buf)
+            .map_err(|e| FetchError::Http {
+                status: 0,
+                message: format!("Failed to read response body: {}", e),
+            })?;
+
+        if buf.len() as u64 > self.max_response_bytes {
+            return Err(FetchError::ResponseTooLarge {
+                url: url.clone(),
+                limit: self.max_response_bytes,
+            });
+        }
+
+        let content = String::from_utf8(buf).map_err(|e| FetchError::Parse(format!(
+            "Response body is not valid UTF-8: {}",
+            e
+        )))?;
+
+        let computed_digest = verify_integrity(&content, integrity.as_deref())?;
+        let format = detect_format(url, content_type.as_deref(), &content);
+
+        if let Some(dir) = &self.cache_dir {
+            /* Cache writes are best-effort: an IO error here can never
+            break resolution, only cost us a cache hit next time */
+            let _ = CacheEntry {
+                etag,
+                last_modified,
+                fetched_at: unix_now(),
+            }
+            .store(dir, url, &content);
+        }
 
         Ok(FetchResult {
             content,
             canonical_location: url.clone(),
             is_remote: true,
             resolved_path: None,
+            from_cache: false,
+            computed_digest: Some(computed_digest),
+            format,
+        })
+    }
+}
+
+pub(crate) fn header_value(headers: &reqwest::header::HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string())
+}
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/* Compute the `sha256-<base64>` subresource-integrity digest of `content` */
+pub(crate) fn compute_integrity_digest(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("sha256-{}", general_purpose::STANDARD.encode(hasher.finalize()))
+}
+
+/* Compute the digest of `content` and, if `declared` is set, verify it matches
+(in constant time, so a byte-by-byte mismatch can't be timed out of the
+comparison) before returning the computed digest to record on `FetchResult` */
+pub(crate) fn verify_integrity(content: &str, declared: Option<&str>) -> Result<String, FetchError> {
+    let computed = compute_integrity_digest(content);
+
+    if let Some(expected) = declared {
+        if !digests_match(expected, &computed) {
+            return Err(FetchError::IntegrityMismatch {
+                expected: expected.to_string(),
+                actual: computed,
+            });
+        }
+    }
+
+    Ok(computed)
+}
+
+/* Determine the serialization format of a fetched body: the `Content-Type`
+header wins when it names a recognized media type, otherwise fall back to
+the URL's extension, and finally to the first non-whitespace byte (`{` or
+`[` reads as JSON, anything else as YAML) */
+pub(crate) fn detect_format(url: &str, content_type: Option<&str>, content: &str) -> FetchedFormat {
+    if let Some(media_type) = content_type.and_then(|ct| ct.split(';').next()) {
+        match media_type.trim().to_ascii_lowercase().as_str() {
+            "application/json" | "text/json" => return FetchedFormat::Json,
+            "application/yaml" | "text/yaml" | "application/x-yaml" => return FetchedFormat::Yaml,
+            _ => {}
+        }
+    }
+
+    let lower_url = url.to_ascii_lowercase();
+    if lower_url.ends_with(".json") {
+        return FetchedFormat::Json;
+    }
+    if lower_url.ends_with(".yaml") || lower_url.ends_with(".yml") {
+        return FetchedFormat::Yaml;
+    }
+
+    match content.trim_start().chars().next() {
+        Some('{') | Some('[') => FetchedFormat::Json,
+        Some(_) => FetchedFormat::Yaml,
+        None => FetchedFormat::Unknown,
+    }
+}
+
+fn digests_match(a: &str, b: &str) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.bytes().zip(b.bytes()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/* The cache validators for one cached URL: the ETag/Last-Modified headers
+needed to issue a conditional request, plus a `fetched_at` timestamp for
+callers that want to apply their own max-age policy on top */
+pub(crate) struct CacheEntry {
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+    pub(crate) fetched_at: u64,
+}
+
+impl CacheEntry {
+    pub(crate) fn sidecar_path(cache_dir: &Path, url: &str) -> PathBuf {
+        cache_dir.join(format!("{}.json", cache_key(url)))
+    }
+
+    pub(crate) fn body_path(cache_dir: &Path, url: &str) -> PathBuf {
+        cache_dir.join(cache_key(url))
+    }
+
+    /* Load the cached validators for `url`, but only if its body file is
+    still on disk too -- otherwise a conditional request could come back
+    with a 304 and nothing to serve. Any IO or parse error here is treated
+    as a soft cache miss. */
+    pub(crate) fn load(cache_dir: &Path, url: &str) -> Option<Self> {
+        if !Self::body_path(cache_dir, url).is_file() {
+            return None;
+        }
+        let raw = std::fs::read_to_string(Self::sidecar_path(cache_dir, url)).ok()?;
+        Self::from_json(&raw)
+    }
+
+    pub(crate) fn store(&self, cache_dir: &Path, url: &str, content: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(cache_dir)?;
+        std::fs::write(Self::body_path(cache_dir, url), content)?;
+        std::fs::write(Self::sidecar_path(cache_dir, url), self.to_json())
+    }
+
+    /* Hand-rolled JSON encode/decode for this one fixed-shape
+    `{etag, last_modified, fetched_at}` sidecar object */
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"etag":{},"last_modified":{},"fetched_at":{}}}"#,
+            json_opt_string(&self.etag),
+            json_opt_string(&self.last_modified),
+            self.fetched_at
+        )
+    }
+
+    fn from_json(raw: &str) -> Option<Self> {
+        Some(Self {
+            etag: json_field_string(raw, "etag"),
+            last_modified: json_field_string(raw, "last_modified"),
+            fetched_at: json_field_number(raw, "fetched_at").unwrap_or(0),
         })
     }
 }
 
+fn json_opt_string(value: &Option<String>) -> String {
+    match value {
+        Some(s) => format!("\"{}\"", json_escape(s)),
+        None => "null".to_string(),
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn json_unescape(s: &str) -> String {
+    s.replace("\\\"", "\"").replace("\\\\", "\\")
+}
+
+/* Extract a `"key":"value"` or `"key":null` field from `raw`. Only needs to
+round-trip what `to_json` above produces, not parse arbitrary JSON. */
+fn json_field_string(raw: &str, key: &str) -> Option<String> {
+    let marker = format!("\"{}\":", key);
+    let start = raw.find(&marker)? + marker.len();
+    let rest = &raw[start..];
+    if rest.starts_with("null") {
+        return None;
+    }
+    let rest = rest.strip_prefix('"')?;
+    let mut escaped = false;
+    for (i, c) in rest.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            return Some(json_unescape(&rest[..i]));
+        }
+    }
+    None
+}
+
+fn json_field_number(raw: &str, key: &str) -> Option<u64> {
+    let marker = format!("\"{}\":", key);
+    let start = raw.find(&marker)? + marker.len();
+    let rest = &raw[start..];
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+pub(crate) fn cache_key(url: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(url.as_bytes());
+    hex_encode(&hasher.finalize())
+}
+
+/* Simple hex-encoding helper (avoids pulling in an extra dependency) */
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +443,7 @@ mod tests {
 
         let http_import = ImportSource::Http {
             url: "https://example.com/types.abi.yaml".to_string(),
+            integrity: None,
         };
         let path_import = ImportSource::Path {
             path: "local.abi.yaml".to_string(),
@@ -103,6 +459,102 @@ mod tests {
         assert!(!fetcher.handles(&git_import));
     }
 
+    #[test]
+    fn test_verify_integrity_no_declared_digest_just_records_it() {
+        let digest = verify_integrity("hello", None).unwrap();
+        assert_eq!(digest, compute_integrity_digest("hello"));
+    }
+
+    #[test]
+    fn test_verify_integrity_matching_digest_passes() {
+        let digest = compute_integrity_digest("hello");
+        assert_eq!(verify_integrity("hello", Some(&digest)).unwrap(), digest);
+    }
+
+    #[test]
+    fn test_verify_integrity_mismatch_fails() {
+        let result = verify_integrity("hello", Some("sha256-not-the-real-digest"));
+        assert!(matches!(result, Err(FetchError::IntegrityMismatch { .. })));
+    }
+
+    #[test]
+    fn test_detect_format_from_content_type() {
+        let url = "https://example.com/types";
+        assert_eq!(detect_format(url, Some("application/json"), "{}"), FetchedFormat::Json);
+        assert_eq!(
+            detect_format(url, Some("application/yaml; charset=utf-8"), "abi: {}"),
+            FetchedFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn test_detect_format_falls_back_to_url_extension() {
+        assert_eq!(
+            detect_format("https://example.com/types.json", Some("application/octet-stream"), "{}"),
+            FetchedFormat::Json
+        );
+        assert_eq!(
+            detect_format("https://example.com/types.yaml", None, "abi: {}"),
+            FetchedFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn test_detect_format_falls_back_to_body_sniff() {
+        assert_eq!(detect_format("https://example.com/types", None, "  { \"abi\": {} }"), FetchedFormat::Json);
+        assert_eq!(detect_format("https://example.com/types", None, "abi:\n  package: x"), FetchedFormat::Yaml);
+        assert_eq!(detect_format("https://example.com/types", None, ""), FetchedFormat::Unknown);
+    }
+
+    #[test]
+    fn test_cache_entry_json_roundtrip() {
+        let entry = CacheEntry {
+            etag: Some("\"abc\\123\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+            fetched_at: 1_700_000_000,
+        };
+
+        let decoded = CacheEntry::from_json(&entry.to_json()).unwrap();
+        assert_eq!(decoded.etag, entry.etag);
+        assert_eq!(decoded.last_modified, entry.last_modified);
+        assert_eq!(decoded.fetched_at, entry.fetched_at);
+    }
+
+    #[test]
+    fn test_cache_entry_json_roundtrip_with_no_validators() {
+        let entry = CacheEntry {
+            etag: None,
+            last_modified: None,
+            fetched_at: 0,
+        };
+
+        let decoded = CacheEntry::from_json(&entry.to_json()).unwrap();
+        assert!(decoded.etag.is_none());
+        assert!(decoded.last_modified.is_none());
+    }
+
+    #[test]
+    fn test_cache_round_trip_on_disk() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let url = "https://example.com/types.abi.yaml";
+
+        assert!(CacheEntry::load(temp_dir.path(), url).is_none());
+
+        let entry = CacheEntry {
+            etag: Some("\"abc\"".to_string()),
+            last_modified: None,
+            fetched_at: 42,
+        };
+        entry.store(temp_dir.path(), url, "content").unwrap();
+
+        let loaded = CacheEntry::load(temp_dir.path(), url).unwrap();
+        assert_eq!(loaded.etag, entry.etag);
+        assert_eq!(
+            std::fs::read_to_string(CacheEntry::body_path(temp_dir.path(), url)).unwrap(),
+            "content"
+        );
+    }
+
     /* Integration test - requires network access */
     #[test]
     #[ignore] /* Run with: cargo test -- --ignored */
@@ -110,6 +562,7 @@ mod tests {
         let fetcher = HttpFetcher::new().unwrap();
         let source = ImportSource::Http {
             url: "https://httpbin.org/get".to_string(),
+            integrity: None,
         };
         let ctx = FetchContext {
             base_path: None,
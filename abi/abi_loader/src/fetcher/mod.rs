@@ -3,6 +3,8 @@
 //! This module provides a pluggable fetcher system for resolving ABI imports
 //! from various sources: local paths, git repositories, HTTP URLs, and on-chain.
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod async_http;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod git;
 #[cfg(not(target_arch = "wasm32"))]
@@ -12,7 +14,10 @@ pub mod onchain;
 pub mod path;
 
 use crate::file::ImportSource;
+use std::future::Future;
 use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
 
 /* ============================================================================
    Fetcher Configuration
@@ -33,6 +38,9 @@ pub struct FetcherConfig {
     /* Git-specific configuration */
     pub git_config: GitFetcherConfig,
 
+    /* HTTP-specific configuration */
+    pub http_config: HttpFetcherConfig,
+
     /* On-chain specific configuration */
     pub onchain_config: OnchainFetcherConfig,
 
@@ -55,6 +63,7 @@ impl FetcherConfig {
             allow_http: true,
             allow_onchain: true,
             git_config: GitFetcherConfig::default(),
+            http_config: HttpFetcherConfig::default(),
             onchain_config: OnchainFetcherConfig::default(),
             cache_config: CacheConfig::default(),
         }
@@ -68,6 +77,7 @@ impl FetcherConfig {
             allow_http: false,
             allow_onchain: false,
             git_config: GitFetcherConfig::default(),
+            http_config: HttpFetcherConfig::default(),
             onchain_config: OnchainFetcherConfig::default(),
             cache_config: CacheConfig::disabled(),
         }
@@ -81,6 +91,7 @@ impl FetcherConfig {
             allow_http: false,
             allow_onchain: true,
             git_config: GitFetcherConfig::default(),
+            http_config: HttpFetcherConfig::default(),
             onchain_config: OnchainFetcherConfig::default(),
             cache_config: CacheConfig::default(),
         }
@@ -94,6 +105,7 @@ impl FetcherConfig {
             allow_http: false,
             allow_onchain: false,
             git_config: GitFetcherConfig::default(),
+            http_config: HttpFetcherConfig::default(),
             onchain_config: OnchainFetcherConfig::default(),
             cache_config: CacheConfig::disabled(),
         }
@@ -135,6 +147,34 @@ impl GitFetcherConfig {
     }
 }
 
+/* HTTP fetcher configuration */
+#[derive(Debug, Clone)]
+pub struct HttpFetcherConfig {
+    /* Request timeout in seconds */
+    pub timeout_seconds: u64,
+    /* Maximum number of redirects to follow before failing the fetch */
+    pub max_redirects: usize,
+    /* If set, a redirect may only land on the original request's own host
+    or one of these hosts; a redirect elsewhere (e.g. an https import
+    silently downgraded to http, or redirected off-host) fails the fetch.
+    `None` disables this check, allowing redirects anywhere. */
+    pub allowed_redirect_hosts: Option<Vec<String>>,
+    /* Maximum response body size in bytes, enforced while streaming the
+    response rather than buffering it first */
+    pub max_response_bytes: u64,
+}
+
+impl Default for HttpFetcherConfig {
+    fn default() -> Self {
+        Self {
+            timeout_seconds: 30,
+            max_redirects: 10,
+            allowed_redirect_hosts: None,
+            max_response_bytes: 10 * 1024 * 1024, /* 10 MB */
+        }
+    }
+}
+
 /* On-chain fetcher configuration */
 #[derive(Debug, Clone)]
 pub struct OnchainFetcherConfig {
@@ -285,6 +325,30 @@ pub struct FetchResult {
     pub is_remote: bool,
     /* Resolved file path (for path imports only) */
     pub resolved_path: Option<PathBuf>,
+    /* Whether this content was served from an on-disk cache (currently only
+    populated by `HttpFetcher`/`AsyncHttpFetcher`) instead of freshly fetched */
+    pub from_cache: bool,
+    /* The `sha256-<base64>` digest of `content` as fetched (currently only
+    populated by `HttpFetcher`/`AsyncHttpFetcher`), whether or not the
+    import declared an `integrity` value to verify against -- lets
+    `--freeze` pin a digest for every HTTP(S) import, not just the ones
+    that already have one. Git and on-chain results carry no digest. */
+    pub computed_digest: Option<String>,
+    /* The serialization format `content` is encoded in, so callers can pick
+    the right parser instead of always assuming YAML */
+    pub format: FetchedFormat,
+}
+
+/* How a fetched ABI file's content is serialized */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchedFormat {
+    /* YAML (the native format for ABI files) */
+    Yaml,
+    /* JSON */
+    Json,
+    /* Could not be determined from the `Content-Type` header, URL extension,
+    or a leading-byte sniff -- treat as YAML, the repo's default */
+    Unknown,
 }
 
 /* ============================================================================
@@ -316,6 +380,10 @@ pub enum FetchError {
     UnknownNetwork(String),
     /* Revision mismatch */
     RevisionMismatch { required: String, actual: u64 },
+    /* Response body exceeded the configured maximum size */
+    ResponseTooLarge { url: String, limit: u64 },
+    /* Downloaded content's digest didn't match the import's declared `integrity` value */
+    IntegrityMismatch { expected: String, actual: String },
 }
 
 impl std::fmt::Display for FetchError {
@@ -338,6 +406,12 @@ impl std::fmt::Display for FetchError {
             FetchError::RevisionMismatch { required, actual } => {
                 write!(f, "Revision mismatch: required {}, got {}", required, actual)
             }
+            FetchError::ResponseTooLarge { url, limit } => {
+                write!(f, "Response for {} exceeded the {} byte limit", url, limit)
+            }
+            FetchError::IntegrityMismatch { expected, actual } => {
+                write!(f, "Integrity mismatch: expected {}, got {}", expected, actual)
+            }
         }
     }
 }
@@ -394,7 +468,13 @@ impl CompositeFetcher {
         }
         #[cfg(not(target_arch = "wasm32"))]
         if config.allow_http {
-            fetchers.push(Box::new(http::HttpFetcher::new()?));
+            let http_fetcher = http::HttpFetcher::with_config(config.http_config.clone())?;
+            let http_fetcher = if config.cache_config.enabled {
+                http_fetcher.with_cache_dir(config.cache_config.cache_dir.clone())
+            } else {
+                http_fetcher
+            };
+            fetchers.push(Box::new(http_fetcher));
         }
         #[cfg(not(target_arch = "wasm32"))]
         if config.allow_onchain {
@@ -431,6 +511,187 @@ impl CompositeFetcher {
     }
 }
 
+/* ============================================================================
+   Async Fetcher Trait
+   ============================================================================ */
+
+/* Async counterpart of [`ImportFetcher`] for embedding import resolution in
+an application that already owns a Tokio runtime. Methods return a boxed
+future rather than being declared `async fn` so the trait stays
+object-safe (`Box<dyn AsyncImportFetcher>`), mirroring how `ImportFetcher`
+itself is used as a trait object in `CompositeFetcher`. */
+#[cfg(not(target_arch = "wasm32"))]
+pub trait AsyncImportFetcher: Send + Sync {
+    /* Check if this fetcher handles the given import source type */
+    fn handles(&self, source: &ImportSource) -> bool;
+
+    /* Fetch the ABI content from the source */
+    fn fetch<'a>(
+        &'a self,
+        source: &'a ImportSource,
+        ctx: &'a FetchContext,
+    ) -> Pin<Box<dyn Future<Output = Result<FetchResult, FetchError>> + Send + 'a>>;
+}
+
+/* Adapts an existing synchronous [`ImportFetcher`] (e.g. `PathFetcher`,
+`GitFetcher`) for use by [`AsyncCompositeFetcher`]: the blocking `fetch`
+call runs on a dedicated blocking thread via `tokio::task::spawn_blocking`
+so it doesn't stall the async executor while other imports are fetched
+concurrently. */
+#[cfg(not(target_arch = "wasm32"))]
+pub struct SyncFetcherAdapter<T> {
+    inner: Arc<T>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> SyncFetcherAdapter<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: ImportFetcher + 'static> AsyncImportFetcher for SyncFetcherAdapter<T> {
+    fn handles(&self, source: &ImportSource) -> bool {
+        self.inner.handles(source)
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        source: &'a ImportSource,
+        ctx: &'a FetchContext,
+    ) -> Pin<Box<dyn Future<Output = Result<FetchResult, FetchError>> + Send + 'a>> {
+        let inner = self.inner.clone();
+        let source = source.clone();
+        let ctx = ctx.clone();
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || inner.fetch(&source, &ctx))
+                .await
+                .map_err(|e| {
+                    FetchError::Io(std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
+                })?
+        })
+    }
+}
+
+/* Adapts an [`AsyncImportFetcher`] back to the blocking [`ImportFetcher`]
+trait so it can be dropped into existing synchronous call sites (e.g. a
+`CompositeFetcher`) unchanged -- each call blocks the current thread on a
+fresh single-threaded Tokio runtime for the duration of that one fetch. */
+#[cfg(not(target_arch = "wasm32"))]
+pub struct BlockingAdapter<T> {
+    inner: T,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T> BlockingAdapter<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: AsyncImportFetcher> ImportFetcher for BlockingAdapter<T> {
+    fn handles(&self, source: &ImportSource) -> bool {
+        self.inner.handles(source)
+    }
+
+    fn fetch(&self, source: &ImportSource, ctx: &FetchContext) -> Result<FetchResult, FetchError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| FetchError::Io(std::io::Error::new(std::io::ErrorKind::Other, e.to_string())))?;
+        runtime.block_on(self.inner.fetch(source, ctx))
+    }
+}
+
+/* ============================================================================
+   Async Composite Fetcher
+   ============================================================================ */
+
+/* Async counterpart of [`CompositeFetcher`]: delegates to the appropriate
+backend and lets callers fetch many imports concurrently instead of one at
+a time. */
+#[cfg(not(target_arch = "wasm32"))]
+pub struct AsyncCompositeFetcher {
+    fetchers: Vec<Box<dyn AsyncImportFetcher>>,
+    config: FetcherConfig,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AsyncCompositeFetcher {
+    /* Create a new async composite fetcher with the given configuration.
+    Path and git imports are resolved via `SyncFetcherAdapter` since both
+    are backed by blocking filesystem/`libgit2` calls; HTTP imports use
+    `AsyncHttpFetcher` directly on top of `reqwest::Client`. */
+    pub fn new(config: FetcherConfig) -> Result<Self, FetchError> {
+        let mut fetchers: Vec<Box<dyn AsyncImportFetcher>> = Vec::new();
+
+        if config.allow_path {
+            fetchers.push(Box::new(SyncFetcherAdapter::new(path::PathFetcher::new())));
+        }
+        if config.allow_git {
+            fetchers.push(Box::new(SyncFetcherAdapter::new(git::GitFetcher::new(
+                &config.git_config,
+            ))));
+        }
+        if config.allow_http {
+            let http_fetcher = async_http::AsyncHttpFetcher::with_config(config.http_config.clone())?;
+            let http_fetcher = if config.cache_config.enabled {
+                http_fetcher.with_cache_dir(config.cache_config.cache_dir.clone())
+            } else {
+                http_fetcher
+            };
+            fetchers.push(Box::new(http_fetcher));
+        }
+        if config.allow_onchain {
+            fetchers.push(Box::new(SyncFetcherAdapter::new(onchain::OnchainFetcher::new(
+                &config.onchain_config,
+            ))));
+        }
+
+        Ok(Self { fetchers, config })
+    }
+
+    /* Fetch a single import source */
+    pub async fn fetch(
+        &self,
+        source: &ImportSource,
+        ctx: &FetchContext,
+    ) -> Result<FetchResult, FetchError> {
+        if !self.config.is_allowed(source) {
+            return Err(FetchError::NotAllowed(source.clone()));
+        }
+
+        for fetcher in &self.fetchers {
+            if fetcher.handles(source) {
+                return fetcher.fetch(source, ctx).await;
+            }
+        }
+
+        Err(FetchError::UnsupportedSource(format!("{:?}", source)))
+    }
+
+    /* Fetch many import sources concurrently, preserving the order of
+    `sources` in the returned results */
+    pub async fn fetch_many(
+        &self,
+        sources: &[(ImportSource, FetchContext)],
+    ) -> Vec<Result<FetchResult, FetchError>> {
+        let futures = sources
+            .iter()
+            .map(|(source, ctx)| self.fetch(source, ctx));
+        futures::future::join_all(futures).await
+    }
+
+    /* Get the configuration */
+    pub fn config(&self) -> &FetcherConfig {
+        &self.config
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,7 +2,7 @@
 //!
 //! Fetches ABI files from on-chain ABI accounts via RPC.
 
-use crate::fetcher::{FetchContext, FetchError, FetchResult, ImportFetcher, OnchainFetcherConfig};
+use crate::fetcher::{FetchContext, FetchError, FetchResult, FetchedFormat, ImportFetcher, OnchainFetcherConfig};
 use crate::file::{ImportSource, OnchainTarget, RevisionSpec};
 use base64::engine::general_purpose;
 use base64::Engine as _;
@@ -479,6 +479,9 @@ impl ImportFetcher for OnchainFetcher {
             canonical_location,
             is_remote: true,
             resolved_path: None,
+            from_cache: false,
+            computed_digest: None,
+            format: FetchedFormat::Yaml,
         })
     }
 }
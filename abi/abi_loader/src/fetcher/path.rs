@@ -2,7 +2,7 @@
 //!
 //! Fetches ABI files from local filesystem paths.
 
-use crate::fetcher::{FetchContext, FetchError, FetchResult, ImportFetcher};
+use crate::fetcher::{FetchContext, FetchError, FetchResult, FetchedFormat, ImportFetcher};
 use crate::file::ImportSource;
 use std::path::PathBuf;
 
@@ -84,6 +84,9 @@ impl ImportFetcher for PathFetcher {
             canonical_location,
             is_remote: false,
             resolved_path: Some(resolved_path),
+            from_cache: false,
+            computed_digest: None,
+            format: FetchedFormat::Yaml,
         })
     }
 }
@@ -58,7 +58,7 @@ pub struct AbiMetadata {
 
     /* List of imported ABI files */
     #[serde(default)]
-    pub imports: Vec<String>,
+    pub imports: Vec<ImportSource>,
 
     /* Optional configuration options */
     #[serde(default)]
@@ -102,7 +102,7 @@ impl AbiFile {
     }
 
     /* Get the imports */
-    pub fn imports(&self) -> &[String] {
+    pub fn imports(&self) -> &[ImportSource] {
         &self.abi.imports
     }
 
@@ -146,3 +146,93 @@ impl AbiFile {
         self.abi.options.program_metadata.root_types.events.as_deref()
     }
 }
+
+/* ============================================================================
+   Import Sources
+   ============================================================================ */
+
+/* Where an import comes from and how to locate it */
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImportSource {
+    /* A local filesystem path, resolved relative to the importing file or an include directory */
+    Path {
+        path: String,
+    },
+
+    /* A file at a specific ref in a git repository */
+    Git {
+        url: String,
+        git_ref: String,
+        path: String,
+    },
+
+    /* A file served over HTTP/HTTPS */
+    Http {
+        url: String,
+        /* Expected content digest (`sha256-<base64>`), verified against the
+        downloaded body. When absent, the computed digest is recorded on the
+        `FetchResult` instead of being enforced -- see `--freeze` */
+        #[serde(default)]
+        integrity: Option<String>,
+    },
+
+    /* An ABI published on-chain via an ABI manager program */
+    Onchain {
+        address: String,
+        target: OnchainTarget,
+        network: String,
+        #[serde(default)]
+        revision: RevisionSpec,
+    },
+}
+
+impl ImportSource {
+    /* Whether this import is fetched from a remote source rather than the local filesystem */
+    pub fn is_remote(&self) -> bool {
+        !matches!(self, ImportSource::Path { .. })
+    }
+}
+
+/* Which on-chain account an `Onchain` import should resolve to */
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OnchainTarget {
+    /* Resolve via the program's derived ABI account */
+    Program,
+    /* Resolve via an explicit ABI meta account address */
+    AbiMeta,
+    /* The address is already an ABI account */
+    Abi,
+}
+
+/* Which revision of an on-chain ABI account satisfies an import */
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RevisionSpec {
+    /* Require an exact revision number */
+    Exact(u64),
+    /* `"latest"`, or a `">=N"` minimum-revision requirement */
+    Specifier(String),
+}
+
+impl RevisionSpec {
+    /* Check whether `actual` satisfies this requirement */
+    pub fn satisfies(&self, actual: u64) -> bool {
+        match self {
+            RevisionSpec::Exact(required) => actual == *required,
+            RevisionSpec::Specifier(s) if s == "latest" => true,
+            RevisionSpec::Specifier(s) => s
+                .strip_prefix(">=")
+                .and_then(|min| min.parse::<u64>().ok())
+                .map(|min| actual >= min)
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl Default for RevisionSpec {
+    fn default() -> Self {
+        RevisionSpec::Specifier("latest".to_string())
+    }
+}
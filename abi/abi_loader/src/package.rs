@@ -64,6 +64,11 @@ pub struct ResolvedPackage {
     pub dependencies: Vec<PackageId>,
     /* Whether this package was fetched from a remote source */
     pub is_remote: bool,
+    /* Content digest (`sha256-<base64>`) computed when this package was
+    fetched over HTTP(S), regardless of whether an `integrity` value was
+    declared on the import -- lets `--freeze` pin such a package to its
+    observed hash. `None` for path, git and on-chain packages. */
+    pub digest: Option<String>,
 }
 
 impl ResolvedPackage {
@@ -80,6 +85,7 @@ impl ResolvedPackage {
             abi_file,
             dependencies,
             is_remote,
+            digest: None,
         }
     }
 
@@ -272,6 +278,18 @@ impl ResolutionResult {
         }
         manifest
     }
+
+    /* Create a lockfile map (package@version -> pinned digest) of every
+    remote package with a recorded digest, for `--freeze` reproducible builds */
+    pub fn to_lockfile(&self) -> std::collections::HashMap<String, String> {
+        let mut lockfile = std::collections::HashMap::new();
+        for pkg in &self.all_packages {
+            if let Some(digest) = &pkg.digest {
+                lockfile.insert(pkg.id.to_string(), digest.clone());
+            }
+        }
+        lockfile
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,242 @@
+//! SSZ-style content hashing for schema-described values.
+//!
+//! Gives any [`TypeKind`]/[`TypeDef`] a deterministic Merkle root over a
+//! decoded value, independent of any particular codegen backend. Mirrors the
+//! SSZ merkleization rules: primitives are packed little-endian into 32-byte
+//! chunks, lists of roots (struct fields, array elements, union variants) are
+//! merkleized by padding to the next power of two and hashing pairs
+//! bottom-up, and variable-length containers mix the element count into the
+//! final root.
+//!
+//! This operates purely on a schema's [`TypeKind`] and a matching byte
+//! slice, without any of `abi_gen`'s footprint/layout resolution. That means
+//! a few corners are necessarily best-effort rather than fully general:
+//! - Jagged (variable-element-size) arrays can't be split into elements
+//!   without per-element footprint functions, which live in `abi_gen`'s
+//!   resolved-type graph, not here. They're hashed as a flat byte chunk
+//!   list and the byte length stands in for the element count.
+//! - `EnumType`'s `tag_ref` is an arbitrary expression in the general case;
+//!   since this function only has a raw byte slice to work with, it assumes
+//!   the conventional `[u64 tag][payload]` encoding (tag as the first 8
+//!   little-endian bytes of `value`).
+//! - Plain `UnionType` carries no discriminant of its own, so there's no way
+//!   to select a variant; it's hashed as a flat byte chunk list.
+
+use sha2::{Digest, Sha256};
+
+use crate::types::{
+    ArrayType, EnumType, FloatingPointType, IntegralType, PrimitiveType,
+    SizeDiscriminatedUnionType, StructType, TypeDef, TypeKind,
+};
+
+const CHUNK_SIZE: usize = 32;
+
+type Chunk = [u8; CHUNK_SIZE];
+
+fn zero_chunk() -> Chunk {
+    [0u8; CHUNK_SIZE]
+}
+
+fn hash_pair(left: &Chunk, right: &Chunk) -> Chunk {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Merkleize a list of 32-byte chunks the way SSZ does: pad the count up to
+/// the next power of two with zero chunks, then pairwise-hash bottom-up.
+fn merkleize_chunks(mut chunks: Vec<Chunk>) -> Chunk {
+    if chunks.is_empty() {
+        return zero_chunk();
+    }
+    chunks.resize(chunks.len().next_power_of_two(), zero_chunk());
+    while chunks.len() > 1 {
+        chunks = chunks
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+    chunks[0]
+}
+
+/// Pack raw little-endian bytes into 32-byte chunks, zero-padding the last one.
+fn pack_bytes(bytes: &[u8]) -> Vec<Chunk> {
+    if bytes.is_empty() {
+        return Vec::new();
+    }
+    bytes
+        .chunks(CHUNK_SIZE)
+        .map(|chunk| {
+            let mut padded = zero_chunk();
+            padded[..chunk.len()].copy_from_slice(chunk);
+            padded
+        })
+        .collect()
+}
+
+/// `H(merkle_root || u256_le(length))`, used for variable-length containers.
+fn mix_in_length(root: &Chunk, length: u64) -> Chunk {
+    let mut length_chunk = zero_chunk();
+    length_chunk[..8].copy_from_slice(&length.to_le_bytes());
+    hash_pair(root, &length_chunk)
+}
+
+fn primitive_byte_width(ty: &PrimitiveType) -> usize {
+    match ty {
+        PrimitiveType::Integral(IntegralType::U8) | PrimitiveType::Integral(IntegralType::I8) => 1,
+        PrimitiveType::Integral(IntegralType::U16)
+        | PrimitiveType::Integral(IntegralType::I16)
+        | PrimitiveType::FloatingPoint(FloatingPointType::F16) => 2,
+        PrimitiveType::Integral(IntegralType::U32)
+        | PrimitiveType::Integral(IntegralType::I32)
+        | PrimitiveType::FloatingPoint(FloatingPointType::F32) => 4,
+        PrimitiveType::Integral(IntegralType::U64)
+        | PrimitiveType::Integral(IntegralType::I64)
+        | PrimitiveType::FloatingPoint(FloatingPointType::F64) => 8,
+    }
+}
+
+/// Static serialized byte width of `ty`, if determinable without reading an
+/// actual value (fixed-size primitives/structs/arrays only).
+fn static_byte_width(ty: &TypeKind) -> Option<usize> {
+    match ty {
+        TypeKind::Primitive(p) => Some(primitive_byte_width(p)),
+        TypeKind::Struct(s) => {
+            let mut total = 0usize;
+            for field in &s.fields {
+                total += static_byte_width(&field.field_type)?;
+            }
+            Some(total)
+        }
+        TypeKind::Array(a) if !a.jagged => {
+            let count = a.size.try_evaluate_constant()? as usize;
+            let elem_width = static_byte_width(&a.element_type)?;
+            Some(count * elem_width)
+        }
+        TypeKind::Array(_) => None, // jagged arrays have no static width
+        TypeKind::Enum(_) => None,  // variant-dependent
+        TypeKind::Union(_) => None, // variant-dependent
+        TypeKind::SizeDiscriminatedUnion(_) => None, // variant-dependent
+        TypeKind::TypeRef(_) => None, // would need the referenced type's definition
+    }
+}
+
+fn hash_struct(value: &[u8], s: &StructType) -> Chunk {
+    let mut field_roots = Vec::with_capacity(s.fields.len());
+    let mut offset = 0usize;
+    for (i, field) in s.fields.iter().enumerate() {
+        let is_last = i == s.fields.len() - 1;
+        let field_value = match static_byte_width(&field.field_type) {
+            Some(width) => {
+                let start = offset.min(value.len());
+                let end = (offset + width).min(value.len());
+                &value[start..end]
+            }
+            None if is_last => &value[offset.min(value.len())..],
+            // A non-trailing field with no statically-known width can't be
+            // bounded without per-field footprint functions; treat it as empty
+            // rather than guessing at a byte range.
+            None => &[][..],
+        };
+        field_roots.push(hash_tree_root(field_value, &field.field_type));
+        offset += field_value.len();
+    }
+    merkleize_chunks(field_roots)
+}
+
+fn hash_array(value: &[u8], a: &ArrayType) -> Chunk {
+    if a.jagged {
+        let root = merkleize_chunks(pack_bytes(value));
+        return mix_in_length(&root, value.len() as u64);
+    }
+
+    let elem_width = static_byte_width(&a.element_type);
+    let constant_count = a.size.try_evaluate_constant().map(|c| c as usize);
+    let count = constant_count
+        .or_else(|| elem_width.filter(|w| *w > 0).map(|w| value.len() / w));
+
+    let root = match (count, elem_width) {
+        (Some(count), Some(width)) if width > 0 => {
+            let roots = (0..count)
+                .map(|i| {
+                    let start = (i * width).min(value.len());
+                    let end = ((i + 1) * width).min(value.len());
+                    hash_tree_root(&value[start..end], &a.element_type)
+                })
+                .collect();
+            merkleize_chunks(roots)
+        }
+        _ => merkleize_chunks(pack_bytes(value)),
+    };
+
+    // A non-constant size makes this a variable-length list, so its element
+    // count (or byte length, when elements can't be split) is mixed in.
+    if constant_count.is_none() {
+        return mix_in_length(&root, count.unwrap_or(value.len()) as u64);
+    }
+    root
+}
+
+fn hash_enum(value: &[u8], e: &EnumType) -> Chunk {
+    if value.len() < 8 {
+        return merkleize_chunks(pack_bytes(value));
+    }
+    let tag_value = u64::from_le_bytes(value[..8].try_into().unwrap());
+    let payload = &value[8..];
+
+    let variant_root = match e.variants.iter().find(|v| v.tag_value == tag_value) {
+        Some(variant) => hash_tree_root(payload, &variant.variant_type),
+        None => merkleize_chunks(pack_bytes(payload)),
+    };
+
+    let mut tag_chunk = zero_chunk();
+    tag_chunk[..8].copy_from_slice(&tag_value.to_le_bytes());
+    merkleize_chunks(vec![variant_root, tag_chunk])
+}
+
+fn hash_size_discriminated_union(value: &[u8], u: &SizeDiscriminatedUnionType) -> Chunk {
+    // There's no explicit discriminant field here, only per-variant expected
+    // sizes, so the matching variant's index stands in for `tag_value` (the
+    // same role a selector index plays in an SSZ union).
+    let selected = u
+        .variants
+        .iter()
+        .enumerate()
+        .find(|(_, v)| v.expected_size as usize == value.len());
+
+    let (index, variant_root) = match selected {
+        Some((index, variant)) => (index as u64, hash_tree_root(value, &variant.variant_type)),
+        None => (u64::MAX, merkleize_chunks(pack_bytes(value))),
+    };
+
+    let mut tag_chunk = zero_chunk();
+    tag_chunk[..8].copy_from_slice(&index.to_le_bytes());
+    merkleize_chunks(vec![variant_root, tag_chunk])
+}
+
+/// Computes the SSZ-style content hash of `value` as described by `ty`.
+pub fn hash_tree_root(value: &[u8], ty: &TypeKind) -> [u8; CHUNK_SIZE] {
+    match ty {
+        TypeKind::Primitive(p) => {
+            let width = primitive_byte_width(p).min(value.len());
+            merkleize_chunks(pack_bytes(&value[..width]))
+        }
+        TypeKind::Struct(s) => hash_struct(value, s),
+        TypeKind::Array(a) => hash_array(value, a),
+        TypeKind::Enum(e) => hash_enum(value, e),
+        TypeKind::SizeDiscriminatedUnion(u) => hash_size_discriminated_union(value, u),
+        // No discriminant of its own to select a variant with; hash the raw bytes.
+        TypeKind::Union(_) => merkleize_chunks(pack_bytes(value)),
+        // No definition available here to resolve the reference against.
+        TypeKind::TypeRef(_) => merkleize_chunks(pack_bytes(value)),
+    }
+}
+
+impl TypeDef {
+    /// Computes the SSZ-style content hash of `value` as described by this
+    /// type's schema. See [`hash_tree_root`] for the merkleization rules.
+    pub fn root(&self, value: &[u8]) -> [u8; CHUNK_SIZE] {
+        hash_tree_root(value, &self.kind)
+    }
+}
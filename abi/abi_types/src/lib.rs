@@ -5,8 +5,10 @@
 //! any file I/O or code generation logic.
 
 pub mod expr;
+pub mod hash_tree_root;
 pub mod types;
 
 // Re-export commonly used types at the crate root
 pub use expr::*;
+pub use hash_tree_root::hash_tree_root;
 pub use types::*;
@@ -1,7 +1,7 @@
 use crate::expr::ExprKind;
 use serde_derive::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub enum IntegralType {
     U8,
@@ -31,6 +31,27 @@ pub enum PrimitiveType {
     FloatingPoint(FloatingPointType),
 }
 
+/// Width of the leading discriminant written by a "tagged" union layout --
+/// see `ContainerAttributes::tagged`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum TagWidth {
+    One,
+    Two,
+    Four,
+}
+
+impl TagWidth {
+    /// Number of bytes the discriminant occupies on the wire.
+    pub fn bytes(self) -> u64 {
+        match self {
+            TagWidth::One => 1,
+            TagWidth::Two => 2,
+            TagWidth::Four => 4,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ContainerAttributes {
@@ -40,6 +61,22 @@ pub struct ContainerAttributes {
     pub aligned: u64,
     #[serde(default)]
     pub comment: Option<String>,
+    /// Opt-in field-reordering pass (only meaningful for `StructType`):
+    /// permits fields to be packed in a different physical order than
+    /// declared, to minimize padding. Fields participating in a layout
+    /// dependency (an array size or enum tag referencing another field)
+    /// keep their required ordering regardless of this flag.
+    #[serde(default)]
+    pub optimize_layout: bool,
+    /// When present (only meaningful for `UnionType`), the union is laid
+    /// out with a leading discriminant of this width, assigned
+    /// deterministically from variant declaration order, ahead of the
+    /// variant payload. This makes the buffer self-describing: `_validate`
+    /// checks the tag, and per-variant `_init` functions stamp it
+    /// automatically. Absent, a union's on-wire form is untagged -- the
+    /// caller must already know which variant is active.
+    #[serde(default)]
+    pub tagged: Option<TagWidth>,
 }
 
 impl Default for ContainerAttributes {
@@ -48,6 +85,8 @@ impl Default for ContainerAttributes {
             packed: false,
             aligned: 0,
             comment: None,
+            optimize_layout: false,
+            tagged: None,
         }
     }
 }
@@ -60,6 +99,28 @@ pub struct EnumVariant {
     pub variant_type: TypeKind,
 }
 
+/// Niche-filling layout for an enum: instead of a separate tag field, the
+/// discriminant is encoded in the unused value range ("niche") of a scalar
+/// field belonging to the single data-carrying variant. Data-less variants
+/// are represented by writing a sentinel from that niche into the field, so
+/// no extra bytes are spent on a tag. This is rustc's `Option<&T>`-style
+/// optimization, recast for this crate's declarative types.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct NicheFilling {
+    /// Name of the variant whose field supplies the niche; this is the only
+    /// variant that actually carries data at runtime.
+    pub dataful_variant: String,
+    /// Path (dot-separated for nested fields) to the scalar field within
+    /// `dataful_variant`'s type whose declared `valid_range` leaves unused
+    /// values.
+    pub niche_field_path: String,
+    /// First value past the end of the field's declared valid range.
+    pub niche_start: i64,
+    /// Number of unused values available to encode data-less variants.
+    pub niche_count: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct EnumType {
@@ -67,6 +128,17 @@ pub struct EnumType {
     pub container_attributes: ContainerAttributes,
     pub tag_ref: ExprKind,
     pub variants: Vec<EnumVariant>,
+    /// When present, this enum is laid out without a tag field -- see
+    /// `NicheFilling`.
+    #[serde(default)]
+    pub niche: Option<NicheFilling>,
+    /// Manually declared integer type for the tag field. When absent, the
+    /// tag is sized to the smallest `IntegralType` that fits every declared
+    /// `tag_value` (mirroring rustc's `repr_discr`). When present, layout
+    /// validation checks it against that same minimal type and flags it if
+    /// it's wider or narrower than necessary.
+    #[serde(default)]
+    pub tag_type: Option<IntegralType>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
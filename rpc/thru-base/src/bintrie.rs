@@ -50,17 +50,22 @@ impl BinTrieLeaf {
         leaf
     }
 
+    /// Hash a leaf's pubkey/value pair according to the C implementation.
+    pub fn leaf_hash(pubkey: &Pubkey, value_hash: &Hash) -> Hash {
+        let mut hasher = Sha256::new();
+        hasher.update(&[0x00]); // Leaf prefix
+        hasher.update(pubkey.as_bytes());
+        hasher.update(value_hash.as_bytes());
+        let result = hasher.finalize();
+        Hash::new(result.into())
+    }
+
     /// Compute the hash of this leaf according to the C implementation
     pub fn compute_hash(&mut self) {
         if self.pair.is_sibling_hash {
             self.hash = self.pair.value_hash;
         } else {
-            let mut hasher = Sha256::new();
-            hasher.update(&[0x00]); // Leaf prefix
-            hasher.update(self.pair.pubkey.as_bytes());
-            hasher.update(self.pair.value_hash.as_bytes());
-            let result = hasher.finalize();
-            self.hash = Hash::new(result.into());
+            self.hash = Self::leaf_hash(&self.pair.pubkey, &self.pair.value_hash);
         }
     }
 }
@@ -84,6 +89,16 @@ impl BinTrieNode {
         }
     }
 
+    /// Hash a pair of child hashes according to the C implementation.
+    pub fn node_hash(left: &Hash, right: &Hash) -> Hash {
+        let mut hasher = Sha256::new();
+        hasher.update(&[0x01]); // Internal node prefix
+        hasher.update(left.as_bytes());
+        hasher.update(right.as_bytes());
+        let result = hasher.finalize();
+        Hash::new(result.into())
+    }
+
     /// Compute the hash of this node according to the C implementation
     pub fn compute_hash(&mut self) {
         let left_hash = self
@@ -97,12 +112,7 @@ impl BinTrieNode {
             .map(|e| e.hash())
             .unwrap_or(Hash::default());
 
-        let mut hasher = Sha256::new();
-        hasher.update(&[0x01]); // Internal node prefix
-        hasher.update(left_hash.as_bytes());
-        hasher.update(right_hash.as_bytes());
-        let result = hasher.finalize();
-        self.hash = Hash::new(result.into());
+        self.hash = Self::node_hash(&left_hash, &right_hash);
     }
 }
 
@@ -457,6 +467,43 @@ impl BinTrie {
         }
     }
 
+    /// Verify a proof of existence against a trusted root, without needing
+    /// the trie itself. `proof.proof_indices`/`proof.sibling_hashes` are
+    /// ordered root-to-leaf (as produced by [`Self::prove_existence`]), so
+    /// verification walks them in reverse, recombining the leaf hash up to
+    /// the root and checking the result against `expected_root`.
+    pub fn verify_existence_proof(
+        pubkey: &Pubkey,
+        value_hash: &Hash,
+        proof: &Proof,
+        expected_root: &Hash,
+    ) -> Result<(), BinTrieError> {
+        if proof.proof_indices.len() != proof.sibling_hashes.len() {
+            return Err(BinTrieError::InvalidProof);
+        }
+
+        let mut current = BinTrieLeaf::leaf_hash(pubkey, value_hash);
+        for (bit_idx, sibling_hash) in proof
+            .proof_indices
+            .iter()
+            .zip(proof.sibling_hashes.iter())
+            .rev()
+        {
+            current = if pubkey.get_bit(*bit_idx) {
+                // We went right at this bit, so the sibling is on the left.
+                BinTrieNode::node_hash(sibling_hash, &current)
+            } else {
+                BinTrieNode::node_hash(&current, sibling_hash)
+            };
+        }
+
+        if current == *expected_root {
+            Ok(())
+        } else {
+            Err(BinTrieError::ProofVerificationFailed)
+        }
+    }
+
     /// Generate a proof of non-existence for a key
     pub fn prove_non_existence(&self, pubkey: &Pubkey) -> Result<NonExistenceProof, BinTrieError> {
         // Empty trie proves non-existence of any key
@@ -774,4 +774,45 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_verify_existence_proof() {
+        let mut trie = BinTrie::new();
+
+        let key1 = test_pubkey(4);
+        let value1 = test_hash(4);
+        trie.insert(key1, value1).unwrap();
+
+        let key2 = test_pubkey(2);
+        let value2 = test_hash(7);
+        trie.insert(key2, value2).unwrap();
+
+        let root = trie.state_root();
+
+        let (proof1, existing_hash1) = trie.prove_existence(&key1).unwrap();
+        assert!(BinTrie::verify_existence_proof(&key1, &existing_hash1, &proof1, &root).is_ok());
+
+        let (proof2, existing_hash2) = trie.prove_existence(&key2).unwrap();
+        assert!(BinTrie::verify_existence_proof(&key2, &existing_hash2, &proof2, &root).is_ok());
+
+        // Wrong value hash should fail verification.
+        assert!(matches!(
+            BinTrie::verify_existence_proof(&key1, &test_hash(99), &proof1, &root),
+            Err(BinTrieError::ProofVerificationFailed)
+        ));
+
+        // A proof verified against the wrong root should fail too.
+        assert!(matches!(
+            BinTrie::verify_existence_proof(&key1, &existing_hash1, &proof1, &test_hash(99)),
+            Err(BinTrieError::ProofVerificationFailed)
+        ));
+
+        // Mismatched proof/sibling lengths are rejected outright.
+        let mut malformed = proof1.clone();
+        malformed.sibling_hashes.pop();
+        assert!(matches!(
+            BinTrie::verify_existence_proof(&key1, &existing_hash1, &malformed, &root),
+            Err(BinTrieError::InvalidProof)
+        ));
+    }
 }
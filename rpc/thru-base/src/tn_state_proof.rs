@@ -2,6 +2,10 @@
 //!
 //! Rust equivalent of the C tn_state_proof.h structures
 
+use crate::bintrie::BinTrie;
+use crate::bintrie_error::BinTrieError;
+use crate::bintrie_proof::Proof;
+use crate::bintrie_types::{Hash as BtHash, Pubkey as BtPubkey};
 use crate::txn_lib::{
     TN_STATE_PROOF_TYPE_CREATION, TN_STATE_PROOF_TYPE_EXISTING, TN_STATE_PROOF_TYPE_UPDATING,
     TnHash, TnPubkey,
@@ -99,6 +103,24 @@ impl StateProofHeader {
             path_bitset,
         })
     }
+
+    /// Recover the ascending list of trie bit-indices this proof carries a
+    /// sibling hash for. `path_bitset` only records which bits were used,
+    /// not the sibling hashes themselves, but the hashes in the body are
+    /// serialized in the same order the bits appear here (root-to-leaf).
+    pub fn proof_indices(&self) -> Vec<u8> {
+        let mut indices = Vec::with_capacity(count_set_bits(&self.path_bitset));
+        for word_idx in 0..4 {
+            let start = word_idx * 8;
+            let word = u64::from_le_bytes(self.path_bitset[start..start + 8].try_into().unwrap());
+            for bit in 0..64 {
+                if (word >> bit) & 1 == 1 {
+                    indices.push((word_idx * 64 + bit) as u8);
+                }
+            }
+        }
+        indices
+    }
 }
 
 /// State proof body variants
@@ -375,6 +397,35 @@ impl StateProof {
     pub fn path_bitset(&self) -> &TnHash {
         &self.header.path_bitset
     }
+
+    /// Verify this proof shows `pubkey`/`value_hash` is included in the
+    /// trie committed to by `expected_root`, without trusting whoever
+    /// handed over the proof. Only `Existing`-type proofs are supported,
+    /// which is what a read-only account lookup requests from the node.
+    pub fn verify_existence(
+        &self,
+        pubkey: &TnPubkey,
+        value_hash: &TnHash,
+        expected_root: &TnHash,
+    ) -> Result<(), BinTrieError> {
+        let sibling_hashes = match &self.body {
+            StateProofBody::Existing { sibling_hashes } => sibling_hashes,
+            _ => return Err(BinTrieError::InvalidProof),
+        };
+
+        let proof = Proof {
+            proof_indices: self.header.proof_indices(),
+            sibling_hashes: sibling_hashes.iter().map(|h| BtHash::new(*h)).collect(),
+            existing_leaf_hash: None,
+        };
+
+        BinTrie::verify_existence_proof(
+            &BtPubkey::new(*pubkey),
+            &BtHash::new(*value_hash),
+            &proof,
+            &BtHash::new(*expected_root),
+        )
+    }
 }
 
 /// Count the number of set bits in a hash (used for calculating sibling hash count)
@@ -546,4 +597,75 @@ mod tests {
             40 + 4 * 32
         );
     }
+
+    #[test]
+    fn test_proof_indices_roundtrip_with_path_bitset() {
+        let mut path_bitset = [0u8; 32];
+        path_bitset[0] = 0b101; // bits 0 and 2
+        path_bitset[8] = 0b1; // bit 64
+
+        let header = StateProofHeader::new(StateProofType::Existing, 1, path_bitset);
+        assert_eq!(header.proof_indices(), vec![0, 2, 64]);
+    }
+
+    #[test]
+    fn test_verify_existence_against_bintrie() {
+        use crate::bintrie_types::{Hash, Pubkey};
+
+        let mut trie = BinTrie::new();
+        let key1 = {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&4u64.to_le_bytes());
+            Pubkey::new(bytes)
+        };
+        let value1 = {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&4u64.to_le_bytes());
+            Hash::new(bytes)
+        };
+        trie.insert(key1, value1).unwrap();
+
+        let key2 = {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&2u64.to_le_bytes());
+            Pubkey::new(bytes)
+        };
+        let value2 = {
+            let mut bytes = [0u8; 32];
+            bytes[..8].copy_from_slice(&7u64.to_le_bytes());
+            Hash::new(bytes)
+        };
+        trie.insert(key2, value2).unwrap();
+
+        let root = trie.state_root();
+        let (proof, existing_hash) = trie.prove_existence(&key1).unwrap();
+
+        let mut path_bitset = [0u8; 32];
+        for &idx in &proof.proof_indices {
+            let word_idx = (idx / 64) as usize;
+            let bit_idx = (idx % 64) as usize;
+            let start = word_idx * 8;
+            let mut word = u64::from_le_bytes(path_bitset[start..start + 8].try_into().unwrap());
+            word |= 1u64 << bit_idx;
+            path_bitset[start..start + 8].copy_from_slice(&word.to_le_bytes());
+        }
+        let sibling_hashes: Vec<TnHash> = proof
+            .sibling_hashes
+            .iter()
+            .map(|h| *h.as_bytes())
+            .collect();
+        let state_proof = StateProof::existing(0, path_bitset, sibling_hashes);
+
+        assert!(
+            state_proof
+                .verify_existence(key1.as_bytes(), existing_hash.as_bytes(), root.as_bytes())
+                .is_ok()
+        );
+
+        assert!(
+            state_proof
+                .verify_existence(key1.as_bytes(), &[0u8; 32], root.as_bytes())
+                .is_err()
+        );
+    }
 }
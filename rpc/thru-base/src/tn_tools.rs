@@ -2,15 +2,21 @@ use crate::tn_public_address;
 use crate::txn_lib::TnPubkey;
 use crate::{tn_public_address::tn_pubkey_to_address_string, tn_signature_encoding};
 use anyhow::Result;
-use ed25519_dalek::SigningKey;
+use ed25519_dalek::{Signer, SigningKey, Verifier};
 use hex;
+use hmac::{Hmac, Mac};
 use rand::TryRngCore;
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
 use std::fmt;
+use std::fs;
+use std::path::Path;
 
 use thiserror::Error;
 
+type HmacSha512 = Hmac<Sha512>;
+
 pub fn gen_key() -> Result<[u8; 32]> {
     let mut private_key = [0u8; 32];
     let mut rng = OsRng;
@@ -18,6 +24,84 @@ pub fn gen_key() -> Result<[u8; 32]> {
     Ok(private_key)
 }
 
+/// Fixed prefix for the off-chain signed-message protocol (see
+/// [`KeyPair::sign_message`]). Prepending it guarantees a signed message can
+/// never also be replayed as a valid transaction signature, since it changes
+/// the bytes that actually get signed.
+const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x0cThru Signed Message:\n";
+
+/// Encodes `value` as an unsigned LEB128 varint.
+fn encode_varint(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        bytes.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+    bytes
+}
+
+/// Builds the domain-separated digest signed by [`KeyPair::sign_message`]:
+/// `prefix || varint(msg.len()) || msg`, hashed with SHA-256.
+fn signed_message_digest(msg: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(SIGNED_MESSAGE_PREFIX);
+    hasher.update(encode_varint(msg.len() as u64));
+    hasher.update(msg);
+    hasher.finalize().into()
+}
+
+/// Overwrites `buf` with zeros using a volatile write, so the compiler can't
+/// optimize the write away as dead code just because nothing reads `buf`
+/// afterwards. Used to scrub private key material out of transient buffers.
+fn zeroize_volatile(buf: &mut [u8]) {
+    for byte in buf.iter_mut() {
+        // SAFETY: `byte` is a valid, aligned `&mut u8` for the duration of the write.
+        unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Parses a SLIP-0010 derivation path like `m/44'/784'/0'/0'` into its
+/// sequence of indices, each returned with the `0x8000_0000` hardening bit
+/// already set. ed25519 only supports hardened derivation, so every
+/// component after the leading `m` must carry a `'` or `h` hardening marker.
+fn parse_hardened_derivation_path(path: &str) -> Result<Vec<u32>> {
+    let mut components = path.split('/');
+    if components.next() != Some("m") {
+        return Err(anyhow::anyhow!("derivation path must start with 'm'"));
+    }
+
+    components
+        .map(|component| {
+            let hardened = component.ends_with('\'') || component.ends_with('h');
+            if !hardened {
+                return Err(anyhow::anyhow!(
+                    "derivation path component '{}' must be hardened (append a ') -- \
+                     ed25519 only supports hardened derivation",
+                    component
+                ));
+            }
+            let index: u32 = component[..component.len() - 1]
+                .parse()
+                .map_err(|_| anyhow::anyhow!("invalid derivation path component: '{}'", component))?;
+            if index & 0x8000_0000 != 0 {
+                return Err(anyhow::anyhow!(
+                    "derivation path index {} is too large to harden",
+                    index
+                ));
+            }
+            Ok(index | 0x8000_0000)
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct KeyPair {
     pub name: String,
@@ -60,8 +144,10 @@ impl KeyPair {
             ));
         }
 
+        let mut private_key_bytes = private_key_bytes;
         let mut private_key = [0u8; 32];
         private_key.copy_from_slice(&private_key_bytes);
+        zeroize_volatile(&mut private_key_bytes);
 
         // Derive public key
         let signing_key = SigningKey::from_bytes(&private_key);
@@ -79,12 +165,151 @@ impl KeyPair {
         })
     }
 
+    /// Derives a `KeyPair` from a seed using SLIP-0010 hierarchical
+    /// deterministic derivation for ed25519, following `path` (e.g.
+    /// `m/44'/784'/0'/0'`). ed25519 supports hardened derivation only, so
+    /// every path component must carry a hardening marker.
+    pub fn derive_from_seed(name: &str, seed: &[u8], path: &str) -> Result<Self> {
+        let indices = parse_hardened_derivation_path(path)?;
+
+        let mut mac = HmacSha512::new_from_slice(b"ed25519 seed")
+            .map_err(|e| anyhow::anyhow!("failed to initialize HMAC: {}", e))?;
+        mac.update(seed);
+        let master = mac.finalize().into_bytes();
+        let mut k: [u8; 32] = master[..32].try_into().unwrap();
+        let mut c: [u8; 32] = master[32..].try_into().unwrap();
+
+        for index in indices {
+            let mut mac = HmacSha512::new_from_slice(&c)
+                .map_err(|e| anyhow::anyhow!("failed to initialize HMAC: {}", e))?;
+            mac.update(&[0u8]);
+            mac.update(&k);
+            mac.update(&index.to_be_bytes());
+            let i = mac.finalize().into_bytes();
+            k.copy_from_slice(&i[..32]);
+            c.copy_from_slice(&i[32..]);
+        }
+
+        let signing_key = SigningKey::from_bytes(&k);
+        let verifying_key = signing_key.verifying_key();
+        let public_key = verifying_key.to_bytes();
+        let address_string = Pubkey::from_bytes(&public_key);
+
+        Ok(Self {
+            name: name.to_string(),
+            private_key: k,
+            public_key,
+            address_string,
+        })
+    }
+
     pub fn public_key_hex(&self) -> String {
         hex::encode(self.public_key)
     }
     pub fn public_key_str(&self) -> String {
         tn_pubkey_to_address_string(&self.public_key)
     }
+
+    /// Sign an arbitrary message with this keypair's private key, returning
+    /// the raw 64-byte ed25519 signature.
+    ///
+    /// Unlike [`crate::txn_lib::Transaction::sign`], this isn't specific to the
+    /// transaction wire format; it's a raw ed25519 signature over whatever
+    /// bytes are passed in (e.g. a request-signing digest).
+    pub fn sign_raw(&self, message: &[u8]) -> [u8; 64] {
+        let signing_key = SigningKey::from_bytes(&self.private_key);
+        signing_key.sign(message).to_bytes()
+    }
+
+    /// Sign an arbitrary message, wrapping the result in this crate's own
+    /// [`Signature`] type so it can be encoded, stored, and verified with
+    /// [`Signature::verify`] the same way an on-chain signature would be.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        Signature::from_bytes(&self.sign_raw(message))
+    }
+
+    /// Signs `msg` for off-chain authentication (e.g. a login challenge),
+    /// following the same domain-separated "signed message" shape as
+    /// Bitcoin's: rather than signing `msg` directly, this signs a digest of
+    /// `prefix || varint(msg.len()) || msg`. Because the fixed prefix never
+    /// appears in the transaction-signing path, a signature produced here
+    /// can never be replayed as a valid transaction signature.
+    pub fn sign_message(&self, msg: &[u8]) -> Signature {
+        self.sign(&signed_message_digest(msg))
+    }
+
+    /// Borrows the private key for the duration of `f` instead of cloning
+    /// the whole `KeyPair` just to reach its secret bytes.
+    pub fn with_private_key<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&[u8; 32]) -> R,
+    {
+        f(&self.private_key)
+    }
+
+    /// Writes this keypair to `path` as a JSON array of the 64 keypair bytes
+    /// (32-byte private key followed by the 32-byte public key), matching the
+    /// on-disk format of Solana's `id.json` so existing tooling for managing
+    /// many identities on disk can be reused as-is.
+    pub fn write_to_file(&self, path: &Path) -> Result<()> {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.private_key);
+        bytes[32..].copy_from_slice(&self.public_key);
+        let mut bytes_vec = bytes.to_vec();
+        zeroize_volatile(&mut bytes);
+        let json_result = serde_json::to_string(&bytes_vec);
+        zeroize_volatile(&mut bytes_vec);
+        let mut json = json_result?;
+        let result = fs::write(path, &json);
+        // SAFETY: zeroing to all-NUL bytes is still valid UTF-8, so `json` is left in a
+        // valid state for its `Drop` impl to run afterwards.
+        zeroize_volatile(unsafe { json.as_bytes_mut() });
+        result?;
+        Ok(())
+    }
+
+    /// Reads a keypair previously written by [`Self::write_to_file`]. The
+    /// public key and `Pubkey` address are re-derived from the private key
+    /// rather than trusted from the file, so a hand-edited or truncated file
+    /// can't desynchronize the two.
+    pub fn read_from_file(name: &str, path: &Path) -> Result<Self> {
+        let mut json = fs::read_to_string(path)?;
+        let bytes_result: serde_json::Result<Vec<u8>> = serde_json::from_str(&json);
+        // SAFETY: zeroing to all-NUL bytes is still valid UTF-8, so `json` is left in a
+        // valid state for its `Drop` impl to run afterwards.
+        zeroize_volatile(unsafe { json.as_bytes_mut() });
+        let mut bytes = bytes_result?;
+
+        if bytes.len() != 32 && bytes.len() != 64 {
+            zeroize_volatile(&mut bytes);
+            return Err(anyhow::anyhow!(
+                "keypair file must contain 32 or 64 bytes, got {}",
+                bytes.len()
+            ));
+        }
+
+        let mut private_key = [0u8; 32];
+        private_key.copy_from_slice(&bytes[..32]);
+        zeroize_volatile(&mut bytes);
+
+        let signing_key = SigningKey::from_bytes(&private_key);
+        let verifying_key = signing_key.verifying_key();
+        let public_key = verifying_key.to_bytes();
+        let address_string = Pubkey::from_bytes(&public_key);
+
+        Ok(Self {
+            name: name.to_string(),
+            private_key,
+            public_key,
+            address_string,
+        })
+    }
+}
+
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        zeroize_volatile(&mut self.private_key);
+    }
 }
 
 /// A public key on the blockchain
@@ -123,9 +348,10 @@ impl Pubkey {
         let mut decoded = [0u8; 32];
         match tn_public_address::tn_public_address_decode(&mut decoded, key.as_bytes()) {
             Ok(()) => Ok(Self(key)),
-            Err(code) => Err(ValidationError::InvalidPubkey(format!(
-                "invalid pubkey format: decode error {}",
-                code
+            Err(code) => Err(ValidationError::PubkeyDecode(DecodeError::from_code(
+                code,
+                key.len(),
+                46,
             ))
             .into()),
         }
@@ -141,9 +367,10 @@ impl Pubkey {
         let mut bytes = [0u8; 32];
         match tn_public_address::tn_public_address_decode(&mut bytes, self.0.as_bytes()) {
             Ok(()) => Ok(bytes),
-            Err(code) => Err(ValidationError::InvalidPubkey(format!(
-                "failed to decode pubkey: error {}",
-                code
+            Err(code) => Err(ValidationError::PubkeyDecode(DecodeError::from_code(
+                code,
+                self.0.len(),
+                46,
             ))
             .into()),
         }
@@ -163,6 +390,34 @@ impl fmt::Display for Pubkey {
     }
 }
 
+impl std::str::FromStr for Pubkey {
+    type Err = anyhow::Error;
+
+    /// Parses either the native `ta...` checksummed form (46 chars) or a raw
+    /// 64-char hex public key, returning the canonical `Pubkey` either way.
+    fn from_str(input: &str) -> Result<Self> {
+        if input.starts_with("ta") && input.len() == 46 {
+            return Self::new(input.to_string());
+        }
+
+        if input.len() == 64 {
+            let bytes = hex::decode(input).map_err(|e| {
+                ValidationError::InvalidPubkey(format!("invalid hex pubkey: {}", e))
+            })?;
+            let bytes: [u8; 32] = bytes.try_into().map_err(|_| {
+                ValidationError::InvalidPubkey("hex pubkey must decode to 32 bytes".to_string())
+            })?;
+            return Ok(Self::from_bytes(&bytes));
+        }
+
+        Err(ValidationError::InvalidPubkey(format!(
+            "pubkey must be a 'ta...' address (46 chars) or 64-char hex string, got {} characters",
+            input.len()
+        ))
+        .into())
+    }
+}
+
 /// A transaction signature
 ///
 /// Signatures in Thru are encoded as 90-character strings starting with "ts"
@@ -200,9 +455,10 @@ impl Signature {
         let mut decoded = [0u8; 64];
         match tn_signature_encoding::tn_signature_decode(&mut decoded, sig.as_bytes()) {
             Ok(()) => Ok(Self(sig)),
-            Err(code) => Err(ValidationError::InvalidSignature(format!(
-                "invalid signature format: decode error {}",
-                code
+            Err(code) => Err(ValidationError::SignatureDecode(DecodeError::from_code(
+                code,
+                sig.len(),
+                90,
             ))
             .into()),
         }
@@ -218,9 +474,10 @@ impl Signature {
         let mut bytes = [0u8; 64];
         match tn_signature_encoding::tn_signature_decode(&mut bytes, self.0.as_bytes()) {
             Ok(()) => Ok(bytes),
-            Err(code) => Err(ValidationError::InvalidSignature(format!(
-                "failed to decode signature: error {}",
-                code
+            Err(code) => Err(ValidationError::SignatureDecode(DecodeError::from_code(
+                code,
+                self.0.len(),
+                90,
             ))
             .into()),
         }
@@ -232,6 +489,29 @@ impl Signature {
         // This should never fail since we're encoding from valid bytes
         Self(signature)
     }
+
+    /// Verify that this signature was produced by `pubkey` signing `message`.
+    ///
+    /// Returns `false` (rather than an error) for a malformed signature or
+    /// pubkey, since both cases just mean "this isn't a valid signature over
+    /// this message" from the caller's perspective.
+    pub fn verify(&self, pubkey: &Pubkey, message: &[u8]) -> bool {
+        let (Ok(signature_bytes), Ok(pubkey_bytes)) = (self.to_bytes(), pubkey.to_bytes()) else {
+            return false;
+        };
+        let Ok(verifying_key) = ed25519_dalek::VerifyingKey::from_bytes(&pubkey_bytes) else {
+            return false;
+        };
+        let signature = ed25519_dalek::Signature::from_bytes(&signature_bytes);
+        verifying_key.verify_strict(message, &signature).is_ok()
+    }
+
+    /// Verifies a signature produced by [`KeyPair::sign_message`]: recomputes
+    /// the same domain-separated digest over `msg` and checks it against this
+    /// signature, rather than checking `msg` directly.
+    pub fn verify_message(&self, pubkey: &Pubkey, msg: &[u8]) -> bool {
+        self.verify(pubkey, &signed_message_digest(msg))
+    }
 }
 
 impl fmt::Display for Signature {
@@ -240,6 +520,36 @@ impl fmt::Display for Signature {
     }
 }
 
+impl std::str::FromStr for Signature {
+    type Err = anyhow::Error;
+
+    /// Parses either the native `ts...` checksummed form (90 chars) or a raw
+    /// 128-char hex signature, returning the canonical `Signature` either way.
+    fn from_str(input: &str) -> Result<Self> {
+        if input.starts_with("ts") && input.len() == 90 {
+            return Self::new(input.to_string());
+        }
+
+        if input.len() == 128 {
+            let bytes = hex::decode(input).map_err(|e| {
+                ValidationError::InvalidSignature(format!("invalid hex signature: {}", e))
+            })?;
+            let bytes: [u8; 64] = bytes.try_into().map_err(|_| {
+                ValidationError::InvalidSignature(
+                    "hex signature must decode to 64 bytes".to_string(),
+                )
+            })?;
+            return Ok(Self::from_bytes(&bytes));
+        }
+
+        Err(ValidationError::InvalidSignature(format!(
+            "signature must be a 'ts...' signature (90 chars) or 128-char hex string, got {} characters",
+            input.len()
+        ))
+        .into())
+    }
+}
+
 /// Validation errors for input data
 #[derive(Error, Debug)]
 pub enum ValidationError {
@@ -250,6 +560,57 @@ pub enum ValidationError {
     /// Invalid signature format
     #[error("Invalid signature: {0}")]
     InvalidSignature(String),
+
+    /// A `ta...` pubkey string failed to decode
+    #[error("invalid public key encoding: {0}")]
+    PubkeyDecode(DecodeError),
+
+    /// A `ts...` signature string failed to decode
+    #[error("invalid signature encoding: {0}")]
+    SignatureDecode(DecodeError),
+}
+
+/// Structured reason a `tn_public_address_decode`/`tn_signature_decode` call
+/// failed, in place of the raw numeric error codes those functions return.
+/// Lets callers match on, say, a checksum failure versus a length mismatch
+/// instead of parsing a `"decode error {code}"` string.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The encoded string wasn't the expected length for its type.
+    #[error("invalid length: expected {expected} bytes, got {got}")]
+    BadLength { expected: usize, got: usize },
+
+    /// The encoded string didn't start with the expected `ta`/`ts` prefix.
+    #[error("invalid prefix")]
+    BadPrefix,
+
+    /// The encoded string contained a character outside the base64-url
+    /// alphabet used by this encoding.
+    #[error("invalid character in encoded data")]
+    InvalidCharacter,
+
+    /// The trailing checksum byte(s) didn't match the decoded payload.
+    #[error("checksum mismatch")]
+    BadChecksum,
+}
+
+impl DecodeError {
+    /// Translates the numeric error codes returned by
+    /// `tn_public_address_decode`/`tn_signature_decode` (`-1` through `-5`)
+    /// into a structured `DecodeError`. `input_len`/`expected_len` fill in
+    /// [`DecodeError::BadLength`], since the numeric code alone doesn't carry
+    /// them.
+    fn from_code(code: i32, input_len: usize, expected_len: usize) -> Self {
+        match code {
+            -1 => DecodeError::BadLength {
+                expected: expected_len,
+                got: input_len,
+            },
+            -2 => DecodeError::BadPrefix,
+            -3 | -4 => DecodeError::InvalidCharacter,
+            _ => DecodeError::BadChecksum,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -314,4 +675,228 @@ mod tests {
         let decoded_bytes = signature.to_bytes().unwrap();
         assert_eq!(bytes, decoded_bytes);
     }
+
+    #[test]
+    fn test_keypair_sign_raw_is_deterministic_and_verifiable() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let message = b"hello thru";
+
+        let signature = keypair.sign_raw(message);
+        assert_eq!(signature, keypair.sign_raw(message));
+
+        let verifying_key =
+            ed25519_dalek::VerifyingKey::from_bytes(&keypair.public_key).unwrap();
+        let parsed_signature = ed25519_dalek::Signature::from_bytes(&signature);
+        assert!(verifying_key.verify_strict(message, &parsed_signature).is_ok());
+    }
+
+    #[test]
+    fn test_keypair_sign_and_signature_verify_round_trip() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let message = b"hello thru";
+
+        let signature = keypair.sign(message);
+        assert!(signature.verify(&keypair.address_string, message));
+    }
+
+    #[test]
+    fn test_signature_verify_rejects_wrong_message_and_wrong_pubkey() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let other_keypair = KeyPair::from_hex_private_key("other", hex::encode([9u8; 32])).unwrap();
+        let message = b"hello thru";
+
+        let signature = keypair.sign(message);
+        assert!(!signature.verify(&keypair.address_string, b"a different message"));
+        assert!(!signature.verify(&other_keypair.address_string, message));
+    }
+
+    #[test]
+    fn test_derive_from_seed_is_deterministic() {
+        let seed = b"test seed for SLIP-0010 derivation";
+        let path = "m/44'/784'/0'/0'";
+
+        let keypair1 = KeyPair::derive_from_seed("account-0", seed, path).unwrap();
+        let keypair2 = KeyPair::derive_from_seed("account-0", seed, path).unwrap();
+
+        assert_eq!(keypair1.private_key, keypair2.private_key);
+        assert_eq!(keypair1.public_key, keypair2.public_key);
+    }
+
+    #[test]
+    fn test_derive_from_seed_differs_by_path() {
+        let seed = b"test seed for SLIP-0010 derivation";
+
+        let account_0 = KeyPair::derive_from_seed("account-0", seed, "m/44'/784'/0'/0'").unwrap();
+        let account_1 = KeyPair::derive_from_seed("account-1", seed, "m/44'/784'/1'/0'").unwrap();
+
+        assert_ne!(account_0.private_key, account_1.private_key);
+    }
+
+    #[test]
+    fn test_derive_from_seed_rejects_unhardened_component() {
+        let seed = b"test seed for SLIP-0010 derivation";
+        let result = KeyPair::derive_from_seed("account-0", seed, "m/44'/784'/0/0'");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_derive_from_seed_rejects_path_without_leading_m() {
+        let seed = b"test seed for SLIP-0010 derivation";
+        let result = KeyPair::derive_from_seed("account-0", seed, "44'/784'/0'/0'");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_with_private_key_matches_field() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let borrowed = keypair.with_private_key(|key| *key);
+        assert_eq!(borrowed, keypair.private_key);
+    }
+
+    #[test]
+    fn test_drop_zeroizes_private_key() {
+        let private_key_ptr: *const [u8; 32];
+        {
+            let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+            private_key_ptr = std::ptr::addr_of!(keypair.private_key);
+        }
+        // SAFETY: the memory is still valid to read immediately after `keypair`
+        // goes out of scope (it hasn't been reused yet), purely to observe
+        // that `Drop` scrubbed it rather than leaving the old key bytes behind.
+        let scrubbed = unsafe { std::ptr::read(private_key_ptr) };
+        assert_eq!(scrubbed, [0u8; 32]);
+    }
+
+    #[test]
+    fn test_write_then_read_keypair_file_round_trips() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("id.json");
+
+        keypair.write_to_file(&path).unwrap();
+        let loaded = KeyPair::read_from_file("test", &path).unwrap();
+
+        assert_eq!(loaded.private_key, keypair.private_key);
+        assert_eq!(loaded.public_key, keypair.public_key);
+        assert_eq!(loaded.address_string, keypair.address_string);
+    }
+
+    #[test]
+    fn test_read_from_file_rejects_wrong_length() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("id.json");
+        std::fs::write(&path, "[1,2,3]").unwrap();
+
+        let result = KeyPair::read_from_file("test", &path);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sign_message_round_trips_with_verify_message() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let message = b"login challenge: 1234567890";
+
+        let signature = keypair.sign_message(message);
+        assert!(signature.verify_message(&keypair.address_string, message));
+    }
+
+    #[test]
+    fn test_sign_message_rejects_wrong_message_and_is_not_raw_signature() {
+        let keypair = KeyPair::from_hex_private_key("test", hex::encode([7u8; 32])).unwrap();
+        let message = b"login challenge: 1234567890";
+
+        let signature = keypair.sign_message(message);
+        assert!(!signature.verify_message(&keypair.address_string, b"a different message"));
+
+        // A signed-message signature must not verify as a raw signature over
+        // the same message -- that's the whole point of domain separation.
+        assert!(!signature.verify(&keypair.address_string, message));
+    }
+
+    #[test]
+    fn test_pubkey_from_str_accepts_native_and_hex_forms() {
+        let bytes = [3u8; 32];
+        let native = Pubkey::from_bytes(&bytes);
+
+        let parsed_native: Pubkey = native.as_str().parse().unwrap();
+        assert_eq!(parsed_native, native);
+
+        let parsed_hex: Pubkey = hex::encode(bytes).parse().unwrap();
+        assert_eq!(parsed_hex, native);
+    }
+
+    #[test]
+    fn test_pubkey_from_str_rejects_garbage() {
+        let result: Result<Pubkey, _> = "not a pubkey".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_signature_from_str_accepts_native_and_hex_forms() {
+        let bytes = [4u8; 64];
+        let native = Signature::from_bytes(&bytes);
+
+        let parsed_native: Signature = native.as_str().parse().unwrap();
+        assert_eq!(parsed_native, native);
+
+        let parsed_hex: Signature = hex::encode(bytes).parse().unwrap();
+        assert_eq!(parsed_hex, native);
+    }
+
+    #[test]
+    fn test_signature_from_str_rejects_garbage() {
+        let result: Result<Signature, _> = "not a signature".parse();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_pubkey_decode_error_is_bad_checksum_on_tampered_checksum() {
+        let bytes = [5u8; 32];
+        let mut encoded = tn_public_address::tn_pubkey_to_address_string(&bytes);
+        let last = encoded.pop().unwrap();
+        encoded.push(if last == 'A' { 'B' } else { 'A' });
+
+        let err = Pubkey::new(encoded).unwrap_err();
+        let validation_err = err.downcast_ref::<ValidationError>().unwrap();
+        assert!(matches!(
+            validation_err,
+            ValidationError::PubkeyDecode(DecodeError::BadChecksum)
+        ));
+    }
+
+    #[test]
+    fn test_decode_error_from_code_maps_every_known_code() {
+        assert_eq!(
+            DecodeError::from_code(-1, 10, 46),
+            DecodeError::BadLength {
+                expected: 46,
+                got: 10
+            }
+        );
+        assert_eq!(DecodeError::from_code(-2, 46, 46), DecodeError::BadPrefix);
+        assert_eq!(
+            DecodeError::from_code(-3, 46, 46),
+            DecodeError::InvalidCharacter
+        );
+        assert_eq!(
+            DecodeError::from_code(-4, 46, 46),
+            DecodeError::InvalidCharacter
+        );
+        assert_eq!(DecodeError::from_code(-5, 46, 46), DecodeError::BadChecksum);
+    }
+
+    #[test]
+    fn test_signature_decode_error_is_bad_checksum_on_tampered_checksum() {
+        let bytes = [6u8; 64];
+        let mut encoded = tn_signature_encoding::tn_signature_to_string(&bytes);
+        let last = encoded.pop().unwrap();
+        encoded.push(if last == 'A' { 'B' } else { 'A' });
+
+        let err = Signature::new(encoded).unwrap_err();
+        let validation_err = err.downcast_ref::<ValidationError>().unwrap();
+        assert!(matches!(
+            validation_err,
+            ValidationError::SignatureDecode(DecodeError::BadChecksum)
+        ));
+    }
 }
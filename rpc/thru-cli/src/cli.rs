@@ -1,6 +1,6 @@
 //! CLI argument parsing and command definitions
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 /// Thru CLI - Command-line interface for the Thru blockchain
 #[derive(Parser)]
@@ -16,10 +16,39 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub quiet: bool,
 
+    /// Print additional detail in human-readable output
+    #[arg(long, global = true)]
+    pub verbose: bool,
+
+    /// Output results as CSV (one row per record)
+    #[arg(long, global = true)]
+    pub csv: bool,
+
+    /// How to render token amounts in human-readable output
+    #[arg(long, global = true, value_enum, default_value = "token")]
+    pub display_units: DisplayUnits,
+
+    /// Minimum confirmation depth for streaming subscription updates (see
+    /// `output::print_subscription_update`); no command prints such updates,
+    /// so this flag is accepted but not read
+    #[arg(long, global = true)]
+    pub confirmations: Option<u64>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// Units to render token amounts in
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum DisplayUnits {
+    /// Raw base-unit integer only
+    Raw,
+    /// Decimal token amount only
+    Token,
+    /// Both the decimal token amount and the raw base-unit integer
+    Both,
+}
+
 /// Available CLI commands
 #[derive(Subcommand)]
 pub enum Commands {
@@ -48,6 +48,7 @@ pub async fn handle_account_command(
     config: &Config,
     subcommand: AccountCommands,
     json_format: bool,
+    csv_format: bool,
 ) -> Result<(), CliError> {
     match subcommand {
         AccountCommands::Create { key_name } => {
@@ -67,6 +68,7 @@ pub async fn handle_account_command(
                 page_size,
                 page_token,
                 json_format,
+                csv_format,
             )
             .await
         }
@@ -90,6 +92,7 @@ async fn list_account_transactions(
     page_size: Option<u32>,
     page_token: Option<String>,
     json_format: bool,
+    csv_format: bool,
 ) -> Result<(), CliError> {
     let account_pubkey = crate::commands::rpc::resolve_account_input(account_input, config)?;
     let client = create_rpc_client(config)?;
@@ -98,13 +101,33 @@ async fn list_account_transactions(
         .list_transactions_for_account(&account_pubkey, page_size, page_token)
         .await?;
 
-    let account_str = account_pubkey.to_string();
+    let account = account_pubkey.to_string();
     let next_page_token = page.next_page_token.clone();
     let signatures: Vec<String> = page.signatures.iter().map(|sig| sig.to_string()).collect();
 
-    let response =
-        output::create_account_transactions_response(&account_str, signatures, next_page_token);
-    output::print_output(response, json_format);
+    if csv_format {
+        let rows: Vec<output::AccountTransactionRow> = signatures
+            .iter()
+            .enumerate()
+            .map(|(idx, signature)| output::AccountTransactionRow {
+                index: idx + 1,
+                account: account.clone(),
+                signature: signature.clone(),
+            })
+            .collect();
+        match crate::csv::to_csv_string(&rows) {
+            Ok(csv) => print!("{}", csv),
+            Err(e) => output::print_error(&format!("Failed to render CSV: {}", e)),
+        }
+        return Ok(());
+    }
+
+    let info = output::AccountTransactionsInfo {
+        account,
+        signatures,
+        next_page_token,
+    };
+    output::print_rendered(&info, output::OutputFormat::from_flags(json_format, false));
     Ok(())
 }
 
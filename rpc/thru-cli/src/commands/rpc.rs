@@ -6,7 +6,7 @@ use std::time::Duration;
 use thru_base::tn_tools::Pubkey;
 use tonic_health::pb::health_check_response::ServingStatus;
 
-use crate::config::Config;
+use crate::config::{AuthMode, Config};
 use crate::crypto::keypair_from_hex;
 use crate::error::CliError;
 use crate::output;
@@ -57,7 +57,7 @@ pub fn resolve_account_input(input: Option<&str>, config: &Config) -> Result<Pub
 }
 
 /// Execute the getVersion command
-pub async fn get_version(config: &Config, json_format: bool) -> Result<(), CliError> {
+pub async fn get_version(config: &Config, format: output::OutputFormat) -> Result<(), CliError> {
     let client = create_rpc_client(config)?;
     let versions = client.get_version().await?;
 
@@ -70,13 +70,7 @@ pub async fn get_version(config: &Config, json_format: bool) -> Result<(), CliEr
         .cloned()
         .unwrap_or_else(|| "unknown".to_string());
 
-    if json_format {
-        let response = output::create_version_response(&thru_node, &thru_rpc);
-        output::print_output(response, true);
-    } else {
-        println!("thru-node: {}", thru_node);
-        println!("thru-rpc: {}", thru_rpc);
-    }
+    output::print_rendered(&output::VersionInfo { thru_node, thru_rpc }, format);
 
     Ok(())
 }
@@ -308,7 +302,8 @@ pub async fn get_account_info(
 pub async fn get_balance(
     config: &Config,
     account_input: Option<&str>,
-    json_format: bool,
+    format: output::OutputFormat,
+    display_units: crate::cli::DisplayUnits,
 ) -> Result<(), CliError> {
     let client = create_rpc_client(config)?;
 
@@ -317,13 +312,29 @@ pub async fn get_balance(
 
     match client.get_balance(&pubkey).await {
         Ok(balance) => {
-            let response = output::create_balance_response(&pubkey.to_string(), balance);
-            output::print_output(response, json_format);
+            let mut info = output::BalanceInfo {
+                pubkey: pubkey.to_string(),
+                balance,
+                owner: None,
+                data_size: None,
+                state_counter: None,
+                display_units,
+            };
+
+            if format == output::OutputFormat::DisplayVerbose {
+                if let Ok(Some(account)) = client.get_account_info(&pubkey, None).await {
+                    info.owner = Some(account.owner.to_string());
+                    info.data_size = Some(account.data_size);
+                    info.state_counter = Some(account.seq);
+                }
+            }
+
+            output::print_rendered(&info, format);
             Ok(())
         }
         Err(e) => {
             let error_msg = format!("Failed to get balance: {}", e);
-            if json_format {
+            if format == output::OutputFormat::Json {
                 let error_response = serde_json::json!({
                     "error": error_msg
                 });
@@ -341,12 +352,18 @@ fn create_rpc_client(config: &Config) -> Result<Client, CliError> {
     let rpc_url = config.get_grpc_url()?;
     let timeout = Duration::from_secs(config.timeout_seconds);
 
-    ClientBuilder::new()
-        .http_endpoint(rpc_url)
-        .timeout(timeout)
-        .auth_token(config.auth_token.clone())
-        .build()
-        .map_err(|e| e.into())
+    let builder = ClientBuilder::new().http_endpoint(rpc_url).timeout(timeout);
+
+    let builder = match config.auth_mode {
+        AuthMode::Signed => {
+            let default_key = config.keys.get_default_key()?;
+            let keypair = keypair_from_hex(default_key)?;
+            builder.signing_key(keypair)
+        }
+        AuthMode::Token => builder.auth_token(config.auth_token.clone()),
+    };
+
+    builder.build().map_err(|e| e.into())
 }
 
 fn health_status_to_str(status: ServingStatus) -> &'static str {
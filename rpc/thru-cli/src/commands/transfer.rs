@@ -18,7 +18,8 @@ pub async fn handle_transfer_command(
     src: &str,
     dst: &str,
     value: u64,
-    json_format: bool,
+    format: output::OutputFormat,
+    display_units: crate::cli::DisplayUnits,
 ) -> Result<(), CliError> {
     // Validate transfer amount
     if value == 0 {
@@ -119,29 +120,31 @@ pub async fn handle_transfer_command(
             transaction_details.execution_result as i64, vm_error_msg
         );
 
-        let response = output::create_transfer_response(
-            src,
-            dst,
+        let info = output::TransferInfo {
+            src: src.to_string(),
+            dst: dst.to_string(),
             value,
-            transaction_details.signature.as_str(),
-            "failed",
-        );
-        output::print_output(response, json_format);
+            signature: transaction_details.signature.as_str().to_string(),
+            status: "failed".to_string(),
+            display_units,
+        };
+        output::print_rendered(&info, format);
 
         return Err(CliError::TransactionSubmission(error_msg));
     }
 
     // Format and display the result
-    let response = output::create_transfer_response(
-        src,
-        dst,
+    let info = output::TransferInfo {
+        src: src.to_string(),
+        dst: dst.to_string(),
         value,
-        transaction_details.signature.as_str(),
-        "success",
-    );
-    output::print_output(response, json_format);
+        signature: transaction_details.signature.as_str().to_string(),
+        status: "success".to_string(),
+        display_units,
+    };
+    output::print_rendered(&info, format);
 
-    if !json_format {
+    if format != output::OutputFormat::Json {
         output::print_success(&format!(
             "Transfer completed successfully. Transaction signature: {}",
             transaction_details.signature.as_str()
@@ -1190,16 +1190,16 @@ async fn upload_program(
         .await
     {
         Ok(session) => {
-            let response = output::create_program_upload_response(
-                "success",
-                session.progress.total_transactions,
-                session.progress.completed_transactions,
-                program_data.len(),
-                Some(&session.meta_account.to_string()),
-                Some(&session.buffer_account.to_string()),
-            );
+            let info = output::ProgramUploadInfo {
+                status: "success".to_string(),
+                total_transactions: session.progress.total_transactions,
+                completed_transactions: session.progress.completed_transactions,
+                program_size: program_data.len(),
+                meta_account: Some(session.meta_account.to_string()),
+                buffer_account: Some(session.buffer_account.to_string()),
+            };
 
-            output::print_output(response, json_format);
+            output::print_rendered(&info, output::OutputFormat::from_flags(json_format, false));
 
             if !json_format {
                 output::print_success("Program upload completed successfully");
@@ -87,7 +87,7 @@ fn convert_hex_to_thru_pubkey(hex_pubkey: String, output_format: OutputFormat) -
             });
             println!("{}", serde_json::to_string_pretty(&result)?);
         }
-        OutputFormat::Text => {
+        _ => {
             println!("Hex public key:  {}", hex_pubkey);
             println!("Thru public key: {}", thru_pubkey.as_str());
         }
@@ -119,7 +119,7 @@ fn convert_thru_to_hex_pubkey(thrufmt_pubkey: String, output_format: OutputForma
             });
             println!("{}", serde_json::to_string_pretty(&result)?);
         }
-        OutputFormat::Text => {
+        _ => {
             println!("Thru public key: {}", thrufmt_pubkey);
             println!("Hex public key:  {}", hex_pubkey);
         }
@@ -159,7 +159,7 @@ fn convert_hex_to_thru_signature(hex_signature: String, output_format: OutputFor
             });
             println!("{}", serde_json::to_string_pretty(&result)?);
         }
-        OutputFormat::Text => {
+        _ => {
             println!("Hex signature:  {}", hex_signature);
             println!("Thru signature: {}", thru_signature.as_str());
         }
@@ -194,7 +194,7 @@ fn convert_thru_to_hex_signature(
             });
             println!("{}", serde_json::to_string_pretty(&result)?);
         }
-        OutputFormat::Text => {
+        _ => {
             println!("Thru signature: {}", thrufmt_signature);
             println!("Hex signature:  {}", hex_signature);
         }
@@ -149,6 +149,18 @@ impl KeyManager {
     }
 }
 
+/// How RPC requests authenticate with the node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthMode {
+    /// Send `auth_token` as a static bearer token on every request
+    #[default]
+    Token,
+    /// Sign each request with the default key and authenticate via
+    /// [`thru_client::ClientBuilder::signing_key`] instead of a static token
+    Signed,
+}
+
 /// Configuration structure for the Thru CLI
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -185,6 +197,10 @@ pub struct Config {
     /// Optional authorization token for HTTP requests
     pub auth_token: Option<String>,
 
+    /// How RPC requests authenticate with the node
+    #[serde(default)]
+    pub auth_mode: AuthMode,
+
     /// Custom toolchain installation path
     pub toolchain_path: Option<PathBuf>,
 
@@ -217,6 +233,7 @@ impl Default for Config {
             timeout_seconds: 30,
             max_retries: 3,
             auth_token: None,
+            auth_mode: AuthMode::default(),
             toolchain_path: None,
             toolchain_version: None,
             sdk_paths: None,
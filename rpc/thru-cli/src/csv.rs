@@ -0,0 +1,633 @@
+//! A minimal serde `Serializer` that flattens a value into CSV text
+//!
+//! Only two top-level shapes are supported: a single struct (rendered as a
+//! one-row table) or a sequence of structs (rendered as a header row plus one
+//! data row per element). Every field must serialize to a scalar; nested
+//! structs, sequences, and maps are rejected with [`CsvError`] instead of
+//! panicking, and fields containing commas, quotes, or newlines are quoted.
+
+use serde::ser::{self, Impossible, Serialize};
+use thiserror::Error;
+
+/// Error returned when a value's shape cannot be represented as CSV
+#[derive(Error, Debug)]
+#[error("{0}")]
+pub struct CsvError(String);
+
+impl ser::Error for CsvError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        CsvError(msg.to_string())
+    }
+}
+
+/// Serialize `value` to a CSV string
+///
+/// `value` must be either a struct (rendered as one data row) or a sequence
+/// of structs (rendered as a header row plus one row per element).
+pub fn to_csv_string<T: Serialize + ?Sized>(value: &T) -> Result<String, CsvError> {
+    value.serialize(RootSerializer)
+}
+
+fn quote_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn render_rows(rows: Vec<Vec<(String, String)>>) -> String {
+    let Some(first) = rows.first() else {
+        return String::new();
+    };
+    let header: Vec<&str> = first.iter().map(|(k, _)| k.as_str()).collect();
+
+    let mut out = header
+        .iter()
+        .map(|h| quote_field(h))
+        .collect::<Vec<_>>()
+        .join(",");
+    out.push('\n');
+
+    for row in &rows {
+        let line = header
+            .iter()
+            .map(|key| {
+                row.iter()
+                    .find(|(k, _)| k == key)
+                    .map(|(_, v)| quote_field(v))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out
+}
+
+macro_rules! unsupported_scalar {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, _v: $ty) -> Result<Self::Ok, Self::Error> {
+            Err(CsvError::custom(
+                "CSV output requires a struct or a list of structs at the top level",
+            ))
+        }
+    };
+}
+
+/// Top-level serializer: accepts a struct (one row) or a sequence of structs
+struct RootSerializer;
+
+impl ser::Serializer for RootSerializer {
+    type Ok = String;
+    type Error = CsvError;
+
+    type SerializeSeq = RowCollector;
+    type SerializeTuple = Impossible<String, CsvError>;
+    type SerializeTupleStruct = Impossible<String, CsvError>;
+    type SerializeTupleVariant = Impossible<String, CsvError>;
+    type SerializeMap = Impossible<String, CsvError>;
+    type SerializeStruct = RowFieldCollector<String>;
+    type SerializeStructVariant = Impossible<String, CsvError>;
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(RowCollector {
+            rows: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(RowFieldCollector::new(render_rows))
+    }
+
+    unsupported_scalar!(serialize_bool, bool);
+    unsupported_scalar!(serialize_i8, i8);
+    unsupported_scalar!(serialize_i16, i16);
+    unsupported_scalar!(serialize_i32, i32);
+    unsupported_scalar!(serialize_i64, i64);
+    unsupported_scalar!(serialize_u8, u8);
+    unsupported_scalar!(serialize_u16, u16);
+    unsupported_scalar!(serialize_u32, u32);
+    unsupported_scalar!(serialize_u64, u64);
+    unsupported_scalar!(serialize_f32, f32);
+    unsupported_scalar!(serialize_f64, f64);
+    unsupported_scalar!(serialize_char, char);
+    unsupported_scalar!(serialize_str, &str);
+    unsupported_scalar!(serialize_bytes, &[u8]);
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(CsvError::custom(
+            "CSV output requires a struct or a list of structs at the top level",
+        ))
+    }
+}
+
+/// Accumulates one row (struct) per sequence element, then renders the table
+struct RowCollector {
+    rows: Vec<Vec<(String, String)>>,
+}
+
+impl ser::SerializeSeq for RowCollector {
+    type Ok = String;
+    type Error = CsvError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let fields = value.serialize(RowSerializer)?;
+        self.rows.push(fields);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(render_rows(self.rows))
+    }
+}
+
+/// Serializes one sequence element (expected to be a struct) into field pairs
+struct RowSerializer;
+
+impl ser::Serializer for RowSerializer {
+    type Ok = Vec<(String, String)>;
+    type Error = CsvError;
+
+    type SerializeSeq = Impossible<Vec<(String, String)>, CsvError>;
+    type SerializeTuple = Impossible<Vec<(String, String)>, CsvError>;
+    type SerializeTupleStruct = Impossible<Vec<(String, String)>, CsvError>;
+    type SerializeTupleVariant = Impossible<Vec<(String, String)>, CsvError>;
+    type SerializeMap = Impossible<Vec<(String, String)>, CsvError>;
+    type SerializeStruct = RowFieldCollector<Vec<(String, String)>>;
+    type SerializeStructVariant = Impossible<Vec<(String, String)>, CsvError>;
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(RowFieldCollector::new(|fields| fields))
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("each row must be a struct"))
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(CsvError::custom("nested sequences are not supported in CSV rows"))
+    }
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(CsvError::custom("tuples are not supported in CSV rows"))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(CsvError::custom("tuples are not supported in CSV rows"))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(CsvError::custom("tuples are not supported in CSV rows"))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(CsvError::custom("maps are not supported in CSV rows"))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(CsvError::custom("enum struct variants are not supported in CSV rows"))
+    }
+}
+
+/// Accumulates a single struct's fields as `(name, stringified value)` pairs,
+/// then converts them into `Out` via `finish` on [`end`](ser::SerializeStruct::end)
+struct RowFieldCollector<Out> {
+    fields: Vec<(String, String)>,
+    finish: fn(Vec<(String, String)>) -> Out,
+}
+
+impl<Out> RowFieldCollector<Out> {
+    fn new(finish: fn(Vec<(String, String)>) -> Out) -> Self {
+        Self {
+            fields: Vec::new(),
+            finish,
+        }
+    }
+}
+
+impl<Out> ser::SerializeStruct for RowFieldCollector<Out> {
+    type Ok = Out;
+    type Error = CsvError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let rendered = value.serialize(ValueSerializer)?;
+        self.fields.push((key.to_string(), rendered));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok((self.finish)(self.fields))
+    }
+}
+
+/// Serializes a single scalar field value to its CSV-cell string form
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = String;
+    type Error = CsvError;
+
+    type SerializeSeq = Impossible<String, CsvError>;
+    type SerializeTuple = Impossible<String, CsvError>;
+    type SerializeTupleStruct = Impossible<String, CsvError>;
+    type SerializeTupleVariant = Impossible<String, CsvError>;
+    type SerializeMap = Impossible<String, CsvError>;
+    type SerializeStruct = Impossible<String, CsvError>;
+    type SerializeStructVariant = Impossible<String, CsvError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("byte fields are not supported in CSV output"))
+    }
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.to_string())
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(CsvError::custom("nested enum values are not supported in CSV output"))
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(CsvError::custom("nested sequences are not supported in CSV output fields"))
+    }
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(CsvError::custom("nested tuples are not supported in CSV output fields"))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(CsvError::custom("nested tuples are not supported in CSV output fields"))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(CsvError::custom("nested tuples are not supported in CSV output fields"))
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(CsvError::custom("nested maps are not supported in CSV output fields"))
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(CsvError::custom("nested structs are not supported in CSV output fields"))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(CsvError::custom("nested structs are not supported in CSV output fields"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Row {
+        index: usize,
+        account: String,
+        signature: String,
+    }
+
+    #[test]
+    fn single_struct_renders_header_and_one_row() {
+        let row = Row {
+            index: 0,
+            account: "ta_abc".to_string(),
+            signature: "sig1".to_string(),
+        };
+        let csv = to_csv_string(&row).unwrap();
+        assert_eq!(csv, "index,account,signature\n0,ta_abc,sig1\n");
+    }
+
+    #[test]
+    fn seq_of_structs_renders_one_row_per_element() {
+        let rows = vec![
+            Row {
+                index: 0,
+                account: "ta_abc".to_string(),
+                signature: "sig1".to_string(),
+            },
+            Row {
+                index: 1,
+                account: "ta_abc".to_string(),
+                signature: "sig2".to_string(),
+            },
+        ];
+        let csv = to_csv_string(&rows).unwrap();
+        assert_eq!(
+            csv,
+            "index,account,signature\n0,ta_abc,sig1\n1,ta_abc,sig2\n"
+        );
+    }
+
+    #[test]
+    fn empty_seq_renders_empty_string() {
+        let rows: Vec<Row> = Vec::new();
+        assert_eq!(to_csv_string(&rows).unwrap(), "");
+    }
+
+    #[test]
+    fn fields_with_commas_are_quoted() {
+        let row = Row {
+            index: 0,
+            account: "a,b".to_string(),
+            signature: "sig".to_string(),
+        };
+        let csv = to_csv_string(&row).unwrap();
+        assert_eq!(csv, "index,account,signature\n0,\"a,b\",sig\n");
+    }
+
+    #[test]
+    fn bare_scalar_is_rejected() {
+        assert!(to_csv_string(&42u64).is_err());
+    }
+
+    #[test]
+    fn nested_struct_field_is_rejected() {
+        #[derive(Serialize)]
+        struct Nested {
+            inner: Row,
+        }
+        let nested = Nested {
+            inner: Row {
+                index: 0,
+                account: "a".to_string(),
+                signature: "s".to_string(),
+            },
+        };
+        assert!(to_csv_string(&nested).is_err());
+    }
+}
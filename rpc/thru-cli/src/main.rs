@@ -9,6 +9,7 @@ mod cli;
 mod commands;
 mod config;
 mod crypto;
+mod csv;
 mod error;
 mod grpc_client;
 mod output;
@@ -31,9 +32,14 @@ async fn main() -> Result<()> {
     let config = Config::load().await?;
 
     // Execute the command
+    let format = if cli.csv {
+        output::OutputFormat::Csv
+    } else {
+        output::OutputFormat::from_flags(cli.json, cli.verbose)
+    };
     match cli.command {
         Commands::GetVersion => {
-            commands::rpc::get_version(&config, cli.json).await?;
+            commands::rpc::get_version(&config, format).await?;
         }
         Commands::GetHealth => {
             commands::rpc::get_health(&config, cli.json).await?;
@@ -41,15 +47,39 @@ async fn main() -> Result<()> {
         Commands::GetHeight => {
             commands::rpc::get_height(&config, cli.json).await?;
         }
-        Commands::GetAccountInfo { account } => {
-            commands::rpc::get_account_info(&config, account.as_deref(), cli.json).await?;
+        Commands::GetAccountInfo {
+            account,
+            data_start,
+            data_len,
+        } => {
+            commands::rpc::get_account_info(
+                &config,
+                account.as_deref(),
+                data_start,
+                data_len,
+                cli.json,
+            )
+            .await?;
         }
         Commands::GetBalance { account } => {
-            commands::rpc::get_balance(&config, account.as_deref(), cli.json).await?;
+            commands::rpc::get_balance(
+                &config,
+                account.as_deref(),
+                format,
+                cli.display_units,
+            )
+            .await?;
         }
         Commands::Transfer { src, dst, value } => {
-            commands::transfer::handle_transfer_command(&config, &src, &dst, value, cli.json)
-                .await?;
+            commands::transfer::handle_transfer_command(
+                &config,
+                &src,
+                &dst,
+                value,
+                format,
+                cli.display_units,
+            )
+            .await?;
         }
         Commands::Token { subcommand } => {
             commands::token::handle_token_command(&config, subcommand, cli.json).await?;
@@ -61,7 +91,8 @@ async fn main() -> Result<()> {
             commands::keys::handle_keys_command(&config, subcommand, cli.json).await?;
         }
         Commands::Account { subcommand } => {
-            commands::account::handle_account_command(&config, subcommand, cli.json).await?;
+            commands::account::handle_account_command(&config, subcommand, cli.json, cli.csv)
+                .await?;
         }
         Commands::Program { subcommand } => {
             commands::program::handle_program_command(&config, subcommand, cli.json).await?;
@@ -1,16 +1,130 @@
 //! Output formatting utilities for the Thru CLI
 
+use crate::cli::DisplayUnits;
+use crate::csv;
 use colored::*;
+use serde::Serialize;
 use serde_json::{Value, json};
 use std::collections::HashMap;
+use std::fmt;
+
+/// Number of decimal places in the THRU token's base unit, matching
+/// `WTHRU_DECIMALS` since wrapped THRU represents native THRU 1:1
+pub const THRU_DECIMALS: u32 = 8;
+
+/// Convert a base-unit amount to a decimal token amount string
+///
+/// Divides `n` by `10^decimals`, formatting the integer part with thousands
+/// separators. The fractional part is trimmed of trailing zeros but never
+/// loses significant digits (unlike a float-based conversion).
+pub fn base_units_to_tokens(n: u64, decimals: u32) -> String {
+    let factor = 10u64.pow(decimals);
+    let whole = n / factor;
+    let frac = n % factor;
+
+    if frac == 0 {
+        return format_number(whole);
+    }
+
+    let frac_str = format!("{:0width$}", frac, width = decimals as usize);
+    let frac_str = frac_str.trim_end_matches('0');
+    format!("{}.{}", format_number(whole), frac_str)
+}
+
+/// Render a base-unit amount per `display_units`, e.g.
+/// `12_345.67 THRU (1234567000000 base units)`
+fn render_amount(n: u64, display_units: DisplayUnits) -> String {
+    match display_units {
+        DisplayUnits::Raw => format!("{} base units", format_number(n)),
+        DisplayUnits::Token => format!("{} THRU", base_units_to_tokens(n, THRU_DECIMALS)),
+        DisplayUnits::Both => format!(
+            "{} THRU ({} base units)",
+            base_units_to_tokens(n, THRU_DECIMALS),
+            n
+        ),
+    }
+}
+
+/// A terser [`fmt::Display`] for CLI output, used when rendering in the default
+/// (non-verbose) mode. The default forwards to `Display`; implementors override
+/// it to drop detail that's only interesting with `--verbose`.
+pub trait QuietDisplay: fmt::Display {
+    /// Write the terse form of `self` to `w`
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        write!(w, "{}", self)
+    }
+}
+
+/// A richer [`fmt::Display`] for CLI output, used when `--verbose` is set. The
+/// default forwards to `Display`; implementors override it to add detail that
+/// would otherwise clutter the default (quiet) view.
+pub trait VerboseDisplay: fmt::Display {
+    /// Write the verbose form of `self` to `w`
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        write!(w, "{}", self)
+    }
+}
 
 /// Output format options
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
-    /// JSON output format
+    /// Pretty-printed JSON output
     Json,
-    /// Human-readable text format
+    /// Single-line JSON output
+    JsonCompact,
+    /// Human-readable text format, used by commands that print their own
+    /// text rather than a [`QuietDisplay`]/[`VerboseDisplay`] type;
+    /// [`OutputFormat::formatted_string`] renders it via `Display`
     Text,
+    /// Human-readable text via `Display`
+    Display,
+    /// Terse human-readable text via `QuietDisplay`; the default when neither
+    /// `--json` nor `--verbose` is given
+    DisplayQuiet,
+    /// Detailed human-readable text via `VerboseDisplay`
+    DisplayVerbose,
+    /// CSV output: one header row plus one data row per record, via a custom
+    /// `serde::Serializer` (see [`crate::csv`])
+    Csv,
+}
+
+impl OutputFormat {
+    /// Pick a rendering format from the CLI's `--json`/`--verbose` flags
+    pub fn from_flags(json: bool, verbose: bool) -> Self {
+        match (json, verbose) {
+            (true, _) => Self::Json,
+            (false, true) => Self::DisplayVerbose,
+            (false, false) => Self::DisplayQuiet,
+        }
+    }
+
+    /// Render `item` according to this format
+    pub fn formatted_string<T>(&self, item: &T) -> String
+    where
+        T: Serialize + fmt::Display + QuietDisplay + VerboseDisplay,
+    {
+        match self {
+            Self::Json => {
+                serde_json::to_string_pretty(item).unwrap_or_else(|_| "{}".to_string())
+            }
+            Self::JsonCompact => {
+                serde_json::to_string(item).unwrap_or_else(|_| "{}".to_string())
+            }
+            Self::Text | Self::Display => item.to_string(),
+            Self::DisplayQuiet => {
+                let mut out = String::new();
+                let _ = QuietDisplay::write_str(item, &mut out);
+                out
+            }
+            Self::DisplayVerbose => {
+                let mut out = String::new();
+                let _ = VerboseDisplay::write_str(item, &mut out);
+                out
+            }
+            Self::Csv => csv::to_csv_string(item)
+                .unwrap_or_else(|e| format!("error: {}", e)),
+        }
+    }
 }
 
 /// Format and print output based on the JSON flag
@@ -25,22 +139,30 @@ pub fn print_output(data: Value, json_format: bool) {
     }
 }
 
+/// Format and print a typed response according to `format`
+///
+/// Unlike [`print_output`], which renders an untyped [`Value`] by matching on
+/// well-known top-level keys, this dispatches through [`QuietDisplay`] and
+/// [`VerboseDisplay`] so each response type controls its own terse and
+/// detailed renderings.
+pub fn print_rendered<T>(item: &T, format: OutputFormat)
+where
+    T: Serialize + fmt::Display + QuietDisplay + VerboseDisplay,
+{
+    println!("{}", format.formatted_string(item));
+}
+
 /// Print data in human-readable format
 fn print_human_readable(data: &Value) {
     match data {
         Value::Object(map) => {
             for (key, value) in map {
                 match key.as_str() {
-                    "version" => print_version_info(value),
                     "health" => print_health_info(value),
                     "account_info" => print_account_info(value),
-                    "balance" => print_balance_info(value),
-                    "transfer" => print_transfer_info(value),
-                    "program_upload" => print_program_upload_info(value),
                     "program_cleanup" => print_program_cleanup_info(value),
                     "keys" => print_keys_info(value),
                     "account_create" => print_account_create_info(value),
-                    "account_transactions" => print_account_transactions(value),
                     _ => println!("{}: {}", key.cyan(), format_value(value)),
                 }
             }
@@ -91,21 +213,6 @@ fn format_value_ext(value: &Value, thousand_separator: bool) -> String {
     }
 }
 
-/// Print version information
-fn print_version_info(data: &Value) {
-    if let Value::Object(version_data) = data {
-        println!("{}", "Version Information".bold().green());
-
-        if let Some(thru_node) = version_data.get("thru-node") {
-            println!("  {}: {}", "Thru Node".cyan(), format_value(thru_node));
-        }
-
-        if let Some(thru_rpc) = version_data.get("thru-rpc") {
-            println!("  {}: {}", "Thru RPC".cyan(), format_value(thru_rpc));
-        }
-    }
-}
-
 /// Print health information
 fn print_health_info(data: &Value) {
     match data {
@@ -144,11 +251,11 @@ fn print_account_info(data: &Value) {
             println!("  {}: {}", "Public Key".cyan(), format_value(pubkey));
         }
 
-        if let Some(balance) = account_data.get("balance") {
+        if let Some(balance) = account_data.get("balance").and_then(Value::as_u64) {
             println!(
-                "  {}: {}",
+                "  {}: {} THRU",
                 "Balance".cyan(),
-                format_value_ext(balance, true)
+                base_units_to_tokens(balance, THRU_DECIMALS)
             );
         }
 
@@ -224,115 +331,6 @@ fn print_account_create_info(data: &Value) {
     }
 }
 
-/// Print account transaction signatures
-fn print_account_transactions(data: &Value) {
-    if let Value::Object(tx_data) = data {
-        println!("{}", "Account Transactions".bold().green());
-
-        if let Some(account) = tx_data.get("account") {
-            println!("  {}: {}", "Account".cyan(), format_value(account));
-        }
-
-        match tx_data.get("signatures").and_then(|value| value.as_array()) {
-            Some(signatures) if signatures.is_empty() => {
-                println!("  {}", "No transactions found.".italic());
-            }
-            Some(signatures) => {
-                println!("  {}:", "Signatures".cyan());
-                for (idx, sig) in signatures.iter().enumerate() {
-                    println!("    {:>2}. {}", idx + 1, format_value(sig));
-                }
-            }
-            None => {
-                println!("  {}", "No transactions found.".italic());
-            }
-        }
-
-        if let Some(Value::String(token)) = tx_data.get("nextPageToken") {
-            if !token.is_empty() {
-                println!("  {}: {}", "Next Page Token".cyan(), token);
-            }
-        }
-    }
-}
-
-/// Print balance information
-fn print_balance_info(data: &Value) {
-    if let Value::Object(balance_data) = data {
-        if let Some(pubkey) = balance_data.get("pubkey") {
-            println!("{}: {}", "Account".cyan(), format_value(pubkey));
-        }
-
-        if let Some(balance) = balance_data.get("balance") {
-            println!(
-                "{}: {}",
-                "Balance".bold().green(),
-                format_value_ext(balance, true)
-            );
-        }
-    } else {
-        println!(
-            "{}: {}",
-            "Balance".bold().green(),
-            format_value_ext(data, true)
-        );
-    }
-}
-
-/// Print program upload information
-fn print_program_upload_info(data: &Value) {
-    if let Value::Object(upload_data) = data {
-        println!("{}", "Program Upload".bold().green());
-
-        if let Some(status) = upload_data.get("status") {
-            let status_str = format_value(status);
-            let colored_status = match status_str.as_str() {
-                "success" => status_str.green(),
-                "failed" => status_str.red(),
-                "in_progress" => status_str.yellow(),
-                _ => status_str.normal(),
-            };
-            println!("  {}: {}", "Status".cyan(), colored_status);
-        }
-
-        if let Some(transactions) = upload_data.get("total_transactions") {
-            println!(
-                "  {}: {}",
-                "Total Transactions".cyan(),
-                format_value(transactions)
-            );
-        }
-
-        if let Some(completed) = upload_data.get("completed_transactions") {
-            println!("  {}: {}", "Completed".cyan(), format_value(completed));
-        }
-
-        if let Some(program_size) = upload_data.get("program_size") {
-            println!(
-                "  {}: {} bytes",
-                "Program Size".cyan(),
-                format_value(program_size)
-            );
-        }
-
-        if let Some(meta_account) = upload_data.get("meta_account") {
-            println!(
-                "  {}: {}",
-                "Meta Account".cyan(),
-                format_value(meta_account)
-            );
-        }
-
-        if let Some(buffer_account) = upload_data.get("buffer_account") {
-            println!(
-                "  {}: {}",
-                "Buffer Account".cyan(),
-                format_value(buffer_account)
-            );
-        }
-    }
-}
-
 /// Print program cleanup information
 fn print_program_cleanup_info(data: &Value) {
     if let Value::Object(cleanup_data) = data {
@@ -416,37 +414,63 @@ fn print_keys_info(data: &Value) {
     }
 }
 
-/// Print transfer information
-fn print_transfer_info(data: &Value) {
-    if let Value::Object(transfer_data) = data {
-        println!("{}", "Transfer Information".bold().green());
-
-        if let Some(src) = transfer_data.get("src") {
-            println!("  {}: {}", "Source".cyan(), format_value(src));
-        }
-
-        if let Some(dst) = transfer_data.get("dst") {
-            println!("  {}: {}", "Destination".cyan(), format_value(dst));
-        }
-
-        if let Some(value) = transfer_data.get("value") {
-            println!("  {}: {}", "Value".cyan(), format_value(value));
-        }
-
-        if let Some(signature) = transfer_data.get("signature") {
-            println!("  {}: {}", "Signature".cyan(), format_value(signature));
+/// Confirmation depth at which a streaming subscription update is considered
+/// finalized, for coloring purposes in [`print_subscription_update`]
+const FINALIZED_CONFIRMATIONS: u64 = 32;
+
+/// Print one streaming subscription update (account/signature/program
+/// notification), pushing a single line to stdout immediately rather than
+/// buffering, unlike the one-shot [`print_output`]
+///
+/// `data` is the `{ "subscription": {...} }` payload produced by
+/// [`create_subscription_update_response`]. If `confirmation_filter` is set,
+/// updates with fewer confirmations than the filter are suppressed.
+pub fn print_subscription_update(data: &Value, json_format: bool, confirmation_filter: Option<u64>) {
+    let Some(subscription) = data.get("subscription") else {
+        return;
+    };
+    let confirmations = subscription
+        .get("confirmations")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    if let Some(min_confirmations) = confirmation_filter {
+        if confirmations < min_confirmations {
+            return;
         }
+    }
 
-        if let Some(status) = transfer_data.get("status") {
-            let status_str = format_value(status);
-            let colored_status = match status_str.as_str() {
-                "success" => status_str.green(),
-                "failed" => status_str.red(),
-                _ => status_str.normal(),
-            };
-            println!("  {}: {}", "Status".cyan(), colored_status);
-        }
+    if json_format {
+        println!(
+            "{}",
+            serde_json::to_string(data).unwrap_or_else(|_| "{}".to_string())
+        );
+        return;
     }
+
+    let kind = subscription.get("kind").map(format_value).unwrap_or_default();
+    let pubkey = subscription.get("pubkey").map(format_value).unwrap_or_default();
+    let slot = subscription
+        .get("slot")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+
+    let confirmations_str = confirmations.to_string();
+    let colored_confirmations = if confirmations == 0 {
+        confirmations_str.red()
+    } else if confirmations < FINALIZED_CONFIRMATIONS {
+        confirmations_str.yellow()
+    } else {
+        confirmations_str.green()
+    };
+
+    println!(
+        "[{}] {} @ slot {} ({} confirmations)",
+        pubkey.cyan(),
+        kind,
+        slot,
+        colored_confirmations
+    );
 }
 
 /// Print error message
@@ -469,17 +493,6 @@ pub fn print_info(message: &str) {
     println!("{}: {}", "Info".bold().blue(), message);
 }
 
-/// Create a JSON response for version information
-pub fn create_version_response(thru_node: &str, thru_rpc: &str) -> Value {
-    json!({
-        "getversion": {
-            "status": "success",
-            "thru-node": thru_node,
-            "thru-rpc": thru_rpc
-        }
-    })
-}
-
 /// Create a JSON response for health information
 pub fn create_health_response(status: &str) -> Value {
     json!({
@@ -496,70 +509,6 @@ pub fn create_account_info_response(account_data: HashMap<String, Value>) -> Val
     })
 }
 
-/// Create a JSON response for account transaction listings
-pub fn create_account_transactions_response(
-    account: &str,
-    signatures: Vec<String>,
-    next_page_token: Option<String>,
-) -> Value {
-    let mut response = json!({
-        "account_transactions": {
-            "account": account,
-            "signatures": signatures,
-        }
-    });
-
-    if let Some(token) = next_page_token {
-        if let Some(obj) = response
-            .get_mut("account_transactions")
-            .and_then(|value| value.as_object_mut())
-        {
-            obj.insert("nextPageToken".to_string(), json!(token));
-        }
-    }
-
-    response
-}
-
-/// Create a JSON response for balance information
-pub fn create_balance_response(pubkey: &str, balance: u64) -> Value {
-    json!({
-        "balance": {
-            "pubkey": pubkey,
-            "balance": balance
-        }
-    })
-}
-
-/// Create a JSON response for program upload
-pub fn create_program_upload_response(
-    status: &str,
-    total_transactions: usize,
-    completed_transactions: usize,
-    program_size: usize,
-    meta_account: Option<&str>,
-    buffer_account: Option<&str>,
-) -> Value {
-    let mut response = json!({
-        "program_upload": {
-            "status": status,
-            "total_transactions": total_transactions,
-            "completed_transactions": completed_transactions,
-            "program_size": program_size
-        }
-    });
-
-    if let Some(meta) = meta_account {
-        response["program_upload"]["meta_account"] = json!(meta);
-    }
-
-    if let Some(buffer) = buffer_account {
-        response["program_upload"]["buffer_account"] = json!(buffer);
-    }
-
-    response
-}
-
 /// Create a JSON response for program cleanup
 pub fn create_program_cleanup_response(status: &str, message: &str) -> Value {
     json!({
@@ -579,25 +528,6 @@ pub fn create_keys_list_response(key_names: Vec<String>) -> Value {
     })
 }
 
-/// Create a JSON response for transfer operations
-pub fn create_transfer_response(
-    src: &str,
-    dst: &str,
-    value: u64,
-    signature: &str,
-    status: &str,
-) -> Value {
-    json!({
-        "transfer": {
-            "src": src,
-            "dst": dst,
-            "value": value,
-            "signature": signature,
-            "status": status
-        }
-    })
-}
-
 /// Create a JSON response for keys operations
 pub fn create_keys_operation_response(
     operation: &str,
@@ -636,3 +566,295 @@ pub fn create_account_create_response(
         }
     })
 }
+
+/// Create a JSON payload for one streaming subscription update (account,
+/// signature, or program notification), to be rendered incrementally by
+/// [`print_subscription_update`]
+pub fn create_subscription_update_response(
+    kind: &str,
+    pubkey: &str,
+    slot: u64,
+    confirmations: u64,
+    data: Value,
+) -> Value {
+    json!({
+        "subscription": {
+            "kind": kind,
+            "pubkey": pubkey,
+            "slot": slot,
+            "confirmations": confirmations,
+            "data": data
+        }
+    })
+}
+
+/// Version information returned by `getVersion`
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionInfo {
+    /// Thru node version string
+    pub thru_node: String,
+    /// Thru RPC version string
+    pub thru_rpc: String,
+}
+
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node {} / rpc {}", self.thru_node, self.thru_rpc)
+    }
+}
+
+impl QuietDisplay for VersionInfo {}
+
+impl VerboseDisplay for VersionInfo {
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(w, "{}", "Version Information".bold().green())?;
+        writeln!(w, "  {}: {}", "Thru Node".cyan(), self.thru_node)?;
+        write!(w, "  {}: {}", "Thru RPC".cyan(), self.thru_rpc)
+    }
+}
+
+/// Balance information returned by `getBalance`
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceInfo {
+    /// Account public key
+    pub pubkey: String,
+    /// Balance in base units
+    pub balance: u64,
+    /// Account owner, only populated in verbose mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Account data size in bytes, only populated in verbose mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_size: Option<u64>,
+    /// Account state counter, only populated in verbose mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_counter: Option<u64>,
+    /// Units to render `balance` in for human-readable output; never serialized,
+    /// since JSON consumers always get the raw `balance` field
+    #[serde(skip)]
+    pub display_units: DisplayUnits,
+}
+
+impl fmt::Display for BalanceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            "Balance".bold().green(),
+            render_amount(self.balance, self.display_units)
+        )
+    }
+}
+
+impl QuietDisplay for BalanceInfo {}
+
+impl VerboseDisplay for BalanceInfo {
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(w, "{}: {}", "Account".cyan(), self.pubkey)?;
+        writeln!(
+            w,
+            "{}: {}",
+            "Balance".bold().green(),
+            render_amount(self.balance, self.display_units)
+        )?;
+        if let Some(owner) = &self.owner {
+            writeln!(w, "{}: {}", "Owner".cyan(), owner)?;
+        }
+        if let Some(data_size) = self.data_size {
+            writeln!(w, "{}: {}", "Data Size".cyan(), data_size)?;
+        }
+        if let Some(state_counter) = self.state_counter {
+            write!(w, "{}: {}", "State Counter".cyan(), state_counter)?;
+        }
+        Ok(())
+    }
+}
+
+/// Transfer confirmation returned after submitting a transfer transaction
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferInfo {
+    /// Source account
+    pub src: String,
+    /// Destination account
+    pub dst: String,
+    /// Amount transferred, in base units
+    pub value: u64,
+    /// Transaction signature
+    pub signature: String,
+    /// Transaction status ("success" or "failed")
+    pub status: String,
+    /// Units to render `value` in for human-readable output; never serialized,
+    /// since JSON consumers always get the raw `value` field
+    #[serde(skip)]
+    pub display_units: DisplayUnits,
+}
+
+impl fmt::Display for TransferInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self.status.as_str() {
+            "success" => self.status.green(),
+            "failed" => self.status.red(),
+            _ => self.status.normal(),
+        };
+        write!(f, "{}: {}", "Status".cyan(), status)
+    }
+}
+
+impl QuietDisplay for TransferInfo {}
+
+impl VerboseDisplay for TransferInfo {
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(w, "{}", "Transfer Information".bold().green())?;
+        writeln!(w, "  {}: {}", "Source".cyan(), self.src)?;
+        writeln!(w, "  {}: {}", "Destination".cyan(), self.dst)?;
+        writeln!(
+            w,
+            "  {}: {}",
+            "Value".cyan(),
+            render_amount(self.value, self.display_units)
+        )?;
+        writeln!(w, "  {}: {}", "Signature".cyan(), self.signature)?;
+        let status = match self.status.as_str() {
+            "success" => self.status.green(),
+            "failed" => self.status.red(),
+            _ => self.status.normal(),
+        };
+        write!(w, "  {}: {}", "Status".cyan(), status)
+    }
+}
+
+/// Program upload progress/result returned by the `program upload` command
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgramUploadInfo {
+    /// Upload status ("success", "failed", or "in_progress")
+    pub status: String,
+    /// Total number of chunk transactions required
+    pub total_transactions: usize,
+    /// Number of chunk transactions completed so far
+    pub completed_transactions: usize,
+    /// Size of the uploaded program, in bytes
+    pub program_size: usize,
+    /// Meta account created for the program, once known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta_account: Option<String>,
+    /// Buffer account used to stage the program's bytes, once known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer_account: Option<String>,
+}
+
+impl fmt::Display for ProgramUploadInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self.status.as_str() {
+            "success" => self.status.green(),
+            "failed" => self.status.red(),
+            "in_progress" => self.status.yellow(),
+            _ => self.status.normal(),
+        };
+        write!(
+            f,
+            "{}: {} ({}/{})",
+            "Status".cyan(),
+            status,
+            self.completed_transactions,
+            self.total_transactions
+        )
+    }
+}
+
+impl QuietDisplay for ProgramUploadInfo {}
+
+impl VerboseDisplay for ProgramUploadInfo {
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(w, "{}", "Program Upload".bold().green())?;
+        let status = match self.status.as_str() {
+            "success" => self.status.green(),
+            "failed" => self.status.red(),
+            "in_progress" => self.status.yellow(),
+            _ => self.status.normal(),
+        };
+        writeln!(w, "  {}: {}", "Status".cyan(), status)?;
+        writeln!(
+            w,
+            "  {}: {}",
+            "Total Transactions".cyan(),
+            self.total_transactions
+        )?;
+        writeln!(
+            w,
+            "  {}: {}",
+            "Completed".cyan(),
+            self.completed_transactions
+        )?;
+        writeln!(
+            w,
+            "  {}: {} bytes",
+            "Program Size".cyan(),
+            self.program_size
+        )?;
+        if let Some(meta_account) = &self.meta_account {
+            writeln!(w, "  {}: {}", "Meta Account".cyan(), meta_account)?;
+        }
+        if let Some(buffer_account) = &self.buffer_account {
+            write!(w, "  {}: {}", "Buffer Account".cyan(), buffer_account)?;
+        }
+        Ok(())
+    }
+}
+
+/// Transaction signatures for an account, returned by `account transactions`
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountTransactionsInfo {
+    /// Account the signatures belong to
+    pub account: String,
+    /// Transaction signatures, most recent first
+    pub signatures: Vec<String>,
+    /// Pagination token for the next page of results, if any
+    #[serde(rename = "nextPageToken", skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+}
+
+impl fmt::Display for AccountTransactionsInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} transaction(s) for {}",
+            self.signatures.len(),
+            self.account
+        )
+    }
+}
+
+impl QuietDisplay for AccountTransactionsInfo {}
+
+impl VerboseDisplay for AccountTransactionsInfo {
+    fn write_str(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(w, "{}", "Account Transactions".bold().green())?;
+        writeln!(w, "  {}: {}", "Account".cyan(), self.account)?;
+        if self.signatures.is_empty() {
+            writeln!(w, "  {}", "No transactions found.".italic())?;
+        } else {
+            writeln!(w, "  {}:", "Signatures".cyan())?;
+            for (idx, sig) in self.signatures.iter().enumerate() {
+                writeln!(w, "    {:>2}. {}", idx + 1, sig)?;
+            }
+        }
+        if let Some(token) = &self.next_page_token {
+            if !token.is_empty() {
+                write!(w, "  {}: {}", "Next Page Token".cyan(), token)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One row of `account transactions --csv` output: `signatures` exploded into
+/// one record per signature, since CSV has no native way to nest an array
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountTransactionRow {
+    /// 1-based position of this signature in the page
+    pub index: usize,
+    /// Account the signature belongs to
+    pub account: String,
+    /// Transaction signature
+    pub signature: String,
+}
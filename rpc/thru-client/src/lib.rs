@@ -25,10 +25,14 @@
 pub mod error;
 
 use std::collections::HashMap;
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 use base64::{Engine as _, engine::general_purpose};
+use prost::Message as _;
 use prost_types::Duration as ProstDuration;
+use rand::TryRngCore;
+use rand::rngs::OsRng;
 use tokio::time;
 use tonic::{
     Request, Status,
@@ -40,7 +44,7 @@ use tonic_health::pb::{HealthCheckRequest, HealthCheckResponse, health_client::H
 use std::convert::TryFrom;
 
 use thru_base::rpc_types::{MakeStateProofConfig, ProofType};
-use thru_base::tn_tools::{Pubkey, Signature};
+use thru_base::tn_tools::{KeyPair, Pubkey, Signature};
 use thru_grpc_client::thru::{
     common::v1 as commonv1,
     core::v1 as corev1,
@@ -63,6 +67,7 @@ pub struct ClientBuilder {
     endpoint: Endpoint,
     timeout: Duration,
     auth_token: Option<String>,
+    signing_key: Option<KeyPair>,
 }
 
 impl ClientBuilder {
@@ -75,6 +80,7 @@ impl ClientBuilder {
             endpoint: default_endpoint,
             timeout: Duration::from_secs(30),
             auth_token: None,
+            signing_key: None,
         }
     }
 
@@ -109,6 +115,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Sign every request with `keypair` instead of sending a static
+    /// `auth_token`, mirroring the ACME JWS flow: each request carries a
+    /// protected header (`alg`/`nonce`/`url`/`kid`) and a signature over
+    /// `b64(header) || "." || b64(payload)`, letting the server verify the
+    /// request's origin. The nonce is minted locally rather than issued by
+    /// the server (see `take_nonce`), so this doesn't provide real replay
+    /// protection yet. Takes precedence over `auth_token` if both are set.
+    pub fn signing_key(mut self, keypair: KeyPair) -> Self {
+        self.signing_key = Some(keypair);
+        self
+    }
+
     /// Build the client.
     pub fn build(self) -> Result<Client> {
         let channel = self.endpoint.connect_lazy();
@@ -124,10 +142,16 @@ impl ClientBuilder {
                 None => None,
             };
 
+        let signer = self.signing_key.map(|keypair| SigningState {
+            keypair,
+            next_nonce: Mutex::new(None),
+        });
+
         Ok(Client {
             channel,
             timeout: self.timeout,
             auth_header,
+            signer,
         })
     }
 }
@@ -138,11 +162,20 @@ impl Default for ClientBuilder {
     }
 }
 
+/// Per-client state for ACME-JWS-style signed requests.
+struct SigningState {
+    keypair: KeyPair,
+    /// Single-use nonce queued up for the next outgoing request, if one has
+    /// already been fetched and not yet consumed.
+    next_nonce: Mutex<Option<String>>,
+}
+
 /// High-level gRPC client for the Thru blockchain.
 pub struct Client {
     channel: Channel,
     timeout: Duration,
     auth_header: Option<MetadataValue<tonic::metadata::Ascii>>,
+    signer: Option<SigningState>,
 }
 
 impl Client {
@@ -173,11 +206,12 @@ impl Client {
             ..Default::default()
         };
 
-        let mut grpc_request = Request::new(request);
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout);
-
-        match client.get_account(grpc_request).await {
+        match self
+            .call_signed("GetAccount", &request, self.timeout, |r| {
+                client.get_account(r)
+            })
+            .await
+        {
             Ok(response) => {
                 let account = response.into_inner();
                 Ok(Some(Account::from_proto(account)?))
@@ -200,11 +234,14 @@ impl Client {
         let mut client = QueryServiceClient::new(self.channel.clone())
             .max_decoding_message_size(128 * 1024 * 1024) /* 128 MB */
             .max_encoding_message_size(128 * 1024 * 1024); /* 128 MB */
-        let mut request = Request::new(servicesv1::GetVersionRequest {});
-        self.apply_metadata(&mut request);
-        request.set_timeout(self.timeout);
-
-        let response = client.get_version(request).await?;
+        let response = self
+            .call_signed(
+                "GetVersion",
+                &servicesv1::GetVersionRequest {},
+                self.timeout,
+                |r| client.get_version(r),
+            )
+            .await?;
         Ok(response.into_inner().versions)
     }
 
@@ -213,13 +250,16 @@ impl Client {
         let mut client = HealthClient::new(self.channel.clone())
             .max_decoding_message_size(128 * 1024 * 1024) /* 128 MB */
             .max_encoding_message_size(128 * 1024 * 1024); /* 128 MB */
-        let mut request = Request::new(HealthCheckRequest {
-            service: String::new(),
-        });
-        self.apply_metadata(&mut request);
-        request.set_timeout(self.timeout);
-
-        let response = client.check(request).await?;
+        let response = self
+            .call_signed(
+                "Check",
+                &HealthCheckRequest {
+                    service: String::new(),
+                },
+                self.timeout,
+                |r| client.check(r),
+            )
+            .await?;
         Ok(response.into_inner())
     }
 
@@ -228,11 +268,14 @@ impl Client {
         let mut client = QueryServiceClient::new(self.channel.clone())
             .max_decoding_message_size(128 * 1024 * 1024) /* 128 MB */
             .max_encoding_message_size(128 * 1024 * 1024); /* 128 MB */
-        let mut grpc_request = Request::new(servicesv1::GetHeightRequest {});
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout);
-
-        let response = client.get_height(grpc_request).await?;
+        let response = self
+            .call_signed(
+                "GetHeight",
+                &servicesv1::GetHeightRequest {},
+                self.timeout,
+                |r| client.get_height(r),
+            )
+            .await?;
         let message = response.into_inner();
         Ok(BlockHeight {
             finalized_height: message.finalized,
@@ -276,11 +319,14 @@ impl Client {
             filter: None,
         };
 
-        let mut grpc_request = Request::new(request);
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout);
-
-        let response = client.list_transactions_for_account(grpc_request).await?;
+        let response = self
+            .call_signed(
+                "ListTransactionsForAccount",
+                &request,
+                self.timeout,
+                |r| client.list_transactions_for_account(r),
+            )
+            .await?;
         let message = response.into_inner();
 
         let mut signatures = Vec::with_capacity(message.signatures.len());
@@ -507,11 +553,14 @@ impl Client {
             }),
         };
 
-        let mut grpc_request = Request::new(request);
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout);
-
-        let response = client.generate_state_proof(grpc_request).await?;
+        let response = self
+            .call_signed(
+                "GenerateStateProof",
+                &request,
+                self.timeout,
+                |r| client.generate_state_proof(r),
+            )
+            .await?;
         let proof_message = response.into_inner().proof.ok_or_else(|| {
             ClientError::TransactionSubmission("empty state proof response".into())
         })?;
@@ -556,25 +605,118 @@ impl Client {
         })
     }
 
-    fn apply_metadata<T>(&self, request: &mut Request<T>) {
-        if let Some(header) = &self.auth_header {
+    /// Attach request authentication: a signed JWS-style header/signature
+    /// pair if `signing_key` was configured, otherwise the static bearer
+    /// token, otherwise nothing.
+    fn apply_auth<T: prost::Message>(&self, request: &mut Request<T>, method: &str) -> Result<()> {
+        if let Some(signer) = &self.signer {
+            let nonce = self.take_nonce(signer);
+            let payload = request.get_ref().encode_to_vec();
+            let header_b64 =
+                general_purpose::URL_SAFE_NO_PAD.encode(signed_request_header(
+                    &nonce,
+                    method,
+                    &signer.keypair.public_key_str(),
+                ));
+            let payload_b64 = general_purpose::URL_SAFE_NO_PAD.encode(&payload);
+            let signing_input = format!("{}.{}", header_b64, payload_b64);
+            let signature = signer.keypair.sign_raw(signing_input.as_bytes());
+            let signature_b64 = general_purpose::URL_SAFE_NO_PAD.encode(signature);
+
+            let metadata = request.metadata_mut();
+            metadata.insert(
+                "thru-jws-protected",
+                MetadataValue::try_from(header_b64).map_err(|e| {
+                    ClientError::Validation(format!("invalid JWS header metadata: {}", e))
+                })?,
+            );
+            metadata.insert(
+                "thru-jws-signature",
+                MetadataValue::try_from(signature_b64).map_err(|e| {
+                    ClientError::Validation(format!("invalid JWS signature metadata: {}", e))
+                })?,
+            );
+        } else if let Some(header) = &self.auth_header {
             request
                 .metadata_mut()
                 .insert("authorization", header.clone());
         }
+
+        Ok(())
+    }
+
+    /// Take the queued single-use nonce if one's waiting, otherwise mint a
+    /// fresh one.
+    ///
+    /// ACME issues nonces from a dedicated `newNonce` endpoint and refreshes
+    /// them off every response's `Replay-Nonce` header. This node's
+    /// generated gRPC client doesn't expose an equivalent nonce RPC or
+    /// response field to harvest one from — there's no `.proto` source for
+    /// it in this checkout — so nonces are minted locally instead. That's
+    /// enough to populate the protected header's shape and to exercise the
+    /// retry-on-replay path below, but it doesn't provide real replay
+    /// protection until the node serves an authoritative nonce.
+    fn take_nonce(&self, signer: &SigningState) -> String {
+        signer
+            .next_nonce
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(Self::mint_nonce)
+    }
+
+    fn mint_nonce() -> String {
+        let mut nonce_bytes = [0u8; 16];
+        let mut rng = OsRng;
+        rng.try_fill_bytes(&mut nonce_bytes)
+            .expect("OS RNG must be available to mint a nonce");
+        general_purpose::URL_SAFE_NO_PAD.encode(nonce_bytes)
+    }
+
+    /// Run a signed RPC call, retrying once with a fresh nonce if the server
+    /// rejects the first attempt as a replay.
+    async fn call_signed<T, R, Fut>(
+        &self,
+        method: &str,
+        request_body: &T,
+        timeout: Duration,
+        mut send: impl FnMut(Request<T>) -> Fut,
+    ) -> std::result::Result<tonic::Response<R>, Status>
+    where
+        T: prost::Message + Clone,
+        Fut: std::future::Future<Output = std::result::Result<tonic::Response<R>, Status>>,
+    {
+        let mut request = Request::new(request_body.clone());
+        if let Err(e) = self.apply_auth(&mut request, method) {
+            return Err(Status::invalid_argument(e.to_string()));
+        }
+        request.set_timeout(timeout);
+
+        match send(request).await {
+            Err(status) if is_bad_nonce(&status) => {
+                let mut retry_request = Request::new(request_body.clone());
+                if let Err(e) = self.apply_auth(&mut retry_request, method) {
+                    return Err(Status::invalid_argument(e.to_string()));
+                }
+                retry_request.set_timeout(timeout);
+                send(retry_request).await
+            }
+            other => other,
+        }
     }
 
     async fn send_transaction(&self, transaction: &[u8]) -> Result<[u8; 64]> {
         let mut client = CommandServiceClient::new(self.channel.clone())
             .max_decoding_message_size(128 * 1024 * 1024) /* 128 MB */
             .max_encoding_message_size(128 * 1024 * 1024); /* 128 MB */
-        let mut grpc_request = Request::new(servicesv1::SendTransactionRequest {
+        let request = servicesv1::SendTransactionRequest {
             raw_transaction: transaction.to_vec(),
-        });
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout);
-
-        let response = client.send_transaction(grpc_request).await?;
+        };
+        let response = self
+            .call_signed("SendTransaction", &request, self.timeout, |r| {
+                client.send_transaction(r)
+            })
+            .await?;
         let signature = response.into_inner().signature.ok_or_else(|| {
             ClientError::TransactionSubmission("missing signature in response".into())
         })?;
@@ -601,11 +743,15 @@ impl Client {
             }),
         };
 
-        let mut grpc_request = Request::new(request);
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout + timeout);
-
-        let mut stream = client.track_transaction(grpc_request).await?.into_inner();
+        let response = self
+            .call_signed(
+                "TrackTransaction",
+                &request,
+                self.timeout + timeout,
+                |r| client.track_transaction(r),
+            )
+            .await?;
+        let mut stream = response.into_inner();
         let deadline = Instant::now() + timeout;
 
         while Instant::now() < deadline {
@@ -655,11 +801,13 @@ impl Client {
                 min_consensus: Some(commonv1::ConsensusStatus::Included as i32),
             };
 
-            let mut grpc_request = Request::new(request);
-            self.apply_metadata(&mut grpc_request);
-            grpc_request.set_timeout(self.timeout);
+            let call_result = self
+                .call_signed("GetTransaction", &request, self.timeout, |r| {
+                    client.get_transaction(r)
+                })
+                .await;
 
-            match client.get_transaction(grpc_request).await {
+            match call_result {
                 Ok(response) => {
                     let transaction = response.into_inner();
                     if transaction.slot.unwrap_or(0) != 0 {
@@ -699,11 +847,11 @@ impl Client {
             ..Default::default()
         };
 
-        let mut grpc_request = Request::new(request);
-        self.apply_metadata(&mut grpc_request);
-        grpc_request.set_timeout(self.timeout);
-
-        let response = client.get_raw_account(grpc_request).await?;
+        let response = self
+            .call_signed("GetRawAccount", &request, self.timeout, |r| {
+                client.get_raw_account(r)
+            })
+            .await?;
         Ok(response.into_inner())
     }
 }
@@ -740,6 +888,23 @@ fn should_retry(status: &Status) -> bool {
     )
 }
 
+/// Build the JWS-style protected header for a signed request, as a small
+/// hand-rolled JSON object (this crate doesn't otherwise depend on a JSON
+/// serializer, so a serde dependency isn't worth adding for one struct).
+fn signed_request_header(nonce: &str, method: &str, kid: &str) -> Vec<u8> {
+    format!(
+        r#"{{"alg":"EdDSA","nonce":"{}","url":"{}","kid":"{}"}}"#,
+        nonce, method, kid
+    )
+    .into_bytes()
+}
+
+/// Whether `status` indicates the signed request's nonce was rejected as a
+/// replay, so the caller should mint a fresh one and retry once.
+fn is_bad_nonce(status: &Status) -> bool {
+    status.code() == tonic::Code::Unauthenticated && status.message().contains("nonce")
+}
+
 fn pubkey_bytes(pubkey: &Pubkey) -> Result<[u8; 32]> {
     pubkey
         .to_bytes()
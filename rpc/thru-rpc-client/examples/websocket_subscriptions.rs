@@ -116,6 +116,7 @@ async fn main() -> Result<()> {
         program_id: bob.address_string.to_string(),
         data_slice: None,
         filters: Some(vec![ProgramAccountFilter::DataSize { data_size: 80 }]),
+        query: None,
     };
 
     let (program_id, mut program_rx) = ws_client.program_subscribe(program_config).await?;
@@ -181,6 +182,7 @@ async fn main() -> Result<()> {
         signatures: Some(vec![signature.as_str().to_string()]),
         programs: Some(vec![bob.address_string.to_string()]),
         starts_with_bytes: None,
+        query: None,
     };
 
     let (events_id, mut events_rx) = ws_client.events_subscribe(Some(events_config)).await?;
@@ -191,6 +193,7 @@ async fn main() -> Result<()> {
         signatures: None,
         programs: None,
         starts_with_bytes: None,
+        query: None,
     };
 
     let (all_events_id, mut all_events_rx) =
@@ -30,6 +30,13 @@ pub struct ClientConfig {
     pub ws_reconnect_attempts: usize,
     /// Delay between WebSocket reconnection attempts
     pub ws_reconnect_delay: Duration,
+    /// Interval between keepalive pings sent on an otherwise idle WebSocket connection
+    pub ws_keepalive_interval: Duration,
+    /// How long to wait without receiving any frame (including pongs) before treating the
+    /// connection as dead and triggering reconnection. Should be set larger than
+    /// `ws_keepalive_interval` so a healthy connection gets at least one ping round-trip before
+    /// being timed out
+    pub ws_idle_timeout: Duration,
     /// Optional authorization token for HTTP requests
     pub auth_token: Option<String>,
 }
@@ -43,6 +50,8 @@ impl Default for ClientConfig {
             max_connections: 100,
             ws_reconnect_attempts: 5,
             ws_reconnect_delay: Duration::from_secs(1),
+            ws_keepalive_interval: Duration::from_secs(30),
+            ws_idle_timeout: Duration::from_secs(90),
             auth_token: None,
         }
     }
@@ -98,6 +107,19 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the interval between keepalive pings sent on an otherwise idle WebSocket connection
+    pub fn ws_keepalive_interval(mut self, interval: Duration) -> Self {
+        self.config.ws_keepalive_interval = interval;
+        self
+    }
+
+    /// Set how long to wait without receiving any frame before treating the WebSocket
+    /// connection as dead and triggering reconnection
+    pub fn ws_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.ws_idle_timeout = timeout;
+        self
+    }
+
     /// Set authorization token for HTTP requests
     pub fn auth_token(mut self, token: Option<String>) -> Self {
         self.config.auth_token = token;
@@ -340,6 +362,8 @@ mod tests {
             .max_connections(200)
             .ws_reconnect_attempts(10)
             .ws_reconnect_delay(Duration::from_secs(2))
+            .ws_keepalive_interval(Duration::from_secs(15))
+            .ws_idle_timeout(Duration::from_secs(45))
             .build();
 
         assert_eq!(
@@ -354,6 +378,8 @@ mod tests {
         assert_eq!(client.config.max_connections, 200);
         assert_eq!(client.config.ws_reconnect_attempts, 10);
         assert_eq!(client.config.ws_reconnect_delay, Duration::from_secs(2));
+        assert_eq!(client.config.ws_keepalive_interval, Duration::from_secs(15));
+        assert_eq!(client.config.ws_idle_timeout, Duration::from_secs(45));
     }
 
     #[test]
@@ -368,6 +394,8 @@ mod tests {
         assert_eq!(config.max_connections, 100);
         assert_eq!(config.ws_reconnect_attempts, 5);
         assert_eq!(config.ws_reconnect_delay, Duration::from_secs(1));
+        assert_eq!(config.ws_keepalive_interval, Duration::from_secs(30));
+        assert_eq!(config.ws_idle_timeout, Duration::from_secs(90));
     }
 
     #[tokio::test]
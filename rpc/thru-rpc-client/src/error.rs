@@ -257,6 +257,13 @@ pub enum SubscriptionError {
     /// Subscription already exists
     #[error("Subscription already exists")]
     AlreadyExists,
+
+    /// The manager's configured ceiling on active subscriptions has been reached
+    #[error("Maximum active subscriptions ({max}) reached")]
+    TooManyActiveSubscriptions {
+        /// The configured ceiling that was hit
+        max: usize,
+    },
 }
 
 /// Extension trait for adding context to errors
@@ -335,6 +342,12 @@ impl From<thru_base::tn_tools::ValidationError> for ValidationError {
             thru_base::tn_tools::ValidationError::InvalidSignature(s) => {
                 ValidationError::InvalidSignature(s)
             }
+            thru_base::tn_tools::ValidationError::PubkeyDecode(e) => {
+                ValidationError::InvalidPubkey(e.to_string())
+            }
+            thru_base::tn_tools::ValidationError::SignatureDecode(e) => {
+                ValidationError::InvalidSignature(e.to_string())
+            }
         }
     }
 }
@@ -435,6 +435,8 @@ mod tests {
             max_connections: 100,
             ws_reconnect_attempts: 5,
             ws_reconnect_delay: Duration::from_secs(1),
+            ws_keepalive_interval: Duration::from_secs(30),
+            ws_idle_timeout: Duration::from_secs(90),
             auth_token: None,
         }
     }
@@ -31,6 +31,7 @@
 pub mod client;
 pub mod error;
 pub mod http;
+pub mod pubsub_blocking;
 pub mod types;
 pub mod utils;
 pub mod websocket;
@@ -61,6 +62,11 @@ pub use types::{
     SignatureExecutionResult,
     SignatureNotification,
     SignatureStatus,
+    // Subscription query DSL
+    QueryCondition,
+    QueryOperator,
+    QueryValue,
+    SubscriptionQuery,
     // Transaction types
     TransactionDetails,
     TransactionResponse,
@@ -72,8 +78,9 @@ pub use thru_base::rpc_types::{MakeStateProofConfig, ProofType};
 
 // Re-export WebSocket types
 pub use websocket::{
-    AccountSubscriptionHandle, SignatureSubscriptionHandle, SlotSubscriptionHandle,
-    SubscriptionHandle, SubscriptionManager, WebSocketClient,
+    AccountSubscriptionHandle, BlockSubscriptionHandle, LogsSubscriptionHandle,
+    ProgramSubscriptionHandle, SignatureSubscriptionHandle, SlotSubscriptionHandle,
+    SubscriptionHandle, SubscriptionManager, VoteSubscriptionHandle, WebSocketClient,
 };
 
 // Version information
@@ -0,0 +1,339 @@
+//! Blocking facade over [`WebSocketClient`] for callers that don't run inside a Tokio runtime
+//!
+//! Every method on [`WebSocketClient`] is `async`, so using it at all normally requires a
+//! Tokio runtime somewhere in the caller's stack. [`PubsubClient`] owns a dedicated
+//! current-thread runtime internally and exposes synchronous equivalents of the common
+//! `*_subscribe` methods, modeled on the ergonomics of Solana's blocking `pubsub_client`:
+//! each call hands back a [`PubsubClientSubscription`] whose `std::sync::mpsc::Receiver` is
+//! fed by a bridge task pulling from the underlying `tokio::sync::mpsc::UnboundedReceiver`,
+//! and dropping the subscription issues the matching unsubscribe automatically.
+//!
+//! A current-thread runtime only makes progress while something is actively polling it, so
+//! a naive `Runtime::block_on` per call would leave every background task (the connection's
+//! message loop, the notification bridge, a dropped subscription's fire-and-forget
+//! unsubscribe) stalled the instant that call returns — exactly the gap a caller spends most
+//! of its time in, parked on [`PubsubClientSubscription::receiver`]. To avoid that, the
+//! runtime is instead driven continuously by a dedicated background thread, and every method
+//! here hands its work to that thread via [`tokio::runtime::Handle`] rather than calling
+//! `block_on` itself.
+
+use std::sync::mpsc as std_mpsc;
+use std::sync::Arc;
+
+use tokio::runtime::Handle;
+use tokio::sync::mpsc;
+use tokio::sync::oneshot;
+
+use crate::client::ClientConfig;
+use crate::error::{Result, ThruError};
+use crate::types::{
+    AccountInfoConfig, AccountNotification, BlockSubscriptionConfig, BlockSummaryNotification,
+    CommitmentLevel, LogsNotification, LogsSubscriptionConfig, ProgramNotification,
+    ProgramSubscriptionConfig, SignatureNotification, VoteNotification,
+};
+use crate::websocket::WebSocketClient;
+use thru_base::tn_tools::{Pubkey, Signature};
+
+/// A blocking handle to a single subscription created through [`PubsubClient`].
+///
+/// Notifications are read via [`PubsubClientSubscription::receiver`]. Dropping this value
+/// issues the matching unsubscribe request, fire-and-forget, so a caller doesn't have to
+/// remember to clean up — the same tradeoff [`crate::websocket::Subscription`]'s `Drop` impl
+/// makes on the async side.
+pub struct PubsubClientSubscription<T> {
+    /// Receives notifications bridged from the underlying async subscription
+    pub receiver: std_mpsc::Receiver<T>,
+    subscription_id: u64,
+    subscribe_method: &'static str,
+    handle: Handle,
+    ws_client: WebSocketClient,
+    // Keeps the background driver thread (see `RuntimeDriver`) alive for as long as this
+    // subscription is in use, even after the `PubsubClient` that created it is dropped.
+    _driver: Arc<RuntimeDriver>,
+}
+
+impl<T> std::fmt::Debug for PubsubClientSubscription<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PubsubClientSubscription")
+            .field("subscription_id", &self.subscription_id)
+            .field("subscribe_method", &self.subscribe_method)
+            .finish()
+    }
+}
+
+impl<T> PubsubClientSubscription<T> {
+    /// The subscription id returned by the server
+    pub fn subscription_id(&self) -> u64 {
+        self.subscription_id
+    }
+}
+
+impl<T> Drop for PubsubClientSubscription<T> {
+    fn drop(&mut self) {
+        let ws_client = self.ws_client.clone();
+        let subscribe_method = self.subscribe_method;
+        let subscription_id = self.subscription_id;
+        self.handle.spawn(async move {
+            ws_client
+                .unsubscribe_by_method(subscribe_method, subscription_id)
+                .await;
+        });
+    }
+}
+
+/// Keeps the background thread driving a [`PubsubClient`]'s runtime alive for as long as
+/// the client or any subscription created from it still exists, and signals it to shut
+/// down once the last one is dropped — otherwise that thread (and the runtime it owns)
+/// would never exit for the rest of the process.
+struct RuntimeDriver {
+    shutdown_tx: Option<oneshot::Sender<()>>,
+}
+
+impl Drop for RuntimeDriver {
+    fn drop(&mut self) {
+        // Dropping the sender resolves the background thread's `await` on the matching
+        // receiver with an error, which ends its `block_on` and lets the thread exit.
+        self.shutdown_tx.take();
+    }
+}
+
+/// Synchronous facade over [`WebSocketClient`] for callers outside a Tokio runtime
+///
+/// Owns a dedicated current-thread runtime, continuously driven by a background thread for
+/// as long as this client (or any subscription created from it) lives, so a
+/// [`PubsubClient`] is self-contained and doesn't depend on (or interfere with) a runtime
+/// the caller may or may not have.
+pub struct PubsubClient {
+    handle: Handle,
+    ws_client: WebSocketClient,
+    driver: Arc<RuntimeDriver>,
+}
+
+impl std::fmt::Debug for PubsubClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PubsubClient")
+            .field("ws_client", &self.ws_client)
+            .finish()
+    }
+}
+
+impl PubsubClient {
+    /// Connect to the WebSocket endpoint in `config`, blocking the calling thread until the
+    /// connection is established
+    pub fn new(config: ClientConfig) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| ThruError::Configuration(format!("failed to start runtime: {e}")))?;
+        let handle = runtime.handle().clone();
+
+        // `runtime` moves onto a dedicated thread that drives it until `shutdown_tx` is
+        // dropped (see `RuntimeDriver`). See the module doc comment for why something
+        // needs to keep driving it at all.
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        std::thread::spawn(move || {
+            runtime.block_on(async move {
+                let _ = shutdown_rx.await;
+            });
+        });
+        let driver = Arc::new(RuntimeDriver {
+            shutdown_tx: Some(shutdown_tx),
+        });
+
+        let ws_client = Self::run_blocking(&handle, WebSocketClient::new(config))?;
+        Ok(Self {
+            handle,
+            ws_client,
+            driver,
+        })
+    }
+
+    /// Run `future` to completion from a plain (non-async) calling thread, without itself
+    /// calling `block_on` — which would contend with the dedicated thread permanently
+    /// driving `handle`'s runtime (see [`Self::new`]). Instead, `future` is spawned as a
+    /// task on that runtime and its result handed back over a `std::sync::mpsc` channel, so
+    /// the calling thread just blocks on ordinary thread parking.
+    fn run_blocking<F>(handle: &Handle, future: F) -> F::Output
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (tx, rx) = std_mpsc::channel();
+        handle.spawn(async move {
+            let _ = tx.send(future.await);
+        });
+        rx.recv()
+            .expect("pubsub runtime driver thread exited unexpectedly")
+    }
+
+    /// Bridge an async subscription's notifications onto a `std::sync::mpsc::Receiver`, via
+    /// a task spawned on this client's runtime, and wrap the result in a
+    /// [`PubsubClientSubscription`] that unsubscribes `subscription_id` via
+    /// `subscribe_method` when dropped.
+    fn bridge_subscription<T>(
+        &self,
+        subscribe_method: &'static str,
+        subscription_id: u64,
+        mut async_rx: mpsc::UnboundedReceiver<T>,
+    ) -> PubsubClientSubscription<T>
+    where
+        T: Send + 'static,
+    {
+        let (sync_tx, sync_rx) = std_mpsc::channel();
+        self.handle.spawn(async move {
+            while let Some(notification) = async_rx.recv().await {
+                if sync_tx.send(notification).is_err() {
+                    break;
+                }
+            }
+        });
+
+        PubsubClientSubscription {
+            receiver: sync_rx,
+            subscription_id,
+            subscribe_method,
+            handle: self.handle.clone(),
+            ws_client: self.ws_client.clone(),
+            _driver: Arc::clone(&self.driver),
+        }
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::account_subscribe`]
+    pub fn account_subscribe(
+        &self,
+        pubkey: &Pubkey,
+        config: Option<AccountInfoConfig>,
+    ) -> Result<PubsubClientSubscription<AccountNotification>> {
+        let ws_client = self.ws_client.clone();
+        let pubkey = pubkey.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(&self.handle, async move {
+            ws_client.account_subscribe(&pubkey, config).await
+        })?;
+        Ok(self.bridge_subscription("accountSubscribe", subscription_id, async_rx))
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::signature_subscribe`]
+    pub fn signature_subscribe(
+        &self,
+        signature: &Signature,
+        commitment: Option<CommitmentLevel>,
+    ) -> Result<PubsubClientSubscription<SignatureNotification>> {
+        let ws_client = self.ws_client.clone();
+        let signature = signature.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(&self.handle, async move {
+            ws_client.signature_subscribe(&signature, commitment).await
+        })?;
+        Ok(self.bridge_subscription("signatureSubscribe", subscription_id, async_rx))
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::slot_subscribe`]
+    pub fn slot_subscribe(&self) -> Result<PubsubClientSubscription<serde_json::Value>> {
+        let ws_client = self.ws_client.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(
+            &self.handle,
+            async move { ws_client.slot_subscribe().await },
+        )?;
+        Ok(self.bridge_subscription("slotSubscribe", subscription_id, async_rx))
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::program_subscribe`]
+    pub fn program_subscribe(
+        &self,
+        config: ProgramSubscriptionConfig,
+    ) -> Result<PubsubClientSubscription<ProgramNotification>> {
+        let ws_client = self.ws_client.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(&self.handle, async move {
+            ws_client.program_subscribe(config).await
+        })?;
+        Ok(self.bridge_subscription("programSubscribe", subscription_id, async_rx))
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::logs_subscribe`]
+    pub fn logs_subscribe(
+        &self,
+        config: LogsSubscriptionConfig,
+    ) -> Result<PubsubClientSubscription<LogsNotification>> {
+        let ws_client = self.ws_client.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(&self.handle, async move {
+            ws_client.logs_subscribe(config).await
+        })?;
+        Ok(self.bridge_subscription("logsSubscribe", subscription_id, async_rx))
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::block_summary_subscribe`]
+    pub fn block_summary_subscribe(
+        &self,
+        config: Option<BlockSubscriptionConfig>,
+    ) -> Result<PubsubClientSubscription<BlockSummaryNotification>> {
+        let ws_client = self.ws_client.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(&self.handle, async move {
+            ws_client.block_summary_subscribe(config).await
+        })?;
+        Ok(self.bridge_subscription("blockSummarySubscribe", subscription_id, async_rx))
+    }
+
+    /// Blocking equivalent of [`WebSocketClient::vote_subscribe`]
+    pub fn vote_subscribe(&self) -> Result<PubsubClientSubscription<VoteNotification>> {
+        let ws_client = self.ws_client.clone();
+        let (subscription_id, async_rx) = Self::run_blocking(
+            &self.handle,
+            async move { ws_client.vote_subscribe().await },
+        )?;
+        Ok(self.bridge_subscription("voteSubscribe", subscription_id, async_rx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mirrors the background-thread setup in `PubsubClient::new`, without the
+    /// `WebSocketClient` connection, so the driver mechanics can be tested without a
+    /// live server.
+    fn spawn_driven_runtime() -> Handle {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let handle = runtime.handle().clone();
+        std::thread::spawn(move || {
+            runtime.block_on(std::future::pending::<()>());
+        });
+        handle
+    }
+
+    #[test]
+    fn test_run_blocking_completes_without_a_local_block_on() {
+        let handle = spawn_driven_runtime();
+        // This test thread never calls `block_on` itself — if `run_blocking` relied on
+        // that, it would hang instead of returning.
+        let result = PubsubClient::run_blocking(&handle, async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            42
+        });
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn test_bridged_notifications_arrive_without_a_local_block_on() {
+        let handle = spawn_driven_runtime();
+        let (async_tx, mut async_rx) = mpsc::unbounded_channel::<u32>();
+        let (sync_tx, sync_rx) = std_mpsc::channel();
+        handle.spawn(async move {
+            while let Some(value) = async_rx.recv().await {
+                if sync_tx.send(value).is_err() {
+                    break;
+                }
+            }
+        });
+
+        async_tx.send(7).unwrap();
+        // Plain blocking recv on this thread, exactly how a `PubsubClientSubscription`
+        // consumer uses `receiver` — the bridge task above only makes progress because
+        // `spawn_driven_runtime`'s background thread is driving it independently.
+        assert_eq!(
+            sync_rx.recv_timeout(std::time::Duration::from_secs(5)),
+            Ok(7)
+        );
+    }
+}
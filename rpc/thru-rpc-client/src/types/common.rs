@@ -3,7 +3,7 @@
 use base64::Engine;
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{ProgramAccount, types::transaction::Event};
+use crate::{ProgramAccount, types::query::SubscriptionQuery, types::transaction::Event};
 use thru_base::rpc_types::ProgramAccountFilter;
 
 /// Configuration for data slicing in account operations
@@ -16,7 +16,7 @@ pub struct DataSliceConfig {
 }
 
 /// Commitment level
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CommitmentLevel {
     /// Query the most recent block finalized by the chain
@@ -72,7 +72,7 @@ pub struct BlockSubscriptionConfig {
 }
 
 /// Configuration for event subscriptions (eventsSubscribe)
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct EventSubscriptionConfig {
     /// Filter for specific transaction signatures
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -84,6 +84,9 @@ pub struct EventSubscriptionConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "startsWithBytes")]
     pub starts_with_bytes: Option<String>,
+    /// Server-side query filter built with [`SubscriptionQuery`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<SubscriptionQuery>,
 }
 
 /// Configuration for program subscriptions (programSubscribe)
@@ -97,6 +100,9 @@ pub struct ProgramSubscriptionConfig {
     /// Filter results using various filter objects
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filters: Option<Vec<ProgramAccountFilter>>,
+    /// Server-side query filter built with [`SubscriptionQuery`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<SubscriptionQuery>,
 }
 
 /// Block raw notification data structure
@@ -194,6 +200,74 @@ pub struct ProgramNotification {
     pub value: ProgramAccount,
 }
 
+/// Filter mode for logs subscriptions (logsSubscribe)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LogsFilter {
+    /// Stream logs for all transactions
+    All,
+    /// Stream logs for all transactions, including simple vote transactions
+    AllWithVotes,
+    /// Stream logs for transactions mentioning the given program or account. Takes a
+    /// single-element array to match the server's `{"mentions": [pubkey]}` wire format.
+    Mentions(Vec<String>),
+}
+
+/// Configuration for logs subscriptions (logsSubscribe)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogsSubscriptionConfig {
+    /// Which transactions to stream logs for
+    pub filter: LogsFilter,
+    /// Commitment level to subscribe at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commitment: Option<CommitmentLevel>,
+    /// Server-side query filter built with [`SubscriptionQuery`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<SubscriptionQuery>,
+}
+
+/// Logs notification data structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogsNotification {
+    /// Context information
+    pub context: ResponseContext,
+    /// The logs data
+    pub value: LogsData,
+}
+
+/// Logs data structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogsData {
+    /// The signature of the transaction that generated these logs
+    pub signature: String,
+    /// Error information (null for success)
+    pub err: Option<serde_json::Value>,
+    /// The log messages emitted by the transaction
+    pub logs: Vec<String>,
+}
+
+/// Vote notification data structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteNotification {
+    /// Context information
+    pub context: ResponseContext,
+    /// The vote data
+    pub value: VoteData,
+}
+
+/// Vote data structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteData {
+    /// The voting account's public key
+    pub vote_pubkey: String,
+    /// Slots covered by the vote
+    pub slots: Vec<u64>,
+    /// The vote transaction's signature
+    pub signature: String,
+    /// Timestamp of the vote, if available
+    pub timestamp: Option<i64>,
+}
+
 /// Slot notification data structure
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SlotNotification {
@@ -293,4 +367,22 @@ mod tests {
         assert!(serialized.contains("SGVsbG8=")); // "Hello" in base64
         assert!(serialized.contains("V29ybGQ=")); // "World" in base64
     }
+
+    #[test]
+    fn test_logs_filter_serialization() {
+        assert_eq!(
+            serde_json::to_value(LogsFilter::All).unwrap(),
+            serde_json::json!("all")
+        );
+        assert_eq!(
+            serde_json::to_value(LogsFilter::AllWithVotes).unwrap(),
+            serde_json::json!("allWithVotes")
+        );
+        assert_eq!(
+            serde_json::to_value(LogsFilter::Mentions(vec!["11111111111111111111111111111111"
+                .to_string()]))
+            .unwrap(),
+            serde_json::json!({"mentions": ["11111111111111111111111111111111"]})
+        );
+    }
 }
@@ -2,6 +2,7 @@
 
 pub mod account;
 pub mod common;
+pub mod query;
 pub mod transaction;
 
 // Re-export commonly used types
@@ -13,9 +14,11 @@ pub use account::{
 pub use common::{
     BlockHeight, BlockRawNotification, BlockRawValue, BlockSubscriptionConfig, BlockSummary,
     BlockSummaryNotification, BlockSummaryValue, CommitmentLevel, EventData, EventNotification,
-    EventSubscriptionConfig, ProgramNotification, ProgramSubscriptionConfig, SlotNotification,
-    Version,
+    EventSubscriptionConfig, LogsData, LogsFilter, LogsNotification, LogsSubscriptionConfig,
+    ProgramNotification, ProgramSubscriptionConfig, SlotNotification, Version, VoteData,
+    VoteNotification,
 };
+pub use query::{QueryCondition, QueryOperator, QueryValue, SubscriptionQuery};
 pub use transaction::{
     Event, SendTransactionConfig, SendTransactionResult, SignatureExecutionResult,
     SignatureNotification, SignatureStatus, TransactionDetails, TransactionResponse,
@@ -0,0 +1,232 @@
+//! Typed query DSL for server-side subscription filtering
+//!
+//! Modeled on tendermint-style event queries: a set of AND-combined conditions
+//! over attribute keys, optionally scoped to an event type. Building a query
+//! through [`SubscriptionQuery`] lets callers push filtering to the server
+//! instead of receiving every notification and filtering client-side.
+
+use serde::{Deserialize, Serialize};
+
+/// Comparison operator for a single [`QueryCondition`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QueryOperator {
+    /// Attribute equals the given value
+    Eq,
+    /// Attribute is less than the given value
+    Lt,
+    /// Attribute is less than or equal to the given value
+    Lte,
+    /// Attribute is greater than the given value
+    Gt,
+    /// Attribute is greater than or equal to the given value
+    Gte,
+    /// Attribute contains the given substring
+    Contains,
+    /// Attribute is present, regardless of its value
+    Exists,
+}
+
+/// The operand of a [`QueryCondition`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum QueryValue {
+    /// A string operand, used by `Eq` and `Contains`
+    String(String),
+    /// A numeric operand, used by `Lt`, `Lte`, `Gt`, and `Gte`
+    Number(f64),
+    /// A boolean operand, used by `Eq`
+    Bool(bool),
+}
+
+/// A single condition over an attribute key, as produced by one of
+/// [`SubscriptionQuery`]'s builder methods
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryCondition {
+    /// The attribute key being matched, e.g. `"mentions"` or `"log"`
+    pub key: String,
+    /// The comparison operator applied to `key`
+    pub operator: QueryOperator,
+    /// The operand compared against, absent for `Exists`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<QueryValue>,
+}
+
+/// A server-side filter over subscription notifications, built from AND-combined
+/// conditions and an optional event type selector.
+///
+/// Each condition method only accepts the operand type its operator requires
+/// (a string for `eq`/`contains`, a number for the ordering comparisons, none
+/// for `exists`), so a malformed query is a compile error rather than a
+/// rejection from the server after the subscribe request is sent.
+///
+/// ```
+/// use thru_rpc_client::SubscriptionQuery;
+///
+/// let query = SubscriptionQuery::new()
+///     .event_type("Transfer")
+///     .eq("mentions", "ta1exampleaddress")
+///     .contains("log", "Instruction");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionQuery {
+    /// Restrict matches to notifications of this event type
+    #[serde(rename = "eventType", skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
+    /// Conditions applied with AND semantics
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<QueryCondition>,
+}
+
+impl SubscriptionQuery {
+    /// Start building an empty query that matches everything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict matches to notifications of the given event type
+    pub fn event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Require `key` to equal `value`
+    pub fn eq(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Eq,
+            value: Some(QueryValue::String(value.into())),
+        });
+        self
+    }
+
+    /// Require `key` to equal the boolean `value`
+    pub fn eq_bool(mut self, key: impl Into<String>, value: bool) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Eq,
+            value: Some(QueryValue::Bool(value)),
+        });
+        self
+    }
+
+    /// Require `key` to be less than `value`
+    pub fn lt(mut self, key: impl Into<String>, value: f64) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Lt,
+            value: Some(QueryValue::Number(value)),
+        });
+        self
+    }
+
+    /// Require `key` to be less than or equal to `value`
+    pub fn lte(mut self, key: impl Into<String>, value: f64) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Lte,
+            value: Some(QueryValue::Number(value)),
+        });
+        self
+    }
+
+    /// Require `key` to be greater than `value`
+    pub fn gt(mut self, key: impl Into<String>, value: f64) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Gt,
+            value: Some(QueryValue::Number(value)),
+        });
+        self
+    }
+
+    /// Require `key` to be greater than or equal to `value`
+    pub fn gte(mut self, key: impl Into<String>, value: f64) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Gte,
+            value: Some(QueryValue::Number(value)),
+        });
+        self
+    }
+
+    /// Require `key` to contain `value` as a substring
+    pub fn contains(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Contains,
+            value: Some(QueryValue::String(value.into())),
+        });
+        self
+    }
+
+    /// Require `key` to be present, regardless of its value
+    pub fn exists(mut self, key: impl Into<String>) -> Self {
+        self.conditions.push(QueryCondition {
+            key: key.into(),
+            operator: QueryOperator::Exists,
+            value: None,
+        });
+        self
+    }
+
+    /// Whether this query has no conditions and no event type, i.e. matches everything
+    pub fn is_empty(&self) -> bool {
+        self.event_type.is_none() && self.conditions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_combines_conditions_with_and_semantics() {
+        let query = SubscriptionQuery::new()
+            .event_type("Transfer")
+            .eq("mentions", "ta1example")
+            .contains("log", "Instruction")
+            .gte("amount", 100.0)
+            .exists("memo");
+
+        assert_eq!(query.event_type.as_deref(), Some("Transfer"));
+        assert_eq!(query.conditions.len(), 4);
+        assert_eq!(query.conditions[0].operator, QueryOperator::Eq);
+        assert_eq!(query.conditions[3].operator, QueryOperator::Exists);
+        assert!(query.conditions[3].value.is_none());
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(SubscriptionQuery::new().is_empty());
+        assert!(!SubscriptionQuery::new().exists("memo").is_empty());
+    }
+
+    #[test]
+    fn serializes_to_expected_json_shape() {
+        let query = SubscriptionQuery::new().eq("mentions", "ta1example");
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "conditions": [
+                    {"key": "mentions", "operator": "eq", "value": "ta1example"}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn eq_bool_serializes_boolean_operand() {
+        let query = SubscriptionQuery::new().eq_bool("isVote", false);
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "conditions": [
+                    {"key": "isVote", "operator": "eq", "value": false}
+                ]
+            })
+        );
+    }
+}
@@ -2,23 +2,285 @@
 //!
 //! This module provides RAII-style handles for WebSocket subscriptions that automatically
 //! clean up when dropped, along with a subscription manager for coordinating multiple
-//! subscriptions.
+//! subscriptions. Every handle also implements [`futures_util::Stream`], so notifications
+//! can be consumed with `StreamExt::next()` or composed with stream combinators instead of
+//! looping on `next_notification()`.
 
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::pin::Pin;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::task::{Context, Poll};
 
+use futures_util::Stream;
+use serde::Serialize;
 use serde_json::Value;
-use tokio::sync::{RwLock, mpsc};
+use tokio::sync::{RwLock, broadcast, mpsc};
 
-use crate::error::Result;
-use crate::types::{AccountInfoConfig, AccountNotification, CommitmentLevel};
+use crate::error::{Result, SubscriptionError};
+use crate::types::{
+    AccountInfoConfig, AccountNotification, BlockSubscriptionConfig, BlockSummaryNotification,
+    CommitmentLevel, LogsNotification, LogsSubscriptionConfig, ProgramNotification,
+    ProgramSubscriptionConfig, SignatureNotification, VoteNotification,
+};
 use crate::websocket::WebSocketClient;
 use thru_base::tn_tools::{Pubkey, Signature};
 
 /// Unique identifier for a subscription
 pub type SubscriptionId = u64;
 
+/// Which kind of subscription a manager-tracked id refers to, so cleanup code can
+/// issue the matching unsubscribe RPC without the caller having to remember it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubscriptionKind {
+    Account,
+    Signature,
+    Slot,
+    Program,
+    Logs,
+    Block,
+    Vote,
+}
+
+/// What `active_subscriptions` records about a handle: which kind it is and
+/// the upstream id to unsubscribe when it's the last reference.
+type SubscriptionRecord = (SubscriptionKind, SubscriptionId);
+
+/// A cleanup request enqueued by a handle's `Drop` impl for the manager's
+/// background cleanup task to drain and act on: which `active_subscriptions`
+/// entry to remove, alongside what to unsubscribe upstream.
+type CleanupRequest = (u64, SubscriptionKind, SubscriptionId);
+
+/// Table of subscriptions a manager's handles are still considered to hold,
+/// keyed by `handle_id`. Entries are removed as soon as a handle unsubscribes
+/// or is dropped (not just in bulk via `unsubscribe_all()`), so
+/// `active_count()`/`metrics()`/`check_capacity()` reflect subscriptions
+/// actually still open rather than every one ever created.
+type ActiveSubscriptions = Arc<RwLock<HashMap<u64, SubscriptionRecord>>>;
+
+/// Capacity of the broadcast channel each shared upstream subscription fans
+/// notifications out through; a slow handle only lags its own receiver.
+const BROADCAST_CAPACITY: usize = 1024;
+
+/// Safety limits for a `SubscriptionManager`, mirroring the guardrails production
+/// pubsub trackers use so that a single stalled consumer can't make the client
+/// buffer notifications without bound.
+#[derive(Debug, Clone)]
+pub struct SubscriptionLimits {
+    /// Reject new `subscribe_*` calls once `active_count()` reaches this ceiling
+    pub max_active_subscriptions: usize,
+    /// Per-subscription queue depth. For program, logs, block and vote handles,
+    /// once the queue is full new notifications are dropped (and counted in
+    /// [`SubscriptionMetrics::notifications_dropped`]) rather than buffered
+    /// without limit. Account, signature and slot handles read from a broadcast
+    /// channel of this capacity: a handle that falls behind skips the oldest
+    /// notifications, and those skips are not counted in [`SubscriptionMetrics`]
+    pub max_queued_notifications: usize,
+    /// Optional cap on the serialized bytes queued for a single program, logs,
+    /// block or vote subscription; account, signature and slot handles are not
+    /// byte-capped
+    pub max_queued_bytes: Option<usize>,
+}
+
+impl Default for SubscriptionLimits {
+    fn default() -> Self {
+        Self {
+            max_active_subscriptions: 1024,
+            max_queued_notifications: BROADCAST_CAPACITY,
+            max_queued_bytes: None,
+        }
+    }
+}
+
+/// Shared, atomically-updated counters backing [`SubscriptionMetrics`] snapshots.
+#[derive(Debug, Default)]
+struct MetricsInner {
+    total_created: AtomicU64,
+    notifications_delivered: AtomicU64,
+    notifications_dropped: AtomicU64,
+}
+
+/// Point-in-time snapshot of a `SubscriptionManager`'s subscription and
+/// notification counters, so operators can observe backpressure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriptionMetrics {
+    /// Total subscriptions created over the manager's lifetime, including ones since unsubscribed
+    pub total_created: u64,
+    /// Subscriptions currently active
+    pub active: u64,
+    /// Notifications successfully delivered to a program, logs, block or vote
+    /// handle's queue (account, signature and slot deliveries are not counted)
+    pub notifications_delivered: u64,
+    /// Notifications dropped because a program, logs, block or vote handle's
+    /// queue (or byte cap) was full
+    pub notifications_dropped: u64,
+}
+
+/// Relay notifications from the client's internal unbounded channel into a
+/// bounded queue, dropping (and counting) new notifications once the handle's
+/// consumer falls far enough behind to fill it or the optional byte cap is hit.
+/// Returns the bounded receiver side along with the running total of bytes
+/// currently queued, which the handle decrements as it consumes items.
+fn spawn_bounded_relay<T>(
+    mut upstream: mpsc::UnboundedReceiver<T>,
+    limits: SubscriptionLimits,
+    metrics: Arc<MetricsInner>,
+) -> (mpsc::Receiver<(T, usize)>, Arc<AtomicUsize>)
+where
+    T: Serialize + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(limits.max_queued_notifications.max(1));
+    let queued_bytes = Arc::new(AtomicUsize::new(0));
+    let relay_queued_bytes = Arc::clone(&queued_bytes);
+    tokio::spawn(async move {
+        while let Some(notification) = upstream.recv().await {
+            let size = serde_json::to_vec(&notification).map(|v| v.len()).unwrap_or(0);
+            let over_byte_cap = limits.max_queued_bytes.is_some_and(|cap| {
+                relay_queued_bytes.load(Ordering::Relaxed) + size > cap
+            });
+            if over_byte_cap {
+                metrics.notifications_dropped.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+            match tx.try_send((notification, size)) {
+                Ok(()) => {
+                    relay_queued_bytes.fetch_add(size, Ordering::Relaxed);
+                    metrics.notifications_delivered.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    metrics.notifications_dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => break,
+            }
+        }
+    });
+    (rx, queued_bytes)
+}
+
+/// An upstream subscription shared by every handle that asked for the same
+/// normalized parameters. Notifications are fanned out to each handle's own
+/// broadcast receiver; the real server subscription is only cancelled once
+/// `refcount` drops to zero.
+///
+/// `subscription_id` never changes once set: `WebSocketClient` keeps it valid
+/// across a reconnect by rekeying its own internal tracking transparently
+/// (see `WebSocketClient::replay_subscriptions`) and forwards notifications
+/// to the same `mpsc::UnboundedReceiver` this subscription's forwarding task
+/// already reads from, so no respawn is needed at this layer either.
+/// `generation` and `resubscribing` are kept purely as reconnect diagnostics.
+struct SharedSubscription<K, T> {
+    subscription_id: AtomicU64,
+    key: K,
+    refcount: AtomicUsize,
+    sender: broadcast::Sender<T>,
+    /// Bumped every time the underlying `WebSocketClient` reconnects while this
+    /// subscription is live
+    generation: AtomicU64,
+    /// Set briefly while a reconnect replay is in flight, for diagnostics
+    resubscribing: AtomicBool,
+}
+
+impl<K: std::fmt::Debug, T> std::fmt::Debug for SharedSubscription<K, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedSubscription")
+            .field("subscription_id", &self.subscription_id.load(Ordering::Relaxed))
+            .field("key", &self.key)
+            .field("refcount", &self.refcount.load(Ordering::Relaxed))
+            .field("generation", &self.generation.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+/// Decrement a shared subscription's refcount, and if it was the last
+/// handle referencing it, remove it from `registry` and issue the real
+/// server-side unsubscribe.
+async fn release_shared<K, T, F, Fut>(
+    registry: &RwLock<HashMap<K, Arc<SharedSubscription<K, T>>>>,
+    shared: &Arc<SharedSubscription<K, T>>,
+    unsubscribe: F,
+) -> Result<bool>
+where
+    K: Eq + Hash + Clone,
+    F: FnOnce(SubscriptionId) -> Fut,
+    Fut: std::future::Future<Output = Result<bool>>,
+{
+    if shared.refcount.fetch_sub(1, Ordering::SeqCst) == 1 {
+        registry.write().await.remove(&shared.key);
+        unsubscribe(shared.subscription_id.load(Ordering::SeqCst)).await
+    } else {
+        Ok(true)
+    }
+}
+
+/// Mark every subscription recorded in `registry` as having lived through a
+/// reconnect. The actual replay happens transparently inside `WebSocketClient`
+/// (see `WebSocketClient::replay_subscriptions`), which keeps each
+/// subscription's id valid and keeps feeding its existing forwarding task, so
+/// this only advances the diagnostic counters handles expose via
+/// `is_resubscribing()`/`generation()` — and only for subscriptions whose
+/// replay actually succeeded, so those counters don't lie about a subscription
+/// a failed replay silently dropped.
+async fn resubscribe_registry<K, T>(
+    registry: &RwLock<HashMap<K, Arc<SharedSubscription<K, T>>>>,
+    ws_client: &WebSocketClient,
+) where
+    K: Eq + Hash + Clone + std::fmt::Debug,
+    T: Clone + Send + 'static,
+{
+    let shared_subs: Vec<_> = registry.read().await.values().cloned().collect();
+    for shared in shared_subs {
+        shared.resubscribing.store(true, Ordering::SeqCst);
+        let public_id = shared.subscription_id.load(Ordering::SeqCst);
+        if ws_client.is_subscription_live(public_id).await {
+            shared.generation.fetch_add(1, Ordering::SeqCst);
+        } else {
+            tracing::warn!(
+                "Subscription {:?} ({}) was dropped by a failed reconnect replay",
+                shared.key,
+                public_id
+            );
+        }
+        shared.resubscribing.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Normalized key for deduplicating account subscriptions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct AccountSubKey {
+    pubkey: String,
+    skip_data: Option<bool>,
+    data_slice: Option<(usize, usize)>,
+}
+
+impl AccountSubKey {
+    fn new(pubkey: &Pubkey, config: Option<&AccountInfoConfig>) -> Self {
+        Self {
+            pubkey: pubkey.as_str().to_string(),
+            skip_data: config.and_then(|c| c.skip_data),
+            data_slice: config
+                .and_then(|c| c.data_slice.as_ref())
+                .map(|slice| (slice.offset, slice.length)),
+        }
+    }
+}
+
+/// Normalized key for deduplicating signature subscriptions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SignatureSubKey {
+    signature: String,
+    commitment: Option<CommitmentLevel>,
+}
+
+impl SignatureSubKey {
+    fn new(signature: &Signature, commitment: Option<CommitmentLevel>) -> Self {
+        Self {
+            signature: signature.as_str().to_string(),
+            commitment,
+        }
+    }
+}
+
 /// Base trait for all subscription handles
 pub trait SubscriptionHandle {
     /// Get the subscription ID
@@ -31,26 +293,147 @@ pub trait SubscriptionHandle {
     fn unsubscribe(&mut self) -> impl std::future::Future<Output = Result<bool>> + Send;
 }
 
+/// Lightweight RAII wrapper around a single raw `WebSocketClient` subscription.
+///
+/// Unlike the per-kind handles `SubscriptionManager` hands out, this doesn't dedupe or
+/// fan out to other callers — it's a thin `Stream` over the receiver a `*_subscribe`
+/// method already returns, for callers who just want auto-unsubscribe without setting up
+/// a manager. Construct via `WebSocketClient::*_subscribe_stream`.
+pub struct Subscription<T> {
+    id: SubscriptionId,
+    method: &'static str,
+    ws_client: WebSocketClient,
+    receiver: mpsc::UnboundedReceiver<T>,
+    active: bool,
+}
+
+impl<T> std::fmt::Debug for Subscription<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription")
+            .field("id", &self.id)
+            .field("method", &self.method)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+impl<T> Subscription<T> {
+    pub(crate) fn new(
+        id: SubscriptionId,
+        method: &'static str,
+        ws_client: WebSocketClient,
+        receiver: mpsc::UnboundedReceiver<T>,
+    ) -> Self {
+        Self {
+            id,
+            method,
+            ws_client,
+            receiver,
+            active: true,
+        }
+    }
+
+    /// The subscription id returned by the server
+    pub fn id(&self) -> SubscriptionId {
+        self.id
+    }
+
+    /// Get the next notification from this subscription
+    pub async fn next_notification(&mut self) -> Option<T> {
+        if !self.active {
+            return None;
+        }
+        self.receiver.recv().await
+    }
+
+    /// Whether this subscription has already been unsubscribed, manually or via `Drop`
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Manually unsubscribe (automatically attempted on drop otherwise)
+    ///
+    /// This is fire-and-forget, same as the cleanup `Drop` triggers: no response is
+    /// awaited, so it cannot report whether the server actually dropped the
+    /// subscription. Prefer the typed `*_unsubscribe` methods on `WebSocketClient`
+    /// directly if you need a confirmed result.
+    pub async fn unsubscribe(&mut self) {
+        if !self.active {
+            return;
+        }
+        self.active = false;
+        self.ws_client.unsubscribe_by_method(self.method, self.id).await;
+    }
+}
+
+impl<T> Drop for Subscription<T> {
+    fn drop(&mut self) {
+        if self.active {
+            self.active = false;
+            let ws_client = self.ws_client.clone();
+            let method = self.method;
+            let id = self.id;
+            tokio::spawn(async move {
+                ws_client.unsubscribe_by_method(method, id).await;
+            });
+        }
+    }
+}
+
+impl<T> Stream for Subscription<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        this.receiver.poll_recv(cx)
+    }
+}
+
+type AccountRegistry =
+    Arc<RwLock<HashMap<AccountSubKey, Arc<SharedSubscription<AccountSubKey, AccountNotification>>>>>;
+
 /// Handle for account subscription with automatic cleanup
-#[derive(Debug)]
 pub struct AccountSubscriptionHandle {
-    subscription_id: SubscriptionId,
     ws_client: WebSocketClient,
-    notifications: mpsc::UnboundedReceiver<AccountNotification>,
+    notifications: broadcast::Receiver<AccountNotification>,
+    registry: AccountRegistry,
+    shared: Arc<SharedSubscription<AccountSubKey, AccountNotification>>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
     active: bool,
 }
 
+impl std::fmt::Debug for AccountSubscriptionHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountSubscriptionHandle")
+            .field("subscription_id", &self.subscription_id())
+            .field("ws_client", &self.ws_client)
+            .field("notifications", &"broadcast::Receiver<AccountNotification>")
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
 impl AccountSubscriptionHandle {
     /// Create a new account subscription handle
     pub(crate) fn new(
-        subscription_id: SubscriptionId,
         ws_client: WebSocketClient,
-        notifications: mpsc::UnboundedReceiver<AccountNotification>,
+        notifications: broadcast::Receiver<AccountNotification>,
+        registry: AccountRegistry,
+        shared: Arc<SharedSubscription<AccountSubKey, AccountNotification>>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
     ) -> Self {
         Self {
-            subscription_id,
             ws_client,
             notifications,
+            registry,
+            shared,
+            handle_id,
+            active_subscriptions,
             active: true,
         }
     }
@@ -60,18 +443,35 @@ impl AccountSubscriptionHandle {
         if !self.active {
             return None;
         }
-        self.notifications.recv().await
+        loop {
+            match self.notifications.recv().await {
+                Ok(notification) => return Some(notification),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
     }
 
     /// Get the account being monitored
     pub fn account(&self) -> SubscriptionId {
-        self.subscription_id
+        self.subscription_id()
+    }
+
+    /// Whether a reconnect replay for this subscription is currently in flight
+    pub fn is_resubscribing(&self) -> bool {
+        self.shared.resubscribing.load(Ordering::SeqCst)
+    }
+
+    /// Number of times this subscription has been successfully replayed after a
+    /// reconnect. Callers can diff this across polls to detect a notification gap.
+    pub fn generation(&self) -> u64 {
+        self.shared.generation.load(Ordering::SeqCst)
     }
 }
 
 impl SubscriptionHandle for AccountSubscriptionHandle {
     fn subscription_id(&self) -> SubscriptionId {
-        self.subscription_id
+        self.shared.subscription_id.load(Ordering::SeqCst)
     }
 
     fn is_active(&self) -> bool {
@@ -82,40 +482,82 @@ impl SubscriptionHandle for AccountSubscriptionHandle {
         if !self.active {
             return Ok(false);
         }
-
-        let result = self
-            .ws_client
-            .account_unsubscribe(self.subscription_id)
-            .await;
         self.active = false;
-        result
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        let ws_client = self.ws_client.clone();
+        release_shared(&self.registry, &self.shared, |id| async move {
+            ws_client.account_unsubscribe(id).await
+        })
+        .await
     }
 }
 
 impl Drop for AccountSubscriptionHandle {
     fn drop(&mut self) {
         if self.active {
-            // Note: We can't call async unsubscribe in Drop, so we just mark as inactive
-            // In a production system, you might want to send a message to a cleanup task
             self.active = false;
+            let registry = Arc::clone(&self.registry);
+            let shared = Arc::clone(&self.shared);
+            let ws_client = self.ws_client.clone();
+            let active_subscriptions = Arc::clone(&self.active_subscriptions);
+            let handle_id = self.handle_id;
+            tokio::spawn(async move {
+                let _ = release_shared(&registry, &shared, |id| async move {
+                    ws_client.account_unsubscribe(id).await
+                })
+                .await;
+                active_subscriptions.write().await.remove(&handle_id);
+            });
+        }
+    }
+}
+
+impl Stream for AccountSubscriptionHandle {
+    type Item = AccountNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        loop {
+            let fut = this.notifications.recv();
+            futures_util::pin_mut!(fut);
+            match fut.poll(cx) {
+                Poll::Ready(Ok(notification)) => return Poll::Ready(Some(notification)),
+                Poll::Ready(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                Poll::Ready(Err(broadcast::error::RecvError::Closed)) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
         }
     }
 }
 
+type SignatureRegistry = Arc<
+    RwLock<HashMap<SignatureSubKey, Arc<SharedSubscription<SignatureSubKey, SignatureNotification>>>>,
+>;
+
 /// Handle for signature subscription with automatic cleanup
 pub struct SignatureSubscriptionHandle {
-    subscription_id: SubscriptionId,
     ws_client: WebSocketClient,
-    notifications: mpsc::UnboundedReceiver<crate::types::SignatureNotification>,
+    notifications: broadcast::Receiver<SignatureNotification>,
+    registry: SignatureRegistry,
+    shared: Arc<SharedSubscription<SignatureSubKey, SignatureNotification>>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
     active: bool,
 }
 
 impl std::fmt::Debug for SignatureSubscriptionHandle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SignatureSubscriptionHandle")
-            .field("subscription_id", &self.subscription_id)
+            .field("subscription_id", &self.subscription_id())
             .field("ws_client", &self.ws_client)
-            .field("notifications", &"UnboundedReceiver<SignatureNotification>")
+            .field(
+                "notifications",
+                &"broadcast::Receiver<SignatureNotification>",
+            )
             .field("active", &self.active)
             .finish()
     }
@@ -124,45 +566,67 @@ impl std::fmt::Debug for SignatureSubscriptionHandle {
 impl SignatureSubscriptionHandle {
     /// Create a new signature subscription handle
     pub(crate) fn new(
-        subscription_id: SubscriptionId,
         ws_client: WebSocketClient,
-        notifications: mpsc::UnboundedReceiver<crate::types::SignatureNotification>,
+        notifications: broadcast::Receiver<SignatureNotification>,
+        registry: SignatureRegistry,
+        shared: Arc<SharedSubscription<SignatureSubKey, SignatureNotification>>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
     ) -> Self {
         Self {
-            subscription_id,
             ws_client,
             notifications,
+            registry,
+            shared,
+            handle_id,
+            active_subscriptions,
             active: true,
         }
     }
 
     /// Get the next notification from this subscription
-    pub async fn next_notification(&mut self) -> Option<crate::types::SignatureNotification> {
+    pub async fn next_notification(&mut self) -> Option<SignatureNotification> {
         if !self.active {
             return None;
         }
-        self.notifications.recv().await
+        loop {
+            match self.notifications.recv().await {
+                Ok(notification) => return Some(notification),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
     }
 
     /// Wait for signature confirmation with timeout
     pub async fn wait_for_confirmation(
         &mut self,
         timeout: std::time::Duration,
-    ) -> Result<Option<crate::types::SignatureNotification>> {
+    ) -> Result<Option<SignatureNotification>> {
         if !self.active {
             return Ok(None);
         }
 
-        tokio::time::timeout(timeout, self.notifications.recv())
+        tokio::time::timeout(timeout, self.next_notification())
             .await
             .map_err(|_| crate::error::SubscriptionError::ConfirmationTimeout.into())
-            .map(|opt| opt)
+    }
+
+    /// Whether a reconnect replay for this subscription is currently in flight
+    pub fn is_resubscribing(&self) -> bool {
+        self.shared.resubscribing.load(Ordering::SeqCst)
+    }
+
+    /// Number of times this subscription has been successfully replayed after a
+    /// reconnect. Callers can diff this across polls to detect a notification gap.
+    pub fn generation(&self) -> u64 {
+        self.shared.generation.load(Ordering::SeqCst)
     }
 }
 
 impl SubscriptionHandle for SignatureSubscriptionHandle {
     fn subscription_id(&self) -> SubscriptionId {
-        self.subscription_id
+        self.shared.subscription_id.load(Ordering::SeqCst)
     }
 
     fn is_active(&self) -> bool {
@@ -173,13 +637,14 @@ impl SubscriptionHandle for SignatureSubscriptionHandle {
         if !self.active {
             return Ok(false);
         }
-
-        let result = self
-            .ws_client
-            .signature_unsubscribe(self.subscription_id)
-            .await;
         self.active = false;
-        result
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        let ws_client = self.ws_client.clone();
+        release_shared(&self.registry, &self.shared, |id| async move {
+            ws_client.signature_unsubscribe(id).await
+        })
+        .await
     }
 }
 
@@ -187,30 +652,86 @@ impl Drop for SignatureSubscriptionHandle {
     fn drop(&mut self) {
         if self.active {
             self.active = false;
+            let registry = Arc::clone(&self.registry);
+            let shared = Arc::clone(&self.shared);
+            let ws_client = self.ws_client.clone();
+            let active_subscriptions = Arc::clone(&self.active_subscriptions);
+            let handle_id = self.handle_id;
+            tokio::spawn(async move {
+                let _ = release_shared(&registry, &shared, |id| async move {
+                    ws_client.signature_unsubscribe(id).await
+                })
+                .await;
+                active_subscriptions.write().await.remove(&handle_id);
+            });
+        }
+    }
+}
+
+impl Stream for SignatureSubscriptionHandle {
+    type Item = SignatureNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        loop {
+            let fut = this.notifications.recv();
+            futures_util::pin_mut!(fut);
+            match fut.poll(cx) {
+                Poll::Ready(Ok(notification)) => return Poll::Ready(Some(notification)),
+                Poll::Ready(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                Poll::Ready(Err(broadcast::error::RecvError::Closed)) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
         }
     }
 }
 
+/// All slot subscriptions are identical (there are no parameters to key on),
+/// so this registry holds at most one shared upstream subscription.
+type SlotRegistry = Arc<RwLock<HashMap<(), Arc<SharedSubscription<(), Value>>>>>;
+
 /// Handle for slot subscription with automatic cleanup
-#[derive(Debug)]
 pub struct SlotSubscriptionHandle {
-    subscription_id: SubscriptionId,
     ws_client: WebSocketClient,
-    notifications: mpsc::UnboundedReceiver<Value>,
+    notifications: broadcast::Receiver<Value>,
+    registry: SlotRegistry,
+    shared: Arc<SharedSubscription<(), Value>>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
     active: bool,
 }
 
+impl std::fmt::Debug for SlotSubscriptionHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlotSubscriptionHandle")
+            .field("subscription_id", &self.subscription_id())
+            .field("ws_client", &self.ws_client)
+            .field("notifications", &"broadcast::Receiver<Value>")
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
 impl SlotSubscriptionHandle {
     /// Create a new slot subscription handle
     pub(crate) fn new(
-        subscription_id: SubscriptionId,
         ws_client: WebSocketClient,
-        notifications: mpsc::UnboundedReceiver<Value>,
+        notifications: broadcast::Receiver<Value>,
+        registry: SlotRegistry,
+        shared: Arc<SharedSubscription<(), Value>>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
     ) -> Self {
         Self {
-            subscription_id,
             ws_client,
             notifications,
+            registry,
+            shared,
+            handle_id,
+            active_subscriptions,
             active: true,
         }
     }
@@ -220,7 +741,13 @@ impl SlotSubscriptionHandle {
         if !self.active {
             return None;
         }
-        self.notifications.recv().await
+        loop {
+            match self.notifications.recv().await {
+                Ok(notification) => return Some(notification),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
     }
 
     /// Get multiple notifications at once (up to limit)
@@ -237,9 +764,132 @@ impl SlotSubscriptionHandle {
 
         notifications
     }
+
+    /// Whether a reconnect replay for this subscription is currently in flight
+    pub fn is_resubscribing(&self) -> bool {
+        self.shared.resubscribing.load(Ordering::SeqCst)
+    }
+
+    /// Number of times this subscription has been successfully replayed after a
+    /// reconnect. Callers can diff this across polls to detect a notification gap.
+    pub fn generation(&self) -> u64 {
+        self.shared.generation.load(Ordering::SeqCst)
+    }
 }
 
 impl SubscriptionHandle for SlotSubscriptionHandle {
+    fn subscription_id(&self) -> SubscriptionId {
+        self.shared.subscription_id.load(Ordering::SeqCst)
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    async fn unsubscribe(&mut self) -> Result<bool> {
+        if !self.active {
+            return Ok(false);
+        }
+        self.active = false;
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        let ws_client = self.ws_client.clone();
+        release_shared(&self.registry, &self.shared, |id| async move {
+            ws_client.slot_unsubscribe(id).await
+        })
+        .await
+    }
+}
+
+impl Drop for SlotSubscriptionHandle {
+    fn drop(&mut self) {
+        if self.active {
+            self.active = false;
+            let registry = Arc::clone(&self.registry);
+            let shared = Arc::clone(&self.shared);
+            let ws_client = self.ws_client.clone();
+            let active_subscriptions = Arc::clone(&self.active_subscriptions);
+            let handle_id = self.handle_id;
+            tokio::spawn(async move {
+                let _ = release_shared(&registry, &shared, |id| async move {
+                    ws_client.slot_unsubscribe(id).await
+                })
+                .await;
+                active_subscriptions.write().await.remove(&handle_id);
+            });
+        }
+    }
+}
+
+impl Stream for SlotSubscriptionHandle {
+    type Item = Value;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        loop {
+            let fut = this.notifications.recv();
+            futures_util::pin_mut!(fut);
+            match fut.poll(cx) {
+                Poll::Ready(Ok(notification)) => return Poll::Ready(Some(notification)),
+                Poll::Ready(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                Poll::Ready(Err(broadcast::error::RecvError::Closed)) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Handle for program account subscription with automatic cleanup
+#[derive(Debug)]
+pub struct ProgramSubscriptionHandle {
+    subscription_id: SubscriptionId,
+    ws_client: WebSocketClient,
+    notifications: mpsc::Receiver<(ProgramNotification, usize)>,
+    queued_bytes: Arc<AtomicUsize>,
+    cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
+    active: bool,
+}
+
+impl ProgramSubscriptionHandle {
+    /// Create a new program subscription handle
+    pub(crate) fn new(
+        subscription_id: SubscriptionId,
+        ws_client: WebSocketClient,
+        notifications: mpsc::Receiver<(ProgramNotification, usize)>,
+        queued_bytes: Arc<AtomicUsize>,
+        cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
+    ) -> Self {
+        Self {
+            subscription_id,
+            ws_client,
+            notifications,
+            queued_bytes,
+            cleanup_tx,
+            handle_id,
+            active_subscriptions,
+            active: true,
+        }
+    }
+
+    /// Get the next notification from this subscription
+    pub async fn next_notification(&mut self) -> Option<ProgramNotification> {
+        if !self.active {
+            return None;
+        }
+        let (notification, size) = self.notifications.recv().await?;
+        self.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+        Some(notification)
+    }
+}
+
+impl SubscriptionHandle for ProgramSubscriptionHandle {
     fn subscription_id(&self) -> SubscriptionId {
         self.subscription_id
     }
@@ -253,16 +903,341 @@ impl SubscriptionHandle for SlotSubscriptionHandle {
             return Ok(false);
         }
 
-        let result = self.ws_client.slot_unsubscribe(self.subscription_id).await;
         self.active = false;
-        result
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        self.ws_client
+            .program_unsubscribe(self.subscription_id)
+            .await
     }
 }
 
-impl Drop for SlotSubscriptionHandle {
+impl Drop for ProgramSubscriptionHandle {
+    fn drop(&mut self) {
+        if self.active {
+            self.active = false;
+            let _ = self.cleanup_tx.send((
+                self.handle_id,
+                SubscriptionKind::Program,
+                self.subscription_id,
+            ));
+        }
+    }
+}
+
+impl Stream for ProgramSubscriptionHandle {
+    type Item = ProgramNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        match this.notifications.poll_recv(cx) {
+            Poll::Ready(Some((notification, size))) => {
+                this.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+                Poll::Ready(Some(notification))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Handle for transaction logs subscription with automatic cleanup
+#[derive(Debug)]
+pub struct LogsSubscriptionHandle {
+    subscription_id: SubscriptionId,
+    ws_client: WebSocketClient,
+    notifications: mpsc::Receiver<(LogsNotification, usize)>,
+    queued_bytes: Arc<AtomicUsize>,
+    cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
+    active: bool,
+}
+
+impl LogsSubscriptionHandle {
+    /// Create a new logs subscription handle
+    pub(crate) fn new(
+        subscription_id: SubscriptionId,
+        ws_client: WebSocketClient,
+        notifications: mpsc::Receiver<(LogsNotification, usize)>,
+        queued_bytes: Arc<AtomicUsize>,
+        cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
+    ) -> Self {
+        Self {
+            subscription_id,
+            ws_client,
+            notifications,
+            queued_bytes,
+            cleanup_tx,
+            handle_id,
+            active_subscriptions,
+            active: true,
+        }
+    }
+
+    /// Get the next notification from this subscription
+    pub async fn next_notification(&mut self) -> Option<LogsNotification> {
+        if !self.active {
+            return None;
+        }
+        let (notification, size) = self.notifications.recv().await?;
+        self.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+        Some(notification)
+    }
+}
+
+impl SubscriptionHandle for LogsSubscriptionHandle {
+    fn subscription_id(&self) -> SubscriptionId {
+        self.subscription_id
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    async fn unsubscribe(&mut self) -> Result<bool> {
+        if !self.active {
+            return Ok(false);
+        }
+
+        self.active = false;
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        self.ws_client.logs_unsubscribe(self.subscription_id).await
+    }
+}
+
+impl Drop for LogsSubscriptionHandle {
+    fn drop(&mut self) {
+        if self.active {
+            self.active = false;
+            let _ =
+                self.cleanup_tx
+                    .send((self.handle_id, SubscriptionKind::Logs, self.subscription_id));
+        }
+    }
+}
+
+impl Stream for LogsSubscriptionHandle {
+    type Item = LogsNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        match this.notifications.poll_recv(cx) {
+            Poll::Ready(Some((notification, size))) => {
+                this.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+                Poll::Ready(Some(notification))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Handle for block summary subscription with automatic cleanup
+#[derive(Debug)]
+pub struct BlockSubscriptionHandle {
+    subscription_id: SubscriptionId,
+    ws_client: WebSocketClient,
+    notifications: mpsc::Receiver<(BlockSummaryNotification, usize)>,
+    queued_bytes: Arc<AtomicUsize>,
+    cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
+    active: bool,
+}
+
+impl BlockSubscriptionHandle {
+    /// Create a new block subscription handle
+    pub(crate) fn new(
+        subscription_id: SubscriptionId,
+        ws_client: WebSocketClient,
+        notifications: mpsc::Receiver<(BlockSummaryNotification, usize)>,
+        queued_bytes: Arc<AtomicUsize>,
+        cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
+    ) -> Self {
+        Self {
+            subscription_id,
+            ws_client,
+            notifications,
+            queued_bytes,
+            cleanup_tx,
+            handle_id,
+            active_subscriptions,
+            active: true,
+        }
+    }
+
+    /// Get the next notification from this subscription
+    pub async fn next_notification(&mut self) -> Option<BlockSummaryNotification> {
+        if !self.active {
+            return None;
+        }
+        let (notification, size) = self.notifications.recv().await?;
+        self.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+        Some(notification)
+    }
+}
+
+impl SubscriptionHandle for BlockSubscriptionHandle {
+    fn subscription_id(&self) -> SubscriptionId {
+        self.subscription_id
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    async fn unsubscribe(&mut self) -> Result<bool> {
+        if !self.active {
+            return Ok(false);
+        }
+
+        self.active = false;
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        self.ws_client
+            .block_summary_unsubscribe(self.subscription_id)
+            .await
+    }
+}
+
+impl Drop for BlockSubscriptionHandle {
+    fn drop(&mut self) {
+        if self.active {
+            self.active = false;
+            let _ = self.cleanup_tx.send((
+                self.handle_id,
+                SubscriptionKind::Block,
+                self.subscription_id,
+            ));
+        }
+    }
+}
+
+impl Stream for BlockSubscriptionHandle {
+    type Item = BlockSummaryNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        match this.notifications.poll_recv(cx) {
+            Poll::Ready(Some((notification, size))) => {
+                this.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+                Poll::Ready(Some(notification))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Handle for vote subscription with automatic cleanup
+#[derive(Debug)]
+pub struct VoteSubscriptionHandle {
+    subscription_id: SubscriptionId,
+    ws_client: WebSocketClient,
+    notifications: mpsc::Receiver<(VoteNotification, usize)>,
+    queued_bytes: Arc<AtomicUsize>,
+    cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+    handle_id: u64,
+    active_subscriptions: ActiveSubscriptions,
+    active: bool,
+}
+
+impl VoteSubscriptionHandle {
+    /// Create a new vote subscription handle
+    pub(crate) fn new(
+        subscription_id: SubscriptionId,
+        ws_client: WebSocketClient,
+        notifications: mpsc::Receiver<(VoteNotification, usize)>,
+        queued_bytes: Arc<AtomicUsize>,
+        cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+        handle_id: u64,
+        active_subscriptions: ActiveSubscriptions,
+    ) -> Self {
+        Self {
+            subscription_id,
+            ws_client,
+            notifications,
+            queued_bytes,
+            cleanup_tx,
+            handle_id,
+            active_subscriptions,
+            active: true,
+        }
+    }
+
+    /// Get the next notification from this subscription
+    pub async fn next_notification(&mut self) -> Option<VoteNotification> {
+        if !self.active {
+            return None;
+        }
+        let (notification, size) = self.notifications.recv().await?;
+        self.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+        Some(notification)
+    }
+}
+
+impl SubscriptionHandle for VoteSubscriptionHandle {
+    fn subscription_id(&self) -> SubscriptionId {
+        self.subscription_id
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    async fn unsubscribe(&mut self) -> Result<bool> {
+        if !self.active {
+            return Ok(false);
+        }
+
+        self.active = false;
+        self.active_subscriptions.write().await.remove(&self.handle_id);
+
+        self.ws_client.vote_unsubscribe(self.subscription_id).await
+    }
+}
+
+impl Drop for VoteSubscriptionHandle {
     fn drop(&mut self) {
         if self.active {
             self.active = false;
+            let _ = self
+                .cleanup_tx
+                .send((self.handle_id, SubscriptionKind::Vote, self.subscription_id));
+        }
+    }
+}
+
+impl Stream for VoteSubscriptionHandle {
+    type Item = VoteNotification;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if !this.active {
+            return Poll::Ready(None);
+        }
+        match this.notifications.poll_recv(cx) {
+            Poll::Ready(Some((notification, size))) => {
+                this.queued_bytes.fetch_sub(size, Ordering::Relaxed);
+                Poll::Ready(Some(notification))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
         }
     }
 }
@@ -271,80 +1246,450 @@ impl Drop for SlotSubscriptionHandle {
 #[derive(Debug)]
 pub struct SubscriptionManager {
     next_handle_id: AtomicU64,
-    active_subscriptions: Arc<RwLock<HashMap<u64, SubscriptionId>>>,
+    active_subscriptions: ActiveSubscriptions,
     ws_client: WebSocketClient,
+    account_subs: AccountRegistry,
+    signature_subs: SignatureRegistry,
+    slot_subs: SlotRegistry,
+    /// Where `Drop` impls enqueue cleanup requests for the background task below
+    /// to drain, so a dropped handle's server-side subscription is actually cancelled.
+    cleanup_tx: mpsc::UnboundedSender<CleanupRequest>,
+    limits: SubscriptionLimits,
+    metrics: Arc<MetricsInner>,
 }
 
 impl SubscriptionManager {
-    /// Create a new subscription manager
+    /// Create a new subscription manager with [`SubscriptionLimits::default`]
     pub fn new(ws_client: WebSocketClient) -> Self {
+        Self::with_limits(ws_client, SubscriptionLimits::default())
+    }
+
+    /// Create a new subscription manager with custom safety limits
+    ///
+    /// Spawns a background task that listens for `ws_client`'s reconnect events and
+    /// advances the reconnect diagnostics on every deduplicated account/signature/slot
+    /// subscription; the actual replay that keeps handles delivering notifications
+    /// across a transient disconnect happens transparently inside `WebSocketClient`
+    /// itself. Also spawns a cleanup task that drains the requests handles' `Drop`
+    /// impls enqueue and issues the matching unsubscribe RPC, so a dropped handle
+    /// doesn't leak its server-side subscription.
+    pub fn with_limits(ws_client: WebSocketClient, limits: SubscriptionLimits) -> Self {
+        let account_subs: AccountRegistry = Arc::new(RwLock::new(HashMap::new()));
+        let signature_subs: SignatureRegistry = Arc::new(RwLock::new(HashMap::new()));
+        let slot_subs: SlotRegistry = Arc::new(RwLock::new(HashMap::new()));
+
+        Self::spawn_resubscribe_task(
+            ws_client.clone(),
+            Arc::clone(&account_subs),
+            Arc::clone(&signature_subs),
+            Arc::clone(&slot_subs),
+        );
+
+        let (cleanup_tx, cleanup_rx) = mpsc::unbounded_channel();
+        let active_subscriptions: ActiveSubscriptions = Arc::new(RwLock::new(HashMap::new()));
+        Self::spawn_cleanup_task(ws_client.clone(), cleanup_rx, Arc::clone(&active_subscriptions));
+
         Self {
             next_handle_id: AtomicU64::new(1),
-            active_subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            active_subscriptions,
             ws_client,
+            account_subs,
+            signature_subs,
+            slot_subs,
+            cleanup_tx,
+            limits,
+            metrics: Arc::new(MetricsInner::default()),
+        }
+    }
+
+    /// Reject the call with a typed error once `active_count()` has reached
+    /// `limits.max_active_subscriptions`.
+    async fn check_capacity(&self) -> Result<()> {
+        let active = self.active_subscriptions.read().await.len();
+        if active >= self.limits.max_active_subscriptions {
+            return Err(SubscriptionError::TooManyActiveSubscriptions {
+                max: self.limits.max_active_subscriptions,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Snapshot the manager's subscription and notification counters
+    pub async fn metrics(&self) -> SubscriptionMetrics {
+        SubscriptionMetrics {
+            total_created: self.metrics.total_created.load(Ordering::Relaxed),
+            active: self.active_subscriptions.read().await.len() as u64,
+            notifications_delivered: self.metrics.notifications_delivered.load(Ordering::Relaxed),
+            notifications_dropped: self.metrics.notifications_dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Drain cleanup requests enqueued by dropped handles, issue the real
+    /// server-side unsubscribe for each, and remove the matching entry from
+    /// `active_subscriptions` so `active_count()`/`metrics()`/`check_capacity()`
+    /// reflect subscriptions actually still held open rather than growing
+    /// forever over a long-running process's lifetime.
+    fn spawn_cleanup_task(
+        ws_client: WebSocketClient,
+        mut cleanup_rx: mpsc::UnboundedReceiver<CleanupRequest>,
+        active_subscriptions: ActiveSubscriptions,
+    ) {
+        tokio::spawn(async move {
+            while let Some((handle_id, kind, subscription_id)) = cleanup_rx.recv().await {
+                if let Err(e) = Self::unsubscribe_by_kind(&ws_client, kind, subscription_id).await
+                {
+                    tracing::warn!(
+                        "Failed to unsubscribe {:?} {} on drop: {}",
+                        kind,
+                        subscription_id,
+                        e
+                    );
+                }
+                active_subscriptions.write().await.remove(&handle_id);
+            }
+        });
+    }
+
+    /// Issue the unsubscribe RPC matching `kind` for `subscription_id`.
+    async fn unsubscribe_by_kind(
+        ws_client: &WebSocketClient,
+        kind: SubscriptionKind,
+        subscription_id: SubscriptionId,
+    ) -> Result<bool> {
+        match kind {
+            SubscriptionKind::Account => ws_client.account_unsubscribe(subscription_id).await,
+            SubscriptionKind::Signature => ws_client.signature_unsubscribe(subscription_id).await,
+            SubscriptionKind::Slot => ws_client.slot_unsubscribe(subscription_id).await,
+            SubscriptionKind::Program => ws_client.program_unsubscribe(subscription_id).await,
+            SubscriptionKind::Logs => ws_client.logs_unsubscribe(subscription_id).await,
+            SubscriptionKind::Block => ws_client.block_summary_unsubscribe(subscription_id).await,
+            SubscriptionKind::Vote => ws_client.vote_unsubscribe(subscription_id).await,
         }
     }
 
+    /// Listen for reconnect events on `ws_client` and advance the reconnect
+    /// diagnostics on every recorded subscription in each registry. The
+    /// subscriptions themselves are kept alive by `WebSocketClient` without
+    /// any action needed here.
+    fn spawn_resubscribe_task(
+        ws_client: WebSocketClient,
+        account_subs: AccountRegistry,
+        signature_subs: SignatureRegistry,
+        slot_subs: SlotRegistry,
+    ) {
+        let mut reconnects = ws_client.subscribe_reconnects();
+        tokio::spawn(async move {
+            loop {
+                match reconnects.recv().await {
+                    Ok(generation) => {
+                        tracing::info!(
+                            "WebSocket reconnected (generation {}), subscriptions replayed",
+                            generation
+                        );
+                        resubscribe_registry(&account_subs, &ws_client).await;
+                        resubscribe_registry(&signature_subs, &ws_client).await;
+                        resubscribe_registry(&slot_subs, &ws_client).await;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+
     /// Subscribe to account changes with handle
+    ///
+    /// If another handle is already subscribed with identical parameters, this
+    /// reuses that upstream subscription and fans out notifications via a
+    /// broadcast channel instead of opening a second connection slot. The
+    /// subscription is automatically replayed if the WebSocket reconnects.
     pub async fn subscribe_account(
         &self,
         pubkey: &Pubkey,
         config: Option<AccountInfoConfig>,
     ) -> Result<AccountSubscriptionHandle> {
-        let (subscription_id, notifications) =
-            self.ws_client.account_subscribe(pubkey, config).await?;
-        let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
+        self.check_capacity().await?;
+        let key = AccountSubKey::new(pubkey, config.as_ref());
 
-        self.active_subscriptions
-            .write()
-            .await
-            .insert(handle_id, subscription_id);
+        let existing = self.account_subs.read().await.get(&key).cloned();
+        let shared = match existing {
+            Some(shared) => {
+                shared.refcount.fetch_add(1, Ordering::SeqCst);
+                shared
+            }
+            None => {
+                let (subscription_id, mut upstream) =
+                    self.ws_client.account_subscribe(pubkey, config).await?;
+                let (sender, _) = broadcast::channel(self.limits.max_queued_notifications);
+                let fanout = sender.clone();
+                tokio::spawn(async move {
+                    while let Some(notification) = upstream.recv().await {
+                        let _ = fanout.send(notification);
+                    }
+                });
+
+                let shared = Arc::new(SharedSubscription {
+                    subscription_id: AtomicU64::new(subscription_id),
+                    key: key.clone(),
+                    refcount: AtomicUsize::new(1),
+                    sender,
+                    generation: AtomicU64::new(0),
+                    resubscribing: AtomicBool::new(false),
+                });
+                self.account_subs
+                    .write()
+                    .await
+                    .insert(key, Arc::clone(&shared));
+                shared
+            }
+        };
+
+        let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
+        self.active_subscriptions.write().await.insert(
+            handle_id,
+            (SubscriptionKind::Account, shared.subscription_id.load(Ordering::SeqCst)),
+        );
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
 
         Ok(AccountSubscriptionHandle::new(
-            subscription_id,
             self.ws_client.clone(),
-            notifications,
+            shared.sender.subscribe(),
+            Arc::clone(&self.account_subs),
+            shared,
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
         ))
     }
 
     /// Subscribe to signature status with handle
+    ///
+    /// Reuses an existing upstream subscription for the same signature and
+    /// commitment level, fanning notifications out to each caller. The
+    /// subscription is automatically replayed if the WebSocket reconnects.
     pub async fn subscribe_signature(
         &self,
         signature: &Signature,
         commitment: Option<CommitmentLevel>,
     ) -> Result<SignatureSubscriptionHandle> {
-        let (subscription_id, notifications) = self
-            .ws_client
-            .signature_subscribe(signature, commitment)
-            .await?;
+        self.check_capacity().await?;
+        let key = SignatureSubKey::new(signature, commitment);
+
+        let existing = self.signature_subs.read().await.get(&key).cloned();
+        let shared = match existing {
+            Some(shared) => {
+                shared.refcount.fetch_add(1, Ordering::SeqCst);
+                shared
+            }
+            None => {
+                let (subscription_id, mut upstream) = self
+                    .ws_client
+                    .signature_subscribe(signature, commitment)
+                    .await?;
+                let (sender, _) = broadcast::channel(self.limits.max_queued_notifications);
+                let fanout = sender.clone();
+                tokio::spawn(async move {
+                    while let Some(notification) = upstream.recv().await {
+                        let _ = fanout.send(notification);
+                    }
+                });
+
+                let shared = Arc::new(SharedSubscription {
+                    subscription_id: AtomicU64::new(subscription_id),
+                    key: key.clone(),
+                    refcount: AtomicUsize::new(1),
+                    sender,
+                    generation: AtomicU64::new(0),
+                    resubscribing: AtomicBool::new(false),
+                });
+                self.signature_subs
+                    .write()
+                    .await
+                    .insert(key, Arc::clone(&shared));
+                shared
+            }
+        };
+
+        let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
+        self.active_subscriptions.write().await.insert(
+            handle_id,
+            (SubscriptionKind::Signature, shared.subscription_id.load(Ordering::SeqCst)),
+        );
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
+
+        Ok(SignatureSubscriptionHandle::new(
+            self.ws_client.clone(),
+            shared.sender.subscribe(),
+            Arc::clone(&self.signature_subs),
+            shared,
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
+        ))
+    }
+
+    /// Subscribe to slot updates with handle
+    ///
+    /// Every caller shares the same upstream `slotSubscribe`, since there are
+    /// no parameters to distinguish one slot subscription from another. The
+    /// subscription is automatically replayed if the WebSocket reconnects.
+    pub async fn subscribe_slots(&self) -> Result<SlotSubscriptionHandle> {
+        self.check_capacity().await?;
+        let existing = self.slot_subs.read().await.get(&()).cloned();
+        let shared = match existing {
+            Some(shared) => {
+                shared.refcount.fetch_add(1, Ordering::SeqCst);
+                shared
+            }
+            None => {
+                let (subscription_id, mut upstream) = self.ws_client.slot_subscribe().await?;
+                let (sender, _) = broadcast::channel(self.limits.max_queued_notifications);
+                let fanout = sender.clone();
+                tokio::spawn(async move {
+                    while let Some(notification) = upstream.recv().await {
+                        let _ = fanout.send(notification);
+                    }
+                });
+
+                let shared = Arc::new(SharedSubscription {
+                    subscription_id: AtomicU64::new(subscription_id),
+                    key: (),
+                    refcount: AtomicUsize::new(1),
+                    sender,
+                    generation: AtomicU64::new(0),
+                    resubscribing: AtomicBool::new(false),
+                });
+                self.slot_subs
+                    .write()
+                    .await
+                    .insert((), Arc::clone(&shared));
+                shared
+            }
+        };
+
+        let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
+        self.active_subscriptions.write().await.insert(
+            handle_id,
+            (SubscriptionKind::Slot, shared.subscription_id.load(Ordering::SeqCst)),
+        );
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
+
+        Ok(SlotSubscriptionHandle::new(
+            self.ws_client.clone(),
+            shared.sender.subscribe(),
+            Arc::clone(&self.slot_subs),
+            shared,
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
+        ))
+    }
+
+    /// Subscribe to program account changes with handle
+    pub async fn subscribe_program(
+        &self,
+        config: ProgramSubscriptionConfig,
+    ) -> Result<ProgramSubscriptionHandle> {
+        self.check_capacity().await?;
+        let (subscription_id, upstream) = self.ws_client.program_subscribe(config).await?;
+        let (notifications, queued_bytes) =
+            spawn_bounded_relay(upstream, self.limits.clone(), Arc::clone(&self.metrics));
         let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
 
         self.active_subscriptions
             .write()
             .await
-            .insert(handle_id, subscription_id);
+            .insert(handle_id, (SubscriptionKind::Program, subscription_id));
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
 
-        Ok(SignatureSubscriptionHandle::new(
+        Ok(ProgramSubscriptionHandle::new(
             subscription_id,
             self.ws_client.clone(),
             notifications,
+            queued_bytes,
+            self.cleanup_tx.clone(),
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
         ))
     }
 
-    /// Subscribe to slot updates with handle
-    pub async fn subscribe_slots(&self) -> Result<SlotSubscriptionHandle> {
-        let (subscription_id, notifications) = self.ws_client.slot_subscribe().await?;
+    /// Subscribe to transaction logs with handle
+    pub async fn subscribe_logs(
+        &self,
+        config: LogsSubscriptionConfig,
+    ) -> Result<LogsSubscriptionHandle> {
+        self.check_capacity().await?;
+        let (subscription_id, upstream) = self.ws_client.logs_subscribe(config).await?;
+        let (notifications, queued_bytes) =
+            spawn_bounded_relay(upstream, self.limits.clone(), Arc::clone(&self.metrics));
         let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
 
         self.active_subscriptions
             .write()
             .await
-            .insert(handle_id, subscription_id);
+            .insert(handle_id, (SubscriptionKind::Logs, subscription_id));
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
 
-        Ok(SlotSubscriptionHandle::new(
+        Ok(LogsSubscriptionHandle::new(
             subscription_id,
             self.ws_client.clone(),
             notifications,
+            queued_bytes,
+            self.cleanup_tx.clone(),
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
+        ))
+    }
+
+    /// Subscribe to block summaries with handle
+    pub async fn subscribe_block(
+        &self,
+        config: Option<BlockSubscriptionConfig>,
+    ) -> Result<BlockSubscriptionHandle> {
+        self.check_capacity().await?;
+        let (subscription_id, upstream) =
+            self.ws_client.block_summary_subscribe(config).await?;
+        let (notifications, queued_bytes) =
+            spawn_bounded_relay(upstream, self.limits.clone(), Arc::clone(&self.metrics));
+        let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
+
+        self.active_subscriptions
+            .write()
+            .await
+            .insert(handle_id, (SubscriptionKind::Block, subscription_id));
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
+
+        Ok(BlockSubscriptionHandle::new(
+            subscription_id,
+            self.ws_client.clone(),
+            notifications,
+            queued_bytes,
+            self.cleanup_tx.clone(),
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
+        ))
+    }
+
+    /// Subscribe to vote notifications with handle
+    pub async fn subscribe_vote(&self) -> Result<VoteSubscriptionHandle> {
+        self.check_capacity().await?;
+        let (subscription_id, upstream) = self.ws_client.vote_subscribe().await?;
+        let (notifications, queued_bytes) =
+            spawn_bounded_relay(upstream, self.limits.clone(), Arc::clone(&self.metrics));
+        let handle_id = self.next_handle_id.fetch_add(1, Ordering::SeqCst);
+
+        self.active_subscriptions
+            .write()
+            .await
+            .insert(handle_id, (SubscriptionKind::Vote, subscription_id));
+        self.metrics.total_created.fetch_add(1, Ordering::Relaxed);
+
+        Ok(VoteSubscriptionHandle::new(
+            subscription_id,
+            self.ws_client.clone(),
+            notifications,
+            queued_bytes,
+            self.cleanup_tx.clone(),
+            handle_id,
+            Arc::clone(&self.active_subscriptions),
         ))
     }
 
@@ -354,14 +1699,29 @@ impl SubscriptionManager {
     }
 
     /// Unsubscribe all active subscriptions
+    ///
+    /// Issues the real unsubscribe RPC for every subscription still tracked by
+    /// this manager, matching the request to its kind, and returns the number
+    /// actually cancelled (a request that errors or was already gone doesn't count).
     pub async fn unsubscribe_all(&self) -> Result<usize> {
-        let mut subscriptions = self.active_subscriptions.write().await;
-        let count = subscriptions.len();
+        let subscriptions: Vec<SubscriptionRecord> =
+            self.active_subscriptions.write().await.drain().map(|(_, v)| v).collect();
 
-        // In a real implementation, we would unsubscribe each one
-        subscriptions.clear();
+        let mut cancelled = 0;
+        for (kind, subscription_id) in subscriptions {
+            match Self::unsubscribe_by_kind(&self.ws_client, kind, subscription_id).await {
+                Ok(true) => cancelled += 1,
+                Ok(false) => {}
+                Err(e) => tracing::warn!(
+                    "Failed to unsubscribe {:?} {}: {}",
+                    kind,
+                    subscription_id,
+                    e
+                ),
+            }
+        }
 
-        Ok(count)
+        Ok(cancelled)
     }
 }
 
@@ -371,6 +1731,12 @@ impl Clone for SubscriptionManager {
             next_handle_id: AtomicU64::new(self.next_handle_id.load(Ordering::SeqCst)),
             active_subscriptions: Arc::clone(&self.active_subscriptions),
             ws_client: self.ws_client.clone(),
+            account_subs: Arc::clone(&self.account_subs),
+            signature_subs: Arc::clone(&self.signature_subs),
+            slot_subs: Arc::clone(&self.slot_subs),
+            cleanup_tx: self.cleanup_tx.clone(),
+            limits: self.limits.clone(),
+            metrics: Arc::clone(&self.metrics),
         }
     }
 }
@@ -390,6 +1756,8 @@ mod tests {
             max_connections: 100,
             ws_reconnect_attempts: 5,
             ws_reconnect_delay: Duration::from_secs(1),
+            ws_keepalive_interval: Duration::from_secs(30),
+            ws_idle_timeout: Duration::from_secs(90),
             auth_token: None,
         }
     }
@@ -413,5 +1781,23 @@ mod tests {
         assert_subscription_handle::<AccountSubscriptionHandle>();
         assert_subscription_handle::<SignatureSubscriptionHandle>();
         assert_subscription_handle::<SlotSubscriptionHandle>();
+        assert_subscription_handle::<ProgramSubscriptionHandle>();
+        assert_subscription_handle::<LogsSubscriptionHandle>();
+        assert_subscription_handle::<BlockSubscriptionHandle>();
+        assert_subscription_handle::<VoteSubscriptionHandle>();
+    }
+
+    #[test]
+    fn test_subscription_handle_streams() {
+        // Test that our handles implement Stream and can be consumed with combinators
+        fn assert_stream<T: Stream>() {}
+
+        assert_stream::<AccountSubscriptionHandle>();
+        assert_stream::<SignatureSubscriptionHandle>();
+        assert_stream::<SlotSubscriptionHandle>();
+        assert_stream::<ProgramSubscriptionHandle>();
+        assert_stream::<LogsSubscriptionHandle>();
+        assert_stream::<BlockSubscriptionHandle>();
+        assert_stream::<VoteSubscriptionHandle>();
     }
 }
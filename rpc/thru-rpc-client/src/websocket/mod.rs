@@ -3,8 +3,9 @@
 pub mod client;
 pub mod handles;
 
-pub use client::WebSocketClient;
+pub use client::{SubscriptionParams, UnsubscribeFn, WebSocketClient};
 pub use handles::{
-    AccountSubscriptionHandle, SignatureSubscriptionHandle, SlotSubscriptionHandle,
-    SubscriptionHandle, SubscriptionManager,
+    AccountSubscriptionHandle, BlockSubscriptionHandle, LogsSubscriptionHandle,
+    ProgramSubscriptionHandle, SignatureSubscriptionHandle, SlotSubscriptionHandle,
+    Subscription, SubscriptionHandle, SubscriptionManager, VoteSubscriptionHandle,
 };